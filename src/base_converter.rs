@@ -1,27 +1,749 @@
+use crate::number_words::{NumberWords, NUMBER_WORDS};
 use crate::util::horizontal_field;
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{Signed, Zero};
+use std::cmp::max;
 use yew::prelude::*;
 
 const BASES: &[u32] = &[2, 8, 10, 16, 36];
 
+const BITS_PER_ROW: u64 = 32;
+
+const WIDTHS: &[u64] = &[8, 16, 32, 64, 128];
+
+/// Highest bit index a bit field may name, and the highest bit the grid
+/// draws. Higher bits of larger values are only summarized.
+const MAX_FIELD_BIT: u64 = 1023;
+
+struct BitField {
+    name: String,
+    msb: u64,
+    lsb: u64,
+}
+
+impl BitField {
+    fn contains(&self, bit: u64) -> bool {
+        self.lsb <= bit && bit <= self.msb
+    }
+
+    fn extract(&self, value: &BigUint) -> BigUint {
+        let mask = (BigUint::from(1u32) << (self.msb - self.lsb + 1) as usize) - 1u32;
+        (value >> self.lsb as usize) & mask
+    }
+}
+
+/// Parses bit field definitions such as `[31:28]=opcode` or `[3]=flag`,
+/// separated by newlines or commas. Bit indices above `MAX_FIELD_BIT` are
+/// rejected. Returns the valid fields and whether any definition failed to
+/// parse.
+fn parse_fields(text: &str) -> (Vec<BitField>, bool) {
+    let mut fields = vec![];
+    let mut error = false;
+
+    for def in text.split(['\n', ',']) {
+        let def = def.trim();
+        if def.is_empty() {
+            continue;
+        }
+        let field = (|| {
+            let def = def.strip_prefix('[')?;
+            let close = def.find(']')?;
+            let range = &def[..close];
+            let name = def[close + 1..].trim_start().strip_prefix('=')?.trim();
+            let (msb, lsb) = match range.find(':') {
+                Some(i) => (
+                    range[..i].trim().parse().ok()?,
+                    range[i + 1..].trim().parse().ok()?,
+                ),
+                None => {
+                    let bit = range.trim().parse().ok()?;
+                    (bit, bit)
+                }
+            };
+            if msb < lsb || msb > MAX_FIELD_BIT || name.is_empty() {
+                return None;
+            }
+            Some(BitField {
+                name: name.to_string(),
+                msb,
+                lsb,
+            })
+        })();
+
+        match field {
+            Some(field) => fields.push(field),
+            None => error = true,
+        }
+    }
+
+    (fields, error)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Encoding {
+    BigEndian,
+    LittleEndian,
+    Uleb128,
+    Sleb128,
+    ZigZag,
+    CompactSize,
+}
+
+const ENCODINGS: &[Encoding] = &[
+    Encoding::BigEndian,
+    Encoding::LittleEndian,
+    Encoding::Uleb128,
+    Encoding::Sleb128,
+    Encoding::ZigZag,
+    Encoding::CompactSize,
+];
+
+impl Encoding {
+    fn name(self) -> &'static str {
+        match self {
+            Encoding::BigEndian => "Big endian",
+            Encoding::LittleEndian => "Little endian",
+            Encoding::Uleb128 => "Varint (LEB128)",
+            Encoding::Sleb128 => "Signed LEB128",
+            Encoding::ZigZag => "ZigZag varint",
+            Encoding::CompactSize => "CompactSize",
+        }
+    }
+
+    /// `width` is the integer width in bits, or 0 for as many as needed.
+    /// Signed encodings read the value as two's complement at that width.
+    /// Fixed-width encodings return `None` if the value does not fit.
+    fn encode(self, value: &BigUint, width: u64) -> Option<Vec<u8>> {
+        match self {
+            Encoding::BigEndian | Encoding::LittleEndian if width > 0 && value.bits() > width => {
+                None
+            }
+            Encoding::BigEndian => {
+                let mut bytes = self.encode_le(value, width);
+                bytes.reverse();
+                Some(bytes)
+            }
+            Encoding::LittleEndian => Some(self.encode_le(value, width)),
+            Encoding::Uleb128 => Some(encode_uleb128(value.clone())),
+            Encoding::Sleb128 => Some(encode_sleb128(to_signed(value, width))),
+            Encoding::ZigZag => {
+                let value = to_signed(value, width);
+                let zigzag = if value.is_negative() {
+                    (value.magnitude() << 1usize) - 1u32
+                } else {
+                    value.magnitude() << 1usize
+                };
+                Some(encode_uleb128(zigzag))
+            }
+            Encoding::CompactSize => {
+                let (prefix, len) = match value.bits() {
+                    _ if *value < BigUint::from(0xfdu32) => (None, 1),
+                    0..=16 => (Some(0xfd), 2),
+                    17..=32 => (Some(0xfe), 4),
+                    33..=64 => (Some(0xff), 8),
+                    _ => return None,
+                };
+                let mut bytes: Vec<u8> = prefix.into_iter().collect();
+                bytes.extend(self.encode_le(value, len * 8));
+                Some(bytes)
+            }
+        }
+    }
+
+    fn encode_le(self, value: &BigUint, width: u64) -> Vec<u8> {
+        let mut bytes = value.to_bytes_le();
+        if value.is_zero() {
+            bytes.clear();
+        }
+        let len = max(width.div_ceil(8) as usize, 1);
+        if bytes.len() < len {
+            bytes.resize(len, 0);
+        }
+        bytes
+    }
+
+    fn decode(self, bytes: &[u8], width: u64) -> Option<BigUint> {
+        if bytes.is_empty() {
+            return None;
+        }
+        match self {
+            Encoding::BigEndian => Some(BigUint::from_bytes_be(bytes)),
+            Encoding::LittleEndian => Some(BigUint::from_bytes_le(bytes)),
+            Encoding::Uleb128 => decode_uleb128(bytes),
+            Encoding::Sleb128 => {
+                let value = BigInt::from(decode_uleb128(bytes)?);
+                let bits = 7 * bytes.len();
+                let value = if bytes[bytes.len() - 1] & 0x40 != 0 {
+                    value - (BigInt::from(1) << bits)
+                } else {
+                    value
+                };
+                from_signed(value, width)
+            }
+            Encoding::ZigZag => {
+                let value = decode_uleb128(bytes)?;
+                let half = BigInt::from(&value >> 1usize);
+                let value = if value.bit(0) { -half - 1 } else { half };
+                from_signed(value, width)
+            }
+            Encoding::CompactSize => {
+                let len = match bytes[0] {
+                    0xfd => 2,
+                    0xfe => 4,
+                    0xff => 8,
+                    _ => 0,
+                };
+                match len {
+                    0 if bytes.len() == 1 => Some(BigUint::from(bytes[0])),
+                    0 => None,
+                    _ if bytes.len() == len + 1 => Some(BigUint::from_bytes_le(&bytes[1..])),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Representation {
+    Gray,
+    PackedBcd,
+    UnpackedBcd,
+    Factoradic,
+    BalancedTernary,
+    Negabinary,
+}
+
+const REPRESENTATIONS: &[Representation] = &[
+    Representation::Gray,
+    Representation::PackedBcd,
+    Representation::UnpackedBcd,
+    Representation::Factoradic,
+    Representation::BalancedTernary,
+    Representation::Negabinary,
+];
+
+impl Representation {
+    fn name(self) -> &'static str {
+        match self {
+            Representation::Gray => "Gray code",
+            Representation::PackedBcd => "Packed BCD",
+            Representation::UnpackedBcd => "Unpacked BCD",
+            Representation::Factoradic => "Factoradic",
+            Representation::BalancedTernary => "Balanced ternary",
+            Representation::Negabinary => "Negabinary",
+        }
+    }
+
+    fn placeholder(self) -> &'static str {
+        match self {
+            Representation::Gray => "0",
+            Representation::PackedBcd | Representation::UnpackedBcd => "00",
+            Representation::Factoradic => "1:0:0",
+            Representation::BalancedTernary => "1T0",
+            Representation::Negabinary => "110",
+        }
+    }
+
+    fn encode(self, value: &BigUint) -> String {
+        match self {
+            Representation::Gray => (value ^ (value >> 1usize)).to_str_radix(2),
+            Representation::PackedBcd => {
+                let digits = value.to_str_radix(10);
+                let digits = if digits.len() % 2 == 1 {
+                    format!("0{}", digits)
+                } else {
+                    digits
+                };
+                digits
+                    .as_bytes()
+                    .chunks(2)
+                    .map(|pair| String::from_utf8_lossy(pair).into_owned())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }
+            Representation::UnpackedBcd => value
+                .to_str_radix(10)
+                .chars()
+                .map(|c| format!("0{}", c))
+                .collect::<Vec<_>>()
+                .join(" "),
+            Representation::Factoradic => {
+                let mut value = value.clone();
+                let mut digits = vec!["0".to_string()];
+                let mut radix = 2u32;
+                while !value.is_zero() {
+                    digits.push((&value % radix).to_string());
+                    value /= radix;
+                    radix += 1;
+                }
+                digits.reverse();
+                digits.join(":")
+            }
+            Representation::BalancedTernary => {
+                let mut value = value.clone();
+                let mut digits = vec![];
+                while !value.is_zero() {
+                    let digit = (&value % 3u32).to_bytes_le()[0];
+                    digits.push(['0', '1', 'T'][digit as usize]);
+                    if digit == 2 {
+                        value += 1u32;
+                    }
+                    value /= 3u32;
+                }
+                if digits.is_empty() {
+                    digits.push('0');
+                }
+                digits.iter().rev().collect()
+            }
+            Representation::Negabinary => {
+                let mut value = BigInt::from(value.clone());
+                let mut digits = vec![];
+                while !value.is_zero() {
+                    let digit = &value & BigInt::from(1);
+                    digits.push(if digit.is_zero() { '0' } else { '1' });
+                    value = (value - digit) / -2;
+                }
+                if digits.is_empty() {
+                    digits.push('0');
+                }
+                digits.iter().rev().collect()
+            }
+        }
+    }
+
+    fn decode(self, text: &str) -> Option<BigUint> {
+        let text = text.trim();
+        match self {
+            Representation::Gray => {
+                let gray = BigUint::parse_bytes(text.as_bytes(), 2)?;
+                let mut value = gray.clone();
+                let mut shift = gray >> 1usize;
+                while !shift.is_zero() {
+                    value ^= &shift;
+                    shift >>= 1usize;
+                }
+                Some(value)
+            }
+            Representation::PackedBcd | Representation::UnpackedBcd => {
+                let mut digits = String::new();
+                for byte in parse_bytes(text)? {
+                    let (high, low) = (byte >> 4, byte & 0xf);
+                    if self == Representation::PackedBcd {
+                        if high > 9 {
+                            return None;
+                        }
+                        digits.push((b'0' + high) as char);
+                    } else if high != 0 {
+                        return None;
+                    }
+                    if low > 9 {
+                        return None;
+                    }
+                    digits.push((b'0' + low) as char);
+                }
+                BigUint::parse_bytes(digits.as_bytes(), 10)
+            }
+            Representation::Factoradic => {
+                let mut value = BigUint::zero();
+                let mut factorial = BigUint::from(1u32);
+                for (i, digit) in text.split(':').rev().enumerate() {
+                    let digit: u32 = digit.trim().parse().ok()?;
+                    if digit as usize > i {
+                        return None;
+                    }
+                    if i > 0 {
+                        factorial *= i;
+                    }
+                    value += &factorial * digit;
+                }
+                Some(value)
+            }
+            Representation::BalancedTernary | Representation::Negabinary => {
+                let radix = if self == Representation::Negabinary {
+                    -2
+                } else {
+                    3
+                };
+                let mut value = BigInt::zero();
+                for c in text.chars() {
+                    let digit = match (self, c) {
+                        (_, '0') => 0,
+                        (_, '1') => 1,
+                        (Representation::BalancedTernary, 'T')
+                        | (Representation::BalancedTernary, 't') => -1,
+                        (Representation::BalancedTernary, '-') => -1,
+                        _ => return None,
+                    };
+                    value = value * radix + digit;
+                }
+                if text.is_empty() {
+                    return None;
+                }
+                value.to_biguint()
+            }
+        }
+    }
+}
+
+fn encode_uleb128(mut value: BigUint) -> Vec<u8> {
+    let mut bytes = vec![];
+    loop {
+        let byte = (&value % 0x80u32).to_bytes_le()[0];
+        value >>= 7usize;
+        if value.is_zero() {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn encode_sleb128(mut value: BigInt) -> Vec<u8> {
+    let mut bytes = vec![];
+    loop {
+        let low: BigInt = ((&value % 0x80) + 0x80) % 0x80;
+        let byte = low.magnitude().to_bytes_le()[0];
+        value = (value - low) / 0x80;
+        let done = (value.is_zero() && byte & 0x40 == 0)
+            || (value == BigInt::from(-1) && byte & 0x40 != 0);
+        if done {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn decode_uleb128(bytes: &[u8]) -> Option<BigUint> {
+    let (last, init) = bytes.split_last()?;
+    if last & 0x80 != 0 || init.iter().any(|b| b & 0x80 == 0) {
+        return None;
+    }
+    Some(bytes.iter().rev().fold(BigUint::zero(), |acc, &b| {
+        (acc << 7usize) | BigUint::from(b & 0x7f)
+    }))
+}
+
+/// Reads `value` as a two's complement integer of `width` bits. With no
+/// width, or a value that does not fit, the value is taken as non-negative.
+fn to_signed(value: &BigUint, width: u64) -> BigInt {
+    if width > 0 && value.bits() == width {
+        BigInt::from(value.clone()) - (BigInt::from(1) << width as usize)
+    } else {
+        BigInt::from(value.clone())
+    }
+}
+
+fn from_signed(value: BigInt, width: u64) -> Option<BigUint> {
+    match value.sign() {
+        Sign::Minus if width > 0 => {
+            let value = (BigInt::from(1) << width as usize) + value;
+            value.to_biguint().filter(|v| v.bits() == width)
+        }
+        Sign::Minus => None,
+        _ => value.to_biguint(),
+    }
+}
+
+/// Parses byte lists such as `de ad be ef`, `0xde,0xad` or `deadbeef`.
+/// Tokens longer than a byte, such as `0xdead`, are split into bytes.
+fn parse_bytes(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = vec![];
+    for t in text.split(|c: char| c.is_whitespace() || c == ',' || c == ':' || c == '-') {
+        if t.is_empty() {
+            continue;
+        }
+        let t = t.strip_prefix("0x").unwrap_or(t);
+        if t.len() <= 2 {
+            bytes.push(u8::from_str_radix(t, 16).ok()?);
+            continue;
+        }
+        if !t.len().is_multiple_of(2) || !t.is_ascii() {
+            return None;
+        }
+        for i in (0..t.len()).step_by(2) {
+            bytes.push(u8::from_str_radix(&t[i..i + 2], 16).ok()?);
+        }
+    }
+    Some(bytes)
+}
+
 pub struct Model {
     link: ComponentLink<Self>,
     props: Props,
 }
 
 pub enum Msg {
-    Input { base: u32, text: String },
+    Expression(String),
+    Input {
+        base: u32,
+        text: String,
+    },
     Uppercase,
+    ToggleBit(u64),
+    LsbFirst,
+    Fields(String),
+    Width(u64),
+    Encoded {
+        encoding: Encoding,
+        text: String,
+    },
+    Represented {
+        representation: Representation,
+        text: String,
+    },
+    Worded {
+        words: NumberWords,
+        text: String,
+    },
 }
 
 #[derive(Properties, Clone)]
 pub struct Props {
+    #[prop_or_default]
+    expression: String,
+    #[prop_or_default]
+    expression_error: Option<String>,
     #[prop_or_default]
     uppercase: bool,
     #[prop_or_default]
     value: Option<BigUint>,
     #[prop_or_default]
     error: Option<u32>,
+    #[prop_or_default]
+    lsb_first: bool,
+    #[prop_or_default]
+    fields: String,
+    #[prop_or_default]
+    width: u64,
+    #[prop_or_default]
+    encoding_error: Option<Encoding>,
+    #[prop_or_default]
+    representation_error: Option<Representation>,
+    #[prop_or_default]
+    words_error: Option<NumberWords>,
+}
+
+impl Model {
+    fn set_value(&mut self, value: Option<BigUint>) {
+        self.props.value = value;
+        self.props.expression_error = None;
+        self.props.error = None;
+        self.props.encoding_error = None;
+        self.props.representation_error = None;
+        self.props.words_error = None;
+    }
+
+    fn view_representations(&self) -> Html {
+        html! {
+            <>
+            {
+                for REPRESENTATIONS.iter().map(|&representation| {
+                    let mut classes = vec!["input"];
+                    if self.props.representation_error == Some(representation) {
+                        classes.push("is-danger");
+                    }
+                    let value = self.props.value.as_ref().map_or("".to_string(), |v| representation.encode(v));
+                    horizontal_field(representation.name(), html!{
+                        <input class=classes type="text" placeholder=representation.placeholder()
+                            value=value
+                            oninput=self.link.callback(move |e: InputData| Msg::Represented{representation, text: e.value})/>
+                    })
+                })
+            }
+            </>
+        }
+    }
+
+    fn view_number_words(&self) -> Html {
+        html! {
+            <>
+            {
+                for NUMBER_WORDS.iter().map(|&words| {
+                    let mut classes = vec!["input"];
+                    if self.props.words_error == Some(words) {
+                        classes.push("is-danger");
+                    }
+                    let encoded = self.props.value.as_ref().map(|v| words.encode(v));
+                    horizontal_field(words.name(), html!{
+                        <>
+                        <input class=classes type="text" placeholder=words.placeholder()
+                            value=encoded.clone().flatten().unwrap_or_default()
+                            oninput=self.link.callback(move |e: InputData| Msg::Worded{words, text: e.value})/>
+                        {
+                            if encoded == Some(None) && self.props.words_error.is_none() {
+                                html! { <p class="help">{ words.range() }</p> }
+                            } else {
+                                html! {}
+                            }
+                        }
+                        </>
+                    })
+                })
+            }
+            </>
+        }
+    }
+
+    fn view_encodings(&self) -> Html {
+        let width_select = html! {
+            <div class="select">
+                <select onchange=self.link.callback(|e: ChangeData| match e {
+                    ChangeData::Select(s) => Msg::Width(s.value().parse().unwrap_or(0)),
+                    _ => unreachable!(),
+                })>
+                    <option value="0" selected=self.props.width == 0>{"Auto"}</option>
+                    {
+                        for WIDTHS.iter().map(|&w| html!{
+                            <option value=w selected=self.props.width == w>{ format!("{} bits", w) }</option>
+                        })
+                    }
+                </select>
+            </div>
+        };
+
+        html! {
+            <>
+            { horizontal_field("Width", width_select) }
+            {
+                for ENCODINGS.iter().map(|&encoding| {
+                    let mut classes = vec!["input"];
+                    if self.props.encoding_error == Some(encoding) {
+                        classes.push("is-danger");
+                    }
+                    let value = self.props.value.as_ref().map_or("".to_string(), |v| {
+                        encoding.encode(v, self.props.width).map_or("-".to_string(), |bytes| {
+                            let s = bytes.iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(" ");
+                            if self.props.uppercase { s.to_uppercase() } else { s }
+                        })
+                    });
+                    horizontal_field(encoding.name(), html!{
+                        <input class=classes type="text" placeholder="00"
+                            value=value
+                            oninput=self.link.callback(move |e: InputData| Msg::Encoded{encoding, text: e.value})/>
+                    })
+                })
+            }
+            </>
+        }
+    }
+
+    fn view_bits(&self) -> Html {
+        let value = self.props.value.clone().unwrap_or_default();
+        let (fields, fields_error) = parse_fields(&self.props.fields);
+
+        let used_bits = fields.iter().map(|f| f.msb + 1).fold(value.bits(), max);
+        let width = max(used_bits.div_ceil(8) * 8, max(self.props.width, 8));
+        let hidden = width.saturating_sub(MAX_FIELD_BIT + 1);
+        let width = width - hidden;
+
+        let bits: Vec<u64> = if self.props.lsb_first {
+            (0..width).collect()
+        } else {
+            (0..width).rev().collect()
+        };
+
+        let (value, fields) = (&value, &fields);
+        let row = |bits: &[u64]| {
+            let cells = |label: bool| {
+                bits.iter().enumerate().map(move |(i, &bit)| {
+                    let gap = if i > 0 && i % 8 == 0 {
+                        html! { <td></td> }
+                    } else {
+                        html! {}
+                    };
+                    let field = fields.iter().find(|f| f.contains(bit));
+                    let cell = if label {
+                        html! { <td class="has-text-centered is-size-7 has-text-grey">{ bit }</td> }
+                    } else {
+                        let set = value.bit(bit);
+                        let mut classes = vec!["tag"];
+                        if field.is_some() {
+                            classes.push("is-info");
+                        }
+                        if !set {
+                            classes.push("is-light");
+                        }
+                        html! {
+                            <td>
+                                <a class=classes title=field.map_or("".to_string(), |f| f.name.clone())
+                                    onclick=self.link.callback(move |_| Msg::ToggleBit(bit))>
+                                    { set as u8 }
+                                </a>
+                            </td>
+                        }
+                    };
+                    html! { <>{ gap }{ cell }</> }
+                })
+            };
+            html! {
+                <>
+                <tr>{ for cells(true) }</tr>
+                <tr>{ for cells(false) }</tr>
+                </>
+            }
+        };
+
+        let mut fields_classes = vec!["textarea"];
+        if fields_error {
+            fields_classes.push("is-danger");
+        }
+
+        html! {
+            <>
+            <div class="table-container">
+                <table class="table is-narrow">
+                    { for bits.chunks(BITS_PER_ROW as usize).map(row) }
+                </table>
+            </div>
+            {
+                if hidden > 0 {
+                    let set = (value >> width as usize).count_ones();
+                    html! {
+                        <p class="help mb-3">
+                            { format!("Bits {} to {} are not shown; {} of them are set.", width, width + hidden - 1, set) }
+                        </p>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+            {
+                horizontal_field("", html!{
+                    <label class="checkbox">
+                        <input type="checkbox"
+                            checked=self.props.lsb_first
+                            onchange=self.link.callback(|_| Msg::LsbFirst)/>
+                        {" LSB first"}
+                    </label>
+                })
+            }
+            {
+                horizontal_field("Fields", html!{
+                    <textarea class=fields_classes rows=3 placeholder="[31:28]=opcode, [3]=flag"
+                        value=self.props.fields.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::Fields(e.value))/>
+                })
+            }
+            {
+                for fields.iter().map(|f| {
+                    let v = f.extract(value);
+                    let range = if f.msb == f.lsb {
+                        format!("{} [{}]", f.name, f.msb)
+                    } else {
+                        format!("{} [{}:{}]", f.name, f.msb, f.lsb)
+                    };
+                    let text = format!("{} (0x{}, 0b{:0>width$})",
+                        v, v.to_str_radix(16), v.to_str_radix(2), width = (f.msb - f.lsb + 1) as usize);
+                    horizontal_field(&range, html!{
+                        <input class="input" type="text" value=text readonly=true/>
+                    })
+                })
+            }
+            </>
+        }
+    }
 }
 
 impl Component for Model {
@@ -34,12 +756,26 @@ impl Component for Model {
 
     fn update(&mut self, msg: Self::Message) -> yew::ShouldRender {
         match msg {
+            Msg::Expression(text) => {
+                if text.trim().is_empty() {
+                    self.set_value(None);
+                } else {
+                    let width = self.props.width;
+                    match crate::expr::eval(&text).and_then(|v| {
+                        from_signed(v, width)
+                            .ok_or_else(|| "negative result needs a fixed width".to_string())
+                    }) {
+                        Ok(num) => self.set_value(Some(num)),
+                        Err(e) => self.props.expression_error = Some(e),
+                    }
+                }
+                self.props.expression = text;
+            }
             Msg::Input { base, text } => {
                 if text.is_empty() {
                     self.props.value = None;
                 } else if let Some(num) = BigUint::parse_bytes(text.as_bytes(), base) {
-                    self.props.value = Some(num);
-                    self.props.error = None;
+                    self.set_value(Some(num));
                 } else {
                     self.props.error = Some(base);
                 }
@@ -47,6 +783,53 @@ impl Component for Model {
             Msg::Uppercase => {
                 self.props.uppercase = !self.props.uppercase;
             }
+            Msg::ToggleBit(bit) => {
+                let mut value = self.props.value.take().unwrap_or_default();
+                let set = value.bit(bit);
+                value.set_bit(bit, !set);
+                self.set_value(Some(value));
+            }
+            Msg::LsbFirst => {
+                self.props.lsb_first = !self.props.lsb_first;
+            }
+            Msg::Fields(text) => {
+                self.props.fields = text;
+            }
+            Msg::Width(width) => {
+                self.props.width = width;
+            }
+            Msg::Encoded { encoding, text } => {
+                if text.trim().is_empty() {
+                    self.set_value(None);
+                } else if let Some(num) =
+                    parse_bytes(&text).and_then(|bytes| encoding.decode(&bytes, self.props.width))
+                {
+                    self.set_value(Some(num));
+                } else {
+                    self.props.encoding_error = Some(encoding);
+                }
+            }
+            Msg::Represented {
+                representation,
+                text,
+            } => {
+                if text.trim().is_empty() {
+                    self.set_value(None);
+                } else if let Some(num) = representation.decode(&text) {
+                    self.set_value(Some(num));
+                } else {
+                    self.props.representation_error = Some(representation);
+                }
+            }
+            Msg::Worded { words, text } => {
+                if text.trim().is_empty() {
+                    self.set_value(None);
+                } else if let Some(num) = words.decode(&text) {
+                    self.set_value(Some(num));
+                } else {
+                    self.props.words_error = Some(words);
+                }
+            }
         }
         true
     }
@@ -56,8 +839,29 @@ impl Component for Model {
     }
 
     fn view(&self) -> yew::Html {
+        let mut expression_classes = vec!["input"];
+        if self.props.expression_error.is_some() {
+            expression_classes.push("is-danger");
+        }
+
         html! {
             <>
+            {
+                horizontal_field("Expression", html!{
+                    <>
+                    <input class=expression_classes type="text" placeholder="2**127 - 1"
+                        value=self.props.expression.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::Expression(e.value))/>
+                    {
+                        if let Some(e) = &self.props.expression_error {
+                            html! { <p class="help is-danger">{ e }</p> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    </>
+                })
+            }
             {
                 for BASES.iter().map(move |&base| {
                     let mut classes = vec!["input"];
@@ -89,7 +893,156 @@ impl Component for Model {
                     </label>
                 })
             }
+
+            <h2 class="title is-4">{"Byte encodings"}</h2>
+            { self.view_encodings() }
+
+            <h2 class="title is-4">{"Other representations"}</h2>
+            { self.view_representations() }
+
+            <h2 class="title is-4">{"Number words"}</h2>
+            { self.view_number_words() }
+
+            <h2 class="title is-4">{"Bits"}</h2>
+            { self.view_bits() }
+
+            <h2 class="title is-4">{"Floating point (IEEE 754)"}</h2>
+            <crate::float_inspector::Model/>
             </>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fields_reads_ranges_and_single_bits() {
+        let (fields, error) = parse_fields("[31:28]=opcode, [3]=flag");
+        assert!(!error);
+        assert_eq!(fields.len(), 2);
+        assert_eq!(
+            (fields[0].name.as_str(), fields[0].msb, fields[0].lsb),
+            ("opcode", 31, 28)
+        );
+        assert_eq!(
+            (fields[1].name.as_str(), fields[1].msb, fields[1].lsb),
+            ("flag", 3, 3)
+        );
+        assert_eq!(
+            fields[0].extract(&BigUint::from(0xa000_0000u32)),
+            BigUint::from(0xau32)
+        );
+    }
+
+    #[test]
+    fn parse_fields_rejects_out_of_range_bits() {
+        for def in &[
+            "[18446744073709551615]=x",
+            "[1000000000:0]=x",
+            "[1024]=x",
+            "[3:4]=x",
+        ] {
+            let (fields, error) = parse_fields(def);
+            assert!(error, "{}", def);
+            assert!(fields.is_empty(), "{}", def);
+        }
+        assert!(!parse_fields("[1023:0]=x").1);
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn fixed_width_encodings_reject_overflow() {
+        let value = BigUint::from(0x1234u32);
+        let be = Encoding::BigEndian;
+        let le = Encoding::LittleEndian;
+        assert_eq!(
+            be.encode(&value, 32).map(|b| hex(&b)),
+            Some("00001234".to_string())
+        );
+        assert_eq!(
+            le.encode(&value, 16).map(|b| hex(&b)),
+            Some("3412".to_string())
+        );
+        assert_eq!(
+            be.encode(&value, 0).map(|b| hex(&b)),
+            Some("1234".to_string())
+        );
+        assert_eq!(be.encode(&value, 8), None);
+        assert_eq!(le.encode(&value, 8), None);
+    }
+
+    #[test]
+    fn varints_round_trip() {
+        let cases: &[(Encoding, u64, u64, &str)] = &[
+            (Encoding::Uleb128, 624_485, 0, "e58e26"),
+            (Encoding::Sleb128, 0x1_0000_0000 - 123_456, 32, "c0bb78"),
+            (Encoding::ZigZag, 0xffff_ffff, 32, "01"),
+            (Encoding::ZigZag, 2, 32, "04"),
+            (Encoding::CompactSize, 0xfc, 0, "fc"),
+            (Encoding::CompactSize, 0xfd, 0, "fdfd00"),
+            (Encoding::CompactSize, 0x1_0000, 0, "fe00000100"),
+        ];
+        for &(encoding, value, width, expected) in cases {
+            let value = BigUint::from(value);
+            let bytes = encoding.encode(&value, width).unwrap();
+            assert_eq!(hex(&bytes), expected, "{}", encoding.name());
+            assert_eq!(
+                encoding.decode(&bytes, width),
+                Some(value),
+                "{}",
+                encoding.name()
+            );
+        }
+        assert_eq!(
+            Encoding::CompactSize.encode(&(BigUint::from(1u32) << 64usize), 0),
+            None
+        );
+        assert_eq!(Encoding::Uleb128.decode(&[0x80], 0), None);
+    }
+
+    #[test]
+    fn parse_bytes_splits_multi_byte_tokens() {
+        let expected = Some(vec![0x12, 0x34, 0x56]);
+        assert_eq!(parse_bytes("12 34 56"), expected);
+        assert_eq!(parse_bytes("0x1234 0x56"), expected);
+        assert_eq!(parse_bytes("0x12,0x3456"), expected);
+        assert_eq!(parse_bytes("123456"), expected);
+        assert_eq!(parse_bytes("0x123 56"), None);
+        assert_eq!(parse_bytes("zz"), None);
+    }
+
+    #[test]
+    fn representations_round_trip() {
+        let cases: &[(Representation, u32, &str)] = &[
+            (Representation::Gray, 5, "111"),
+            (Representation::PackedBcd, 1234, "12 34"),
+            (Representation::PackedBcd, 123, "01 23"),
+            (Representation::UnpackedBcd, 42, "04 02"),
+            (Representation::Factoradic, 463, "3:4:1:0:1:0"),
+            (Representation::BalancedTernary, 8, "10T"),
+            (Representation::Negabinary, 6, "11010"),
+        ];
+        for &(representation, value, expected) in cases {
+            let value = BigUint::from(value);
+            assert_eq!(
+                representation.encode(&value),
+                expected,
+                "{}",
+                representation.name()
+            );
+            assert_eq!(
+                representation.decode(expected),
+                Some(value),
+                "{}",
+                representation.name()
+            );
+        }
+        assert_eq!(Representation::PackedBcd.decode("1a"), None);
+        assert_eq!(Representation::Factoradic.decode("2:0"), None);
+    }
+}
@@ -0,0 +1,448 @@
+use crate::util::horizontal_field;
+use encoding_rs::{
+    Encoding, BIG5, EUC_JP, EUC_KR, GBK, ISO_2022_JP, SHIFT_JIS, UTF_16BE, UTF_16LE, UTF_8,
+    WINDOWS_1252,
+};
+use yew::prelude::*;
+
+const CHARSETS: &[&Encoding] = &[
+    UTF_8,
+    UTF_16LE,
+    UTF_16BE,
+    SHIFT_JIS,
+    EUC_JP,
+    ISO_2022_JP,
+    EUC_KR,
+    GBK,
+    BIG5,
+    WINDOWS_1252,
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Radix {
+    Hex,
+    Decimal,
+    Octal,
+    Binary,
+}
+
+const RADIXES: &[Radix] = &[Radix::Hex, Radix::Decimal, Radix::Octal, Radix::Binary];
+
+impl Radix {
+    fn name(self) -> &'static str {
+        match self {
+            Radix::Hex => "Hexadecimal",
+            Radix::Decimal => "Decimal",
+            Radix::Octal => "Octal",
+            Radix::Binary => "Binary",
+        }
+    }
+
+    fn radix(self) -> u32 {
+        match self {
+            Radix::Hex => 16,
+            Radix::Decimal => 10,
+            Radix::Octal => 8,
+            Radix::Binary => 2,
+        }
+    }
+
+    fn format(self, b: u8, uppercase: bool) -> String {
+        match self {
+            Radix::Hex if uppercase => format!("{:02X}", b),
+            Radix::Hex => format!("{:02x}", b),
+            Radix::Decimal => b.to_string(),
+            Radix::Octal => format!("{:03o}", b),
+            Radix::Binary => format!("{:08b}", b),
+        }
+    }
+}
+
+const SEPARATORS: &[(&str, &str)] = &[
+    ("Space", " "),
+    ("Comma", ","),
+    ("Comma and space", ", "),
+    ("None", ""),
+    ("Newline", "\n"),
+];
+
+/// Encodes `text` in `charset`. encoding_rs only decodes UTF-16, so it is
+/// encoded here.
+fn encode(text: &str, charset: &'static Encoding) -> Result<Vec<u8>, String> {
+    if charset == UTF_16LE {
+        return Ok(text.encode_utf16().flat_map(u16::to_le_bytes).collect());
+    }
+    if charset == UTF_16BE {
+        return Ok(text.encode_utf16().flat_map(u16::to_be_bytes).collect());
+    }
+    let (bytes, _, unmappable) = charset.encode(text);
+    if unmappable {
+        let chars: String = text
+            .chars()
+            .filter(|c| {
+                let mut buf = [0; 4];
+                charset.encode(c.encode_utf8(&mut buf)).2
+            })
+            .collect();
+        return Err(format!("Cannot encode in {}: {}", charset.name(), chars));
+    }
+    Ok(bytes.into_owned())
+}
+
+fn is_binary(digits: &str) -> bool {
+    !digits.is_empty()
+        && digits.len().is_multiple_of(8)
+        && digits.chars().all(|c| c == '0' || c == '1')
+}
+
+/// Parses a list of byte values such as `48 65 6c`, `\x48\x65`,
+/// `0x48,0x65`, `%48%65` or `[72, 101]`. Tokens with a `0x`, `0o` or `0b`
+/// prefix, `\x` or `%` are read in that base, the others in `radix`. In
+/// hex, `0b` is only a prefix when whole bytes of binary digits follow. A
+/// hex or binary token longer than one byte is split into bytes.
+fn parse_bytes(input: &str, radix: Radix) -> Result<Vec<u8>, String> {
+    let mut bytes = vec![];
+    let marked = input
+        .replace("\\x", " 0x")
+        .replace("\\X", " 0x")
+        .replace('%', " 0x");
+    let tokens = marked
+        .split(|c: char| c.is_whitespace() || ",;:[](){}".contains(c))
+        .filter(|t| !t.is_empty());
+
+    for token in tokens {
+        let lower = token.to_ascii_lowercase();
+        let (digits, radix) = match lower.get(..2) {
+            Some("0x") => (&lower[2..], Radix::Hex),
+            Some("0o") => (&lower[2..], Radix::Octal),
+            Some("0b") if radix != Radix::Hex || is_binary(&lower[2..]) => {
+                (&lower[2..], Radix::Binary)
+            }
+            _ => (&lower[..], radix),
+        };
+        let width = match radix {
+            Radix::Hex => 2,
+            Radix::Binary => 8,
+            Radix::Decimal | Radix::Octal => digits.len().max(1),
+        };
+        // Checked up front: from_str_radix accepts a sign, and slicing
+        // needs ASCII.
+        let valid = digits.chars().all(|c| c.is_digit(radix.radix()));
+        if !valid || digits.is_empty() || digits.len() % width != 0 {
+            return Err(format!("Invalid byte: {}", token));
+        }
+        for i in (0..digits.len()).step_by(width) {
+            let byte = u8::from_str_radix(&digits[i..i + width], radix.radix())
+                .map_err(|_| format!("Invalid byte: {}", token))?;
+            bytes.push(byte);
+        }
+    }
+    Ok(bytes)
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or(true)]
+    encode: bool,
+    #[prop_or_default]
+    input: String,
+    #[prop_or(UTF_8)]
+    charset: &'static Encoding,
+    #[prop_or(Radix::Hex)]
+    radix: Radix,
+    #[prop_or(" ")]
+    separator: &'static str,
+    #[prop_or_default]
+    prefix: String,
+    #[prop_or_default]
+    uppercase: bool,
+}
+
+pub enum Msg {
+    Input(String),
+    Encode(bool),
+    Charset(&'static Encoding),
+    Radix(Radix),
+    Separator(&'static str),
+    Prefix(String),
+    Uppercase(bool),
+}
+
+struct Output {
+    text: String,
+    help: String,
+    error: bool,
+}
+
+impl Model {
+    fn convert(&self) -> Output {
+        let props = &self.props;
+        let result = if props.encode {
+            encode(&props.input, props.charset).map(|bytes| Output {
+                text: bytes
+                    .iter()
+                    .map(|&b| format!("{}{}", props.prefix, props.radix.format(b, props.uppercase)))
+                    .collect::<Vec<_>>()
+                    .join(props.separator),
+                help: format!("{} bytes", bytes.len()),
+                error: false,
+            })
+        } else {
+            parse_bytes(&props.input, props.radix).map(|bytes| {
+                let (text, malformed) = props.charset.decode_without_bom_handling(&bytes);
+                Output {
+                    text: text.into_owned(),
+                    help: if malformed {
+                        format!(
+                            "Not valid {}; malformed sequences are shown as U+FFFD",
+                            props.charset.name()
+                        )
+                    } else {
+                        format!("{} bytes", bytes.len())
+                    },
+                    error: malformed,
+                }
+            })
+        };
+        result.unwrap_or_else(|err| Output {
+            text: String::new(),
+            help: err,
+            error: true,
+        })
+    }
+
+    fn view_encode_options(&self) -> Html {
+        let props = &self.props;
+        let uppercase = props.uppercase;
+        html! {
+            <>
+            {
+                horizontal_field("Separator", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Separator(SEPARATORS[s.selected_index().max(0) as usize].1),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for SEPARATORS.iter().map(|&(name, separator)| html!{
+                                    <option selected=props.separator == separator>{ name }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                horizontal_field("Prefix", html!{
+                    <input class="input" type="text" placeholder="\\x, 0x, %" value=&props.prefix
+                        oninput=self.link.callback(|e: InputData| Msg::Prefix(e.value))/>
+                })
+            }
+            {
+                horizontal_field("", html!{
+                    <label class="checkbox">
+                        <input type="checkbox" checked=uppercase
+                            onchange=self.link.callback(move |_| Msg::Uppercase(!uppercase))/>
+                        {" Uppercase hex digits"}
+                    </label>
+                })
+            }
+            </>
+        }
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(txt) => self.props.input = txt,
+            Msg::Encode(b) => {
+                if self.props.encode != b {
+                    // Keep the input when it does not convert, so that a
+                    // typo is not lost.
+                    let output = self.convert();
+                    if !output.error {
+                        self.props.input = output.text;
+                    }
+                    self.props.encode = b;
+                }
+            }
+            Msg::Charset(charset) => self.props.charset = charset,
+            Msg::Radix(radix) => self.props.radix = radix,
+            Msg::Separator(separator) => self.props.separator = separator,
+            Msg::Prefix(prefix) => self.props.prefix = prefix,
+            Msg::Uppercase(b) => self.props.uppercase = b,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let props = &self.props;
+        let output = self.convert();
+
+        html! {
+            <>
+            <div class="tabs is-boxed">
+                <ul>
+                    <li class=if props.encode {"is-active"} else {""}>
+                        <a onclick=self.link.callback(|_| Msg::Encode(true))>{"Text to bytes"}</a>
+                    </li>
+                    <li class=if props.encode {""} else {"is-active"}>
+                        <a onclick=self.link.callback(|_| Msg::Encode(false))>{"Bytes to text"}</a>
+                    </li>
+                </ul>
+            </div>
+
+            {
+                horizontal_field("Charset", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Charset(CHARSETS[s.selected_index().max(0) as usize]),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for CHARSETS.iter().map(|&charset| html!{
+                                    <option selected=props.charset == charset>{ charset.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                horizontal_field("Base", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Radix(RADIXES[s.selected_index().max(0) as usize]),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for RADIXES.iter().map(|&radix| html!{
+                                    <option selected=props.radix == radix>{ radix.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                if props.encode {
+                    self.view_encode_options()
+                } else {
+                    html! {}
+                }
+            }
+
+            <div class="field">
+                <label class="label">{"Input"}</label>
+                <div class="control">
+                    <textarea class="textarea"
+                        placeholder=if props.encode { "Hello" } else { "48 65 6c 6c 6f" }
+                        value=&props.input
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))/>
+                </div>
+                {
+                    if props.encode {
+                        html! {}
+                    } else {
+                        html! { <p class="help">{"Accepts 48 65 6c, \\x48\\x65, 0x48,0x65, %48%65 and the like. Tokens prefixed with 0x, 0o or 0b override the base."}</p> }
+                    }
+                }
+            </div>
+            <div class="field">
+                <label class="label">{"Output"}</label>
+                <div class="control">
+                    <textarea class=if output.error { "textarea is-danger" } else { "textarea" }
+                        value=output.text readonly=true/>
+                </div>
+                <p class=if output.error { "help is-danger" } else { "help" }>{ output.help }</p>
+            </div>
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_common_byte_list_forms() {
+        let expected = Ok(vec![0x48, 0x65, 0x6c]);
+        for input in &[
+            "48 65 6c",
+            "\\x48\\x65\\x6C",
+            "0x48,0x65,0x6c",
+            "%48%65%6c",
+            "48656c",
+        ] {
+            assert_eq!(parse_bytes(input, Radix::Hex), expected, "{}", input);
+        }
+        assert_eq!(parse_bytes("[72, 101, 108]", Radix::Decimal), expected);
+        assert_eq!(parse_bytes("0o110 0x65 108", Radix::Decimal), expected);
+        assert_eq!(
+            parse_bytes("010010000110010101101100", Radix::Binary),
+            expected
+        );
+    }
+
+    #[test]
+    fn binary_prefix_in_hex_needs_whole_bytes() {
+        assert_eq!(parse_bytes("0b01001000", Radix::Hex), Ok(vec![0x48]));
+        assert_eq!(parse_bytes("0b01", Radix::Hex), Ok(vec![0x0b, 0x01]));
+        assert_eq!(parse_bytes("0b00000001", Radix::Decimal), Ok(vec![1]));
+    }
+
+    #[test]
+    fn rejects_invalid_digits() {
+        for input in &["a\u{e9}b", "+f", "-1", "4", "0x", "1g"] {
+            assert!(parse_bytes(input, Radix::Hex).is_err(), "{}", input);
+        }
+        assert!(parse_bytes("256", Radix::Decimal).is_err());
+        assert!(parse_bytes("+1", Radix::Decimal).is_err());
+    }
+
+    #[test]
+    fn encodes_in_each_charset() {
+        let cases: &[(&'static Encoding, &[u8])] = &[
+            (UTF_8, &[0xe3, 0x81, 0x82, 0x41]),
+            (UTF_16LE, &[0x42, 0x30, 0x41, 0x00]),
+            (UTF_16BE, &[0x30, 0x42, 0x00, 0x41]),
+            (SHIFT_JIS, &[0x82, 0xa0, 0x41]),
+            (EUC_JP, &[0xa4, 0xa2, 0x41]),
+            (
+                ISO_2022_JP,
+                &[0x1b, 0x24, 0x42, 0x24, 0x22, 0x1b, 0x28, 0x42, 0x41],
+            ),
+        ];
+        for &(charset, bytes) in cases {
+            assert_eq!(
+                encode("\u{3042}A", charset).as_deref(),
+                Ok(bytes),
+                "{}",
+                charset.name()
+            );
+        }
+        assert_eq!(encode("\u{e9}", WINDOWS_1252), Ok(vec![0xe9]));
+        assert_eq!(
+            encode("a\u{1F600}", SHIFT_JIS),
+            Err("Cannot encode in Shift_JIS: \u{1F600}".to_string())
+        );
+    }
+}
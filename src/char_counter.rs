@@ -1,25 +1,565 @@
+use crate::frequency::{self, SortKey, Unit, STOP_WORDS, UNITS};
+use crate::japanese::{self, manuscript_lines, MANUSCRIPT_COLUMNS, MANUSCRIPT_ROWS};
+use crate::sms::{self, gsm_septets};
+use crate::twitter::{tweet_length, MAX_WEIGHTED_LENGTH};
 use crate::util::{horizontal_field, TypedNodeRef};
-use web_sys::HtmlTextAreaElement;
+use crate::whitespace::{split_lines, unusual_space_name, Diagnostics, LineEnding};
+use encoding_rs::SHIFT_JIS;
+use std::collections::HashSet;
+use unicode_segmentation::UnicodeSegmentation;
+use web_sys::{FileList, HtmlTextAreaElement};
 use yew::prelude::*;
+use yew::services::reader::{FileData, ReaderService, ReaderTask};
 
 pub struct Model {
     link: ComponentLink<Self>,
     props: Props,
     input: TypedNodeRef<HtmlTextAreaElement>,
+    reader: Option<ReaderTask>,
 }
 
+#[derive(Clone, Copy, PartialEq)]
+pub enum Mode {
+    Counts,
+    Twitter,
+    Sms,
+    Frequency,
+    Lines,
+}
+
+const MODES: &[(Mode, &str)] = &[
+    (Mode::Counts, "Counts"),
+    (Mode::Twitter, "Twitter"),
+    (Mode::Sms, "SMS"),
+    (Mode::Frequency, "Frequency"),
+    (Mode::Lines, "Lines"),
+];
+
+/// Line numbers listed per diagnostic before the rest are summarized.
+const LINE_LINKS: usize = 50;
+
+/// Rows shown in the frequency table; the CSV export has all of them.
+const FREQUENCY_ROWS: usize = 100;
+
 #[derive(Properties, Clone)]
 pub struct Props {
+    #[prop_or(Mode::Counts)]
+    mode: Mode,
+    #[prop_or_default]
+    text: String,
+    #[prop_or(Unit::Words)]
+    unit: Unit,
+    #[prop_or(2)]
+    n: usize,
+    #[prop_or_default]
+    fold_case: bool,
+    #[prop_or_default]
+    stop_words: bool,
+    #[prop_or_default]
+    custom_stop_words: String,
+    #[prop_or(SortKey::Count)]
+    sort_key: SortKey,
+    #[prop_or(true)]
+    descending: bool,
+    #[prop_or_default]
+    graphemes: usize,
     #[prop_or_default]
     chars: usize,
     #[prop_or_default]
+    utf16: usize,
+    #[prop_or_default]
+    utf8: usize,
+    #[prop_or_default]
+    sjis: usize,
+    #[prop_or_default]
+    unmappable: usize,
+    #[prop_or_default]
+    non_whitespace: usize,
+    #[prop_or_default]
+    non_newline: usize,
+    #[prop_or_default]
     words: usize,
     #[prop_or_default]
     lines: usize,
+    #[prop_or_default]
+    japanese: japanese::Stats,
+    #[prop_or_default]
+    manuscript_lines: usize,
 }
 
 pub enum Msg {
     Input,
+    Open(FileList),
+    Loaded(FileData),
+    Jump(usize),
+    Mode(Mode),
+    Unit(Unit),
+    N(String),
+    FoldCase(bool),
+    StopWords(bool),
+    CustomStopWords(String),
+    Sort(SortKey),
+}
+
+impl Model {
+    fn set_text(&mut self, txt: String) {
+        let mut chars = 0;
+        let mut utf16 = 0;
+        let mut sjis = 0;
+        let mut unmappable = 0;
+        let mut words = 0;
+
+        let mut prev_ws = true;
+
+        for c in txt.chars() {
+            chars += 1;
+            utf16 += c.len_utf16();
+
+            let mut buf = [0; 4];
+            let (bytes, _, error) = SHIFT_JIS.encode(c.encode_utf8(&mut buf));
+            if error {
+                unmappable += 1;
+            } else {
+                sjis += bytes.len();
+            }
+
+            if prev_ws && !c.is_whitespace() {
+                words += 1;
+            }
+            prev_ws = c.is_whitespace();
+        }
+
+        let graphemes: Vec<&str> = txt.graphemes(true).collect();
+        self.props.graphemes = graphemes.len();
+        self.props.non_whitespace = graphemes
+            .iter()
+            .filter(|g| !g.chars().all(char::is_whitespace))
+            .count();
+        self.props.non_newline = graphemes
+            .iter()
+            .filter(|g| !g.contains(['\n', '\r']))
+            .count();
+        self.props.chars = chars;
+        self.props.utf16 = utf16;
+        self.props.utf8 = txt.len();
+        self.props.sjis = sjis;
+        self.props.unmappable = unmappable;
+        self.props.words = words;
+        self.props.lines = split_lines(&txt)
+            .iter()
+            .filter(|(_, ending)| !ending.is_empty())
+            .count();
+        self.props.japanese = japanese::Stats::new(&txt);
+        self.props.manuscript_lines = manuscript_lines(&txt, MANUSCRIPT_COLUMNS);
+        self.props.text = txt;
+    }
+
+    /// Selects line `line` (1-based) of the textarea and scrolls it into
+    /// view. The textarea holds LF line endings whatever the file had, but
+    /// line numbers stay the same.
+    fn jump(&self, line: usize) {
+        let textarea = self.input.get();
+        let value = textarea.value();
+        let mut start = 0;
+        let mut end = 0;
+        let mut lines = 0;
+        for (i, l) in value.split('\n').enumerate() {
+            let len = l.encode_utf16().count() as u32;
+            if i + 1 == line {
+                end = start + len;
+            } else if i + 1 < line {
+                start += len + 1;
+            }
+            lines += 1;
+        }
+        let _ = textarea.focus();
+        let _ = textarea.set_selection_range(start, end);
+        let height = textarea.scroll_height() as f64;
+        textarea.set_scroll_top((height * (line - 1) as f64 / lines as f64) as i32);
+    }
+
+    fn view_twitter(&self) -> Html {
+        let tweet = tweet_length(&self.props.text);
+        let remaining = MAX_WEIGHTED_LENGTH as i64 - tweet.length as i64;
+        let remaining_classes = if remaining < 0 {
+            "input is-danger"
+        } else {
+            "input"
+        };
+        let (fits, overflow) = tweet
+            .text
+            .split_at(tweet.overflow.unwrap_or(tweet.text.len()));
+
+        html! {
+            <>
+            { horizontal_field("Length", html!{<input class="input" type="text" value=format!("{} / {}", tweet.length, MAX_WEIGHTED_LENGTH) readonly=true/>}) }
+            {
+                horizontal_field("Remaining", html!{
+                    <input class=remaining_classes type="text" value=remaining readonly=true/>
+                })
+            }
+            {
+                horizontal_field("Preview", html!{
+                    <>
+                    <div class="box" style="white-space: pre-wrap; word-break: break-all;">
+                        { fits }
+                        <mark class="has-background-danger-light">{ overflow }</mark>
+                    </div>
+                    <p class="help">{"URLs count as 23 characters, emoji and CJK characters as 2. The part past the limit is highlighted."}</p>
+                    </>
+                })
+            }
+            </>
+        }
+    }
+
+    fn view_sms(&self) -> Html {
+        let segments = sms::segments(&self.props.text);
+
+        // Group runs of characters by their cost so that only the
+        // characters that matter get marked.
+        let mut runs: Vec<(Option<usize>, String)> = vec![];
+        for c in self.props.text.chars() {
+            let septets = gsm_septets(c);
+            match runs.last_mut() {
+                Some((last, run)) if *last == septets => run.push(c),
+                _ => runs.push((septets, c.to_string())),
+            }
+        }
+        let forcing: Vec<String> = {
+            let mut chars: Vec<char> = self
+                .props
+                .text
+                .chars()
+                .filter(|&c| gsm_septets(c).is_none())
+                .collect();
+            chars.sort_unstable();
+            chars.dedup();
+            chars
+                .iter()
+                .map(|c| format!("{} U+{:04X}", c, *c as u32))
+                .collect()
+        };
+
+        html! {
+            <>
+            { horizontal_field("Encoding", html!{<input class="input" type="text" value=segments.encoding.name() readonly=true/>}) }
+            { horizontal_field("Length", html!{<input class="input" type="text" value=format!("{} {}", segments.units, segments.unit()) readonly=true/>}) }
+            { horizontal_field("Segments", html!{<input class="input" type="text" value=segments.segments readonly=true/>}) }
+            { horizontal_field("Remaining", html!{<input class="input" type="text" value=format!("{} {} in the last segment", segments.remaining, segments.unit()) readonly=true/>}) }
+            {
+                horizontal_field("Forcing UCS-2", html!{
+                    <input class=if forcing.is_empty() { "input" } else { "input is-danger" } type="text" value=forcing.join(", ") readonly=true/>
+                })
+            }
+            {
+                horizontal_field("Preview", html!{
+                    <>
+                    <div class="box" style="white-space: pre-wrap; word-break: break-all;">
+                    {
+                        for runs.into_iter().map(|(septets, run)| match septets {
+                            Some(1) => html! { { run } },
+                            Some(_) => html! { <mark class="has-background-warning-light">{ run }</mark> },
+                            None => html! { <mark class="has-background-danger-light">{ run }</mark> },
+                        })
+                    }
+                    </div>
+                    <p class="help">{"Red characters are not in the GSM 03.38 alphabet and force UCS-2. Yellow ones are in its extension table and take 2 septets. A single message holds 160 septets or 70 UCS-2 units; with concatenation headers each part holds 153 or 67."}</p>
+                    </>
+                })
+            }
+            </>
+        }
+    }
+
+    fn view_frequency(&self) -> Html {
+        let props = &self.props;
+        let mut stop_words: HashSet<String> = props
+            .custom_stop_words
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        if props.stop_words {
+            stop_words.extend(STOP_WORDS.iter().map(|w| w.to_string()));
+        }
+        let options = frequency::Options {
+            unit: props.unit,
+            n: props.n,
+            fold_case: props.fold_case,
+            stop_words,
+        };
+        let entries =
+            frequency::frequencies(&props.text, &options, props.sort_key, props.descending);
+        let total: usize = entries.iter().map(|e| e.count).sum();
+        let csv = format!(
+            "data:text/csv;charset=utf-8,{}",
+            urlencoding::encode(&frequency::to_csv(&entries, total))
+        );
+
+        let fold_case = props.fold_case;
+        let use_stop_words = props.stop_words;
+        let header = |key: SortKey, name: &str| {
+            let arrow = match (props.sort_key == key, props.descending) {
+                (false, _) => "",
+                (true, true) => " ▼",
+                (true, false) => " ▲",
+            };
+            html! {
+                <th><a onclick=self.link.callback(move |_| Msg::Sort(key))>{ format!("{}{}", name, arrow) }</a></th>
+            }
+        };
+
+        html! {
+            <>
+            {
+                horizontal_field("Count", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Unit(UNITS[s.selected_index().max(0) as usize]),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for UNITS.iter().map(|&unit| html!{
+                                    <option selected=props.unit == unit>{ unit.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                if props.unit.has_n() {
+                    horizontal_field("n", html!{
+                        <input class="input" type="number" min="1" value=props.n
+                            oninput=self.link.callback(|e: InputData| Msg::N(e.value))/>
+                    })
+                } else {
+                    html! {}
+                }
+            }
+            {
+                horizontal_field("", html!{
+                    <>
+                    <label class="checkbox">
+                        <input type="checkbox" checked=fold_case
+                            onchange=self.link.callback(move |_| Msg::FoldCase(!fold_case))/>
+                        {" Fold case"}
+                    </label>
+                    <br/>
+                    <label class="checkbox">
+                        <input type="checkbox" checked=use_stop_words
+                            onchange=self.link.callback(move |_| Msg::StopWords(!use_stop_words))/>
+                        {" Ignore English stop words"}
+                    </label>
+                    </>
+                })
+            }
+            {
+                horizontal_field("Stop words", html!{
+                    <>
+                    <input class="input" type="text" placeholder="foo, bar" value=&props.custom_stop_words
+                        oninput=self.link.callback(|e: InputData| Msg::CustomStopWords(e.value))/>
+                    <p class="help">{"Extra words to ignore, separated by commas or spaces. Applies to words and word n-grams."}</p>
+                    </>
+                })
+            }
+            {
+                horizontal_field("", html!{
+                    <a class="button" href=csv download="frequency.csv">{"Export CSV"}</a>
+                })
+            }
+
+            <p class="block">
+                { format!("{} distinct of {} total", entries.len(), total) }
+                {
+                    if entries.len() > FREQUENCY_ROWS {
+                        format!(", showing the first {}", FREQUENCY_ROWS)
+                    } else {
+                        String::new()
+                    }
+                }
+            </p>
+            <table class="table is-striped is-narrow is-fullwidth">
+                <thead>
+                    <tr>
+                        { header(SortKey::Token, "Token") }
+                        { header(SortKey::Count, "Count") }
+                        <th>{"%"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                {
+                    for entries.iter().take(FREQUENCY_ROWS).map(|entry| html! {
+                        <tr>
+                            <td style="white-space: pre"><code>{ &entry.token }</code></td>
+                            <td>{ entry.count }</td>
+                            <td>{ format!("{:.2}", entry.count as f64 * 100.0 / total as f64) }</td>
+                        </tr>
+                    })
+                }
+                </tbody>
+            </table>
+            </>
+        }
+    }
+
+    fn line_links(&self, lines: &[usize]) -> Html {
+        html! {
+            <div class="tags">
+            {
+                for lines.iter().take(LINE_LINKS).map(|&line| html! {
+                    <a class="tag is-link is-light" onclick=self.link.callback(move |_| Msg::Jump(line))>{ line }</a>
+                })
+            }
+            {
+                if lines.len() > LINE_LINKS {
+                    html! { <span class="tag">{ format!("and {} more", lines.len() - LINE_LINKS) }</span> }
+                } else {
+                    html! {}
+                }
+            }
+            </div>
+        }
+    }
+
+    fn line_field(&self, label: &str, summary: String, warn: bool, lines: &[usize]) -> Html {
+        horizontal_field(
+            label,
+            html! {
+                <>
+                <input class=if warn { "input is-danger" } else { "input" } type="text" value=summary readonly=true/>
+                { self.line_links(lines) }
+                </>
+            },
+        )
+    }
+
+    fn view_lines(&self) -> Html {
+        let d = Diagnostics::new(&self.props.text);
+        let plural = |n: usize| format!("{} line{}", n, if n == 1 { "" } else { "s" });
+
+        let ending = match d.ending() {
+            LineEnding::Mixed => format!("Mixed: {} LF, {} CRLF, {} CR", d.lf, d.crlf, d.cr),
+            ending => ending.name().to_string(),
+        };
+        let ending_classes = if d.ending() == LineEnding::Mixed {
+            "input is-danger"
+        } else {
+            "input"
+        };
+        let last: Vec<usize> = if d.final_newline || d.lines == 0 {
+            vec![]
+        } else {
+            vec![d.lines]
+        };
+        let final_newline = if last.is_empty() { "Yes" } else { "Missing" };
+        let longest = d.longest.map_or("".to_string(), |(line, length)| {
+            format!("Line {}, {} characters", line, length)
+        });
+        let longest_line: Vec<usize> = d.longest.iter().map(|&(line, _)| line).collect();
+        let mut unusual_lines: Vec<usize> =
+            d.unusual_spaces.iter().map(|&(line, _)| line).collect();
+        unusual_lines.dedup();
+        let mut unusual_kinds: Vec<char> = d.unusual_spaces.iter().map(|&(_, c)| c).collect();
+        unusual_kinds.sort_unstable();
+        unusual_kinds.dedup();
+        let unusual = unusual_kinds
+            .iter()
+            .map(|&c| {
+                let n = d.unusual_spaces.iter().filter(|&&(_, s)| s == c).count();
+                format!("{} U+{:04X} × {}", unusual_space_name(c), c as u32, n)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let unusual = if unusual.is_empty() {
+            "None".to_string()
+        } else {
+            unusual
+        };
+
+        html! {
+            <>
+            {
+                horizontal_field("Line endings", html!{
+                    <>
+                    <input class=ending_classes type="text" value=ending readonly=true/>
+                    { self.line_links(&d.odd_endings) }
+                    <p class="help">{"Browsers turn line endings typed or pasted into the input into LF. Open a file to check its own."}</p>
+                    </>
+                })
+            }
+            { self.line_field("Final newline", final_newline.to_string(), !last.is_empty(), &last) }
+            { horizontal_field("BOM", html!{<input class="input" type="text" value=if d.bom { "Yes (U+FEFF)" } else { "No" } readonly=true/>}) }
+            { horizontal_field("Lines", html!{<input class="input" type="text" value=d.lines readonly=true/>}) }
+            { self.line_field("Longest line", longest, false, &longest_line) }
+            { self.line_field("Trailing whitespace", plural(d.trailing.len()), !d.trailing.is_empty(), &d.trailing) }
+            { self.line_field("Tab indentation", plural(d.tab_indented.len()), false, &d.tab_indented) }
+            { self.line_field("Space indentation", plural(d.space_indented.len()), false, &d.space_indented) }
+            { self.line_field("Mixed indentation", plural(d.mixed_indent.len()), !d.mixed_indent.is_empty(), &d.mixed_indent) }
+            { self.line_field("Blank lines", plural(d.blank.len()), false, &d.blank) }
+            { self.line_field("Unusual spaces", unusual, !d.unusual_spaces.is_empty(), &unusual_lines) }
+            </>
+        }
+    }
+
+    fn view_counts(&self) -> Html {
+        html! {
+            <>
+            { horizontal_field("Chars (graphemes)", html!{<input class="input" type="text" value=self.props.graphemes readonly=true/>}) }
+            { horizontal_field("Code points", html!{<input class="input" type="text" value=self.props.chars readonly=true/>}) }
+            { horizontal_field("UTF-16 units", html!{<input class="input" type="text" value=self.props.utf16 readonly=true/>}) }
+            { horizontal_field("UTF-8 bytes", html!{<input class="input" type="text" value=self.props.utf8 readonly=true/>}) }
+            {
+                horizontal_field("Shift_JIS bytes", html!{
+                    <>
+                    <input class="input" type="text" value=self.props.sjis readonly=true/>
+                    {
+                        if self.props.unmappable > 0 {
+                            html! { <p class="help is-danger">{ format!("{} characters cannot be encoded in Shift_JIS and are not counted", self.props.unmappable) }</p> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    </>
+                })
+            }
+            { horizontal_field("Words", html!{<input class="input" type="text" value=self.props.words readonly=true/>}) }
+            { horizontal_field("Lines", html!{<input class="input" type="text" value=self.props.lines readonly=true/>}) }
+            { horizontal_field("Without whitespace", html!{<input class="input" type="text" value=self.props.non_whitespace readonly=true/>}) }
+            { horizontal_field("Without newlines", html!{<input class="input" type="text" value=self.props.non_newline readonly=true/>}) }
+
+            <h2 class="title is-4">{"Japanese"}</h2>
+            { horizontal_field("ひらがな", html!{<input class="input" type="text" value=self.props.japanese.hiragana readonly=true/>}) }
+            { horizontal_field("カタカナ", html!{<input class="input" type="text" value=self.props.japanese.katakana readonly=true/>}) }
+            { horizontal_field("漢字", html!{<input class="input" type="text" value=self.props.japanese.kanji readonly=true/>}) }
+            { horizontal_field("全角", html!{<input class="input" type="text" value=self.props.japanese.fullwidth readonly=true/>}) }
+            { horizontal_field("半角", html!{<input class="input" type="text" value=self.props.japanese.halfwidth readonly=true/>}) }
+            { horizontal_field("句読点・記号", html!{<input class="input" type="text" value=self.props.japanese.punctuation readonly=true/>}) }
+            { self.view_manuscript() }
+            </>
+        }
+    }
+
+    fn view_manuscript(&self) -> Html {
+        let lines = self.props.manuscript_lines;
+        let pages = lines.div_ceil(MANUSCRIPT_ROWS);
+        let text = format!(
+            "{:.1} pages ({} pages, {} lines)",
+            lines as f64 / MANUSCRIPT_ROWS as f64,
+            pages,
+            lines
+        );
+        horizontal_field(
+            "原稿用紙",
+            html! {
+                <>
+                <input class="input" type="text" value=text readonly=true/>
+                <p class="help">{"400字詰め (20×20). Each paragraph starts a new line; 句読点 and closing brackets hang at line ends."}</p>
+                </>
+            },
+        )
+    }
 }
 
 impl Component for Model {
@@ -31,6 +571,7 @@ impl Component for Model {
             link,
             props,
             input: Default::default(),
+            reader: None,
         }
     }
 
@@ -38,29 +579,42 @@ impl Component for Model {
         match msg {
             Msg::Input => {
                 let txt = self.input.get().value();
-
-                let mut chars = 0;
-                let mut words = 0;
-                let mut lines = 0;
-
-                let mut prev_ws = true;
-
-                for c in txt.chars() {
-                    chars += 1;
-
-                    if prev_ws && !c.is_whitespace() {
-                        words += 1;
-                    }
-                    prev_ws = c.is_whitespace();
-
-                    if c == '\n' {
-                        lines += 1;
-                    }
+                self.set_text(txt);
+            }
+            Msg::Open(files) => {
+                if let Some(file) = files.get(0) {
+                    let callback = self.link.callback(Msg::Loaded);
+                    self.reader = ReaderService::new().read_file(file, callback).ok();
+                }
+                return false;
+            }
+            Msg::Loaded(data) => {
+                self.reader = None;
+                let txt = String::from_utf8_lossy(&data.content).into_owned();
+                self.input.get().set_value(&txt);
+                self.set_text(txt);
+            }
+            Msg::Jump(line) => {
+                self.jump(line);
+                return false;
+            }
+            Msg::Mode(mode) => self.props.mode = mode,
+            Msg::Unit(unit) => self.props.unit = unit,
+            Msg::N(n) => {
+                if let Ok(n) = n.trim().parse::<usize>() {
+                    self.props.n = n.max(1);
+                }
+            }
+            Msg::FoldCase(fold_case) => self.props.fold_case = fold_case,
+            Msg::StopWords(stop_words) => self.props.stop_words = stop_words,
+            Msg::CustomStopWords(words) => self.props.custom_stop_words = words,
+            Msg::Sort(key) => {
+                if self.props.sort_key == key {
+                    self.props.descending = !self.props.descending;
+                } else {
+                    self.props.sort_key = key;
+                    self.props.descending = key == SortKey::Count;
                 }
-
-                self.props.chars = chars;
-                self.props.words = words;
-                self.props.lines = lines;
             }
         }
         true
@@ -79,11 +633,41 @@ impl Component for Model {
                     <textarea class="textarea" oninput=self.link.callback(|_| Msg::Input) ref=self.input.node_ref() />
                 </div>
             </div>
+            <div class="field">
+                <div class="file is-small">
+                    <label class="file-label">
+                        <input class="file-input" type="file" onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Files(files) => Msg::Open(files),
+                            _ => unreachable!(),
+                        })/>
+                        <span class="file-cta">
+                            <span class="file-label">{"Open a file…"}</span>
+                        </span>
+                    </label>
+                </div>
+            </div>
 
-            { horizontal_field("Chars", html!{<input class="input" type="text" value=self.props.chars readonly=true/>}) }
-            { horizontal_field("Words", html!{<input class="input" type="text" value=self.props.words readonly=true/>}) }
-            { horizontal_field("Lines", html!{<input class="input" type="text" value=self.props.lines readonly=true/>}) }
+            <div class="tabs is-boxed">
+                <ul>
+                {
+                    for MODES.iter().map(|&(mode, name)| html! {
+                        <li class=if self.props.mode == mode { "is-active" } else { "" }>
+                            <a onclick=self.link.callback(move |_| Msg::Mode(mode))>{ name }</a>
+                        </li>
+                    })
+                }
+                </ul>
+            </div>
 
+            {
+                match self.props.mode {
+                    Mode::Counts => self.view_counts(),
+                    Mode::Twitter => self.view_twitter(),
+                    Mode::Sms => self.view_sms(),
+                    Mode::Frequency => self.view_frequency(),
+                    Mode::Lines => self.view_lines(),
+                }
+            }
             </>
         }
     }
@@ -0,0 +1,522 @@
+use crate::util::horizontal_field;
+use yew::prelude::*;
+
+const SETUID: u32 = 0o4000;
+const SETGID: u32 = 0o2000;
+const STICKY: u32 = 0o1000;
+
+/// The classes of `ls -l` output, with the bits each one owns in a symbolic
+/// `chmod` expression.
+const CLASSES: &[(&str, char, u32)] = &[
+    ("User", 'u', 0o4700),
+    ("Group", 'g', 0o2070),
+    ("Other", 'o', 0o1007),
+];
+
+const PERMISSIONS: &[(&str, u32)] = &[("Read", 0o4), ("Write", 0o2), ("Execute", 0o1)];
+
+/// The special bits, in the same order as `CLASSES`, with what they do.
+const SPECIALS: &[(&str, u32, &str)] = &[
+    ("setuid", SETUID, "executables run as the file's owner"),
+    (
+        "setgid",
+        SETGID,
+        "executables run as the file's group; in a directory, new files inherit its group",
+    ),
+    (
+        "sticky",
+        STICKY,
+        "in a directory, only a file's owner can delete or rename it",
+    ),
+];
+
+fn to_symbolic(mode: u32, directory: bool) -> String {
+    let mut s = String::new();
+    s.push(if directory { 'd' } else { '-' });
+    for (i, &(_, bit, _)) in SPECIALS.iter().enumerate() {
+        let bits = (mode >> (6 - 3 * i)) & 7;
+        let special = mode & bit != 0;
+        s.push(if bits & 4 != 0 { 'r' } else { '-' });
+        s.push(if bits & 2 != 0 { 'w' } else { '-' });
+        let (on, off) = if i == 2 { ('t', 'T') } else { ('s', 'S') };
+        s.push(match (bits & 1 != 0, special) {
+            (true, true) => on,
+            (false, true) => off,
+            (true, false) => 'x',
+            (false, false) => '-',
+        });
+    }
+    s
+}
+
+/// Parses `ls -l` style permissions such as `rwsr-xr-x` or `drwxrwxrwt`.
+/// Returns the mode and whether the file type is a directory.
+fn parse_symbolic(text: &str) -> Option<(u32, bool)> {
+    let chars: Vec<char> = text.trim().chars().collect();
+    let (directory, chars) = match chars.len() {
+        9 => (false, &chars[..]),
+        10 if "-dlcbps".contains(chars[0]) => (chars[0] == 'd', &chars[1..]),
+        _ => return None,
+    };
+
+    let mut mode = 0;
+    for (i, triple) in chars.chunks(3).enumerate() {
+        let shift = 6 - 3 * i;
+        match triple[0] {
+            'r' => mode |= 4 << shift,
+            '-' => {}
+            _ => return None,
+        }
+        match triple[1] {
+            'w' => mode |= 2 << shift,
+            '-' => {}
+            _ => return None,
+        }
+        let (on, off) = if i == 2 { ('t', 'T') } else { ('s', 'S') };
+        match triple[2] {
+            'x' => mode |= 1 << shift,
+            '-' => {}
+            c if c == on => mode |= (1 << shift) | SPECIALS[i].1,
+            c if c == off => mode |= SPECIALS[i].1,
+            _ => return None,
+        }
+    }
+    Some((mode, directory))
+}
+
+fn parse_octal(text: &str) -> Option<u32> {
+    let text = text.trim();
+    let text = text.strip_prefix("0o").unwrap_or(text);
+    if text.is_empty() || text.len() > 5 {
+        return None;
+    }
+    u32::from_str_radix(text, 8)
+        .ok()
+        .filter(|&mode| mode <= 0o7777)
+}
+
+/// Applies a `chmod` mode such as `u+x,go-w`, `a=rX` or `g=u` to `mode`,
+/// following GNU chmod. A clause without `u`, `g`, `o` or `a` affects
+/// everyone; the umask is not taken into account.
+///
+/// As in GNU chmod, a directory keeps its setuid and setgid bits through
+/// `=` unless the clause mentions `s`, and through octal modes of up to four
+/// digits; `00755` clears them. Files lose them whenever their class is
+/// assigned.
+fn apply_chmod(mode: u32, directory: bool, expr: &str) -> Result<u32, String> {
+    let mut mode = mode;
+
+    for clause in expr.split(',') {
+        let clause = clause.trim();
+        if clause.is_empty() {
+            return Err("empty clause".to_string());
+        }
+        if clause.chars().all(|c| c.is_ascii_digit()) {
+            let value =
+                parse_octal(clause).ok_or_else(|| format!("invalid octal mode '{}'", clause))?;
+            mode = if directory && clause.len() < 5 {
+                value | (mode & (SETUID | SETGID))
+            } else {
+                value
+            };
+            continue;
+        }
+
+        let mut chars = clause.chars().peekable();
+        let mut who = 0;
+        while let Some(&c) = chars.peek() {
+            who |= match CLASSES.iter().find(|&&(_, name, _)| name == c) {
+                Some(&(_, _, bits)) => bits,
+                None if c == 'a' => 0o7777,
+                None => break,
+            };
+            chars.next();
+        }
+        if who == 0 {
+            who = 0o7777;
+        }
+
+        if chars.peek().is_none() {
+            return Err(format!("missing '+', '-' or '=' in '{}'", clause));
+        }
+        while let Some(op) = chars.next() {
+            if !"+-=".contains(op) {
+                return Err(format!("unexpected '{}' in '{}'", op, clause));
+            }
+
+            let mut bits = 0;
+            let mut preserved = if directory { SETUID | SETGID } else { 0 };
+            while let Some(&c) = chars.peek() {
+                if "+-=".contains(c) {
+                    break;
+                }
+                chars.next();
+                bits |= match c {
+                    'r' => 0o444,
+                    'w' => 0o222,
+                    'x' => 0o111,
+                    'X' if directory || mode & 0o111 != 0 => 0o111,
+                    'X' => 0,
+                    's' => {
+                        preserved = 0;
+                        SETUID | SETGID
+                    }
+                    't' => STICKY,
+                    'u' | 'g' | 'o' => {
+                        let shift = match c {
+                            'u' => 6,
+                            'g' => 3,
+                            _ => 0,
+                        };
+                        ((mode >> shift) & 7) * 0o111
+                    }
+                    _ => return Err(format!("unexpected '{}' in '{}'", c, clause)),
+                };
+            }
+            bits &= who;
+
+            mode = match op {
+                '+' => mode | bits,
+                '-' => mode & !bits,
+                _ => (mode & (!who | preserved)) | bits,
+            };
+        }
+    }
+
+    Ok(mode)
+}
+
+/// Describes a mode in words, one line per special bit and per class.
+fn describe(mode: u32) -> Vec<String> {
+    let mut lines: Vec<String> = SPECIALS
+        .iter()
+        .filter(|&&(_, bit, _)| mode & bit != 0)
+        .map(|&(name, _, meaning)| format!("{}: {}", name, meaning))
+        .collect();
+    for (i, &(class, _, _)) in CLASSES.iter().enumerate() {
+        let bits = (mode >> (6 - 3 * i)) & 7;
+        let permissions: Vec<String> = PERMISSIONS
+            .iter()
+            .filter(|&&(_, bit)| bits & bit != 0)
+            .map(|&(name, _)| name.to_lowercase())
+            .collect();
+        lines.push(format!(
+            "{}: {}",
+            class,
+            if permissions.is_empty() {
+                "no access".to_string()
+            } else {
+                permissions.join(", ")
+            }
+        ));
+    }
+    lines
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or(0o644)]
+    mode: u32,
+    #[prop_or_default]
+    directory: bool,
+    #[prop_or_default]
+    octal_error: bool,
+    #[prop_or_default]
+    symbolic_error: bool,
+    #[prop_or_default]
+    expression: String,
+}
+
+pub enum Msg {
+    Octal(String),
+    Symbolic(String),
+    ToggleBit(u32),
+    Directory,
+    Expression(String),
+    Apply,
+}
+
+impl Model {
+    fn set_mode(&mut self, mode: u32) {
+        self.props.mode = mode;
+        self.props.octal_error = false;
+        self.props.symbolic_error = false;
+    }
+
+    fn checkbox(&self, bit: u32) -> Html {
+        html! {
+            <input type="checkbox"
+                checked=self.props.mode & bit != 0
+                onchange=self.link.callback(move |_| Msg::ToggleBit(bit))/>
+        }
+    }
+
+    fn view_matrix(&self) -> Html {
+        html! {
+            <table class="table is-narrow">
+                <thead>
+                    <tr>
+                        <th></th>
+                        { for PERMISSIONS.iter().map(|&(name, _)| html! { <th>{ name }</th> }) }
+                        <th>{"Special"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                {
+                    for CLASSES.iter().enumerate().map(|(i, &(class, _, _))| {
+                        let shift = 6 - 3 * i;
+                        let (special, bit, _) = SPECIALS[i];
+                        html! {
+                            <tr>
+                                <th>{ class }</th>
+                                { for PERMISSIONS.iter().map(|&(_, p)| html! { <td>{ self.checkbox(p << shift) }</td> }) }
+                                <td>
+                                    <label class="checkbox">
+                                        { self.checkbox(bit) }
+                                        { format!(" {}", special) }
+                                    </label>
+                                </td>
+                            </tr>
+                        }
+                    })
+                }
+                </tbody>
+            </table>
+        }
+    }
+
+    fn view_chmod(&self) -> Html {
+        let result = if self.props.expression.trim().is_empty() {
+            None
+        } else {
+            Some(apply_chmod(
+                self.props.mode,
+                self.props.directory,
+                &self.props.expression,
+            ))
+        };
+        let mut classes = vec!["input"];
+        if let Some(Err(_)) = result {
+            classes.push("is-danger");
+        }
+
+        html! {
+            <>
+            {
+                horizontal_field("chmod", html!{
+                    <>
+                    <input class=classes type="text" placeholder="u+x,go-w"
+                        value=self.props.expression.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::Expression(e.value))/>
+                    {
+                        if let Some(Err(e)) = &result {
+                            html! { <p class="help is-danger">{ e }</p> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    </>
+                })
+            }
+            {
+                horizontal_field("Result", html!{
+                    <div class="field has-addons">
+                        <div class="control is-expanded">
+                            <input class="input" type="text" readonly=true
+                                value=match result {
+                                    Some(Ok(mode)) => format!("{:04o} ({})", mode, to_symbolic(mode, self.props.directory)),
+                                    _ => "".to_string(),
+                                }/>
+                        </div>
+                        <div class="control">
+                            <button class="button"
+                                disabled=!matches!(result, Some(Ok(_)))
+                                onclick=self.link.callback(|_| Msg::Apply)>
+                                {"Use as mode"}
+                            </button>
+                        </div>
+                    </div>
+                })
+            }
+            </>
+        }
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Octal(text) => match parse_octal(&text) {
+                Some(mode) => self.set_mode(mode),
+                None => self.props.octal_error = true,
+            },
+            Msg::Symbolic(text) => match parse_symbolic(&text) {
+                Some((mode, directory)) => {
+                    self.set_mode(mode);
+                    self.props.directory = directory;
+                }
+                None => self.props.symbolic_error = true,
+            },
+            Msg::ToggleBit(bit) => self.set_mode(self.props.mode ^ bit),
+            Msg::Directory => self.props.directory = !self.props.directory,
+            Msg::Expression(text) => self.props.expression = text,
+            Msg::Apply => {
+                if let Ok(mode) = apply_chmod(
+                    self.props.mode,
+                    self.props.directory,
+                    &self.props.expression,
+                ) {
+                    self.set_mode(mode);
+                    self.props.expression.clear();
+                }
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let mut octal_classes = vec!["input"];
+        if self.props.octal_error {
+            octal_classes.push("is-danger");
+        }
+        let mut symbolic_classes = vec!["input"];
+        if self.props.symbolic_error {
+            symbolic_classes.push("is-danger");
+        }
+
+        html! {
+            <>
+            {
+                horizontal_field("Octal", html!{
+                    <input class=octal_classes type="text" placeholder="0755"
+                        value=format!("{:04o}", self.props.mode)
+                        oninput=self.link.callback(|e: InputData| Msg::Octal(e.value))/>
+                })
+            }
+            {
+                horizontal_field("Symbolic", html!{
+                    <input class=symbolic_classes type="text" placeholder="-rwxr-xr-x"
+                        value=to_symbolic(self.props.mode, self.props.directory)
+                        oninput=self.link.callback(|e: InputData| Msg::Symbolic(e.value))/>
+                })
+            }
+            {
+                horizontal_field("", html!{
+                    <label class="checkbox">
+                        <input type="checkbox"
+                            checked=self.props.directory
+                            onchange=self.link.callback(|_| Msg::Directory)/>
+                        {" Directory"}
+                    </label>
+                })
+            }
+            { horizontal_field("Permissions", self.view_matrix()) }
+            {
+                horizontal_field("Meaning", html!{
+                    <div class="content">
+                        <ul>
+                            { for describe(self.props.mode).into_iter().map(|line| html! { <li>{ line }</li> }) }
+                        </ul>
+                    </div>
+                })
+            }
+
+            <h2 class="title is-4">{"chmod"}</h2>
+            { self.view_chmod() }
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbolic_round_trips() {
+        for mode in 0..=0o7777 {
+            for &directory in &[false, true] {
+                let symbolic = to_symbolic(mode, directory);
+                assert_eq!(parse_symbolic(&symbolic), Some((mode, directory)));
+                assert_eq!(parse_symbolic(&symbolic[1..]), Some((mode, false)));
+            }
+        }
+        assert_eq!(to_symbolic(0o4755, false), "-rwsr-xr-x");
+        assert_eq!(to_symbolic(0o1777, true), "drwxrwxrwt");
+        assert_eq!(to_symbolic(0o2640, false), "-rw-r-S---");
+        assert_eq!(parse_symbolic("lrwxrwxrwx"), Some((0o777, false)));
+        assert_eq!(parse_symbolic("rwxrwxrwt"), Some((0o1777, false)));
+        assert_eq!(parse_symbolic("rwsrwxrwx-"), None);
+        assert_eq!(parse_symbolic("rwtrwxrwx"), None);
+        assert_eq!(parse_symbolic("xwxrwxrwx"), None);
+    }
+
+    #[test]
+    fn applies_symbolic_modes() {
+        let file = |mode, expr| apply_chmod(mode, false, expr);
+        assert_eq!(file(0o755, "u+s"), Ok(0o4755));
+        assert_eq!(file(0o755, "g+s"), Ok(0o2755));
+        assert_eq!(file(0o755, "+s"), Ok(0o6755));
+        assert_eq!(file(0o640, "g=u"), Ok(0o660));
+        assert_eq!(file(0o751, "o=g"), Ok(0o755));
+        assert_eq!(file(0o755, "a-x,o+t"), Ok(0o1644));
+        assert_eq!(file(0o644, "u+x,go-w"), Ok(0o744));
+        assert_eq!(file(0o644, "a=rX"), Ok(0o444));
+        assert_eq!(file(0o744, "a=rX"), Ok(0o555));
+        assert_eq!(apply_chmod(0o644, true, "a=rX"), Ok(0o555));
+        assert_eq!(file(0o644, "u=rwx,g=rx,o="), Ok(0o750));
+        assert_eq!(file(0o644, "u+x-w"), Ok(0o544));
+        assert_eq!(file(0o6755, "g=rx"), Ok(0o4755));
+        assert_eq!(file(0o6755, "755"), Ok(0o755));
+        assert_eq!(file(0o644, "0755"), Ok(0o755));
+    }
+
+    #[test]
+    fn directories_keep_setuid_and_setgid() {
+        // Matches GNU coreutils chmod 9.1.
+        let dir = |mode, expr| apply_chmod(mode, true, expr);
+        assert_eq!(dir(0o6755, "g=rx"), Ok(0o6755));
+        assert_eq!(dir(0o6755, "a=rx"), Ok(0o6555));
+        assert_eq!(dir(0o6755, "=rx"), Ok(0o6555));
+        assert_eq!(dir(0o6755, "u="), Ok(0o6055));
+        assert_eq!(dir(0o6755, "g=u"), Ok(0o6775));
+        assert_eq!(dir(0o6755, "o=t"), Ok(0o7750));
+        assert_eq!(dir(0o6755, "g=rx,g-s"), Ok(0o4755));
+        assert_eq!(dir(0o6755, "g=s"), Ok(0o6705));
+        assert_eq!(dir(0o6755, "u=rwx"), Ok(0o6755));
+        assert_eq!(dir(0o4755, "a=rwx,g+s"), Ok(0o6777));
+        assert_eq!(dir(0o6755, "755"), Ok(0o6755));
+        assert_eq!(dir(0o6755, "0755"), Ok(0o6755));
+        assert_eq!(dir(0o6755, "1755"), Ok(0o7755));
+        assert_eq!(dir(0o4755, "2755"), Ok(0o6755));
+        assert_eq!(dir(0o6755, "00755"), Ok(0o755));
+        assert_eq!(dir(0o4755, "02755"), Ok(0o2755));
+    }
+
+    #[test]
+    fn rejects_invalid_modes() {
+        assert!(apply_chmod(0o644, false, "").is_err());
+        assert!(apply_chmod(0o644, false, "u+x,").is_err());
+        assert!(apply_chmod(0o644, false, "u").is_err());
+        assert!(apply_chmod(0o644, false, "u+q").is_err());
+        assert!(apply_chmod(0o644, false, "z+x").is_err());
+        assert!(apply_chmod(0o644, false, "8").is_err());
+        assert!(apply_chmod(0o644, false, "17777").is_err());
+    }
+}
@@ -0,0 +1,368 @@
+use crate::unicode;
+use std::collections::HashMap;
+use unicode_script::{Script, ScriptExtension, UnicodeScript};
+use unicode_security::mixed_script::AugmentedScriptSet;
+use unicode_security::{
+    GeneralSecurityProfile, MixedScript, RestrictionLevel, RestrictionLevelDetection,
+};
+use yew::prelude::*;
+
+/// The TR39 skeleton. Two strings are confusable when their skeletons are
+/// equal.
+pub fn skeleton(s: &str) -> String {
+    unicode_security::skeleton(s).collect()
+}
+
+fn level_name(level: RestrictionLevel) -> &'static str {
+    match level {
+        RestrictionLevel::ASCIIOnly => "ASCII only",
+        RestrictionLevel::SingleScript => "Single script",
+        RestrictionLevel::HighlyRestrictive => "Highly restrictive",
+        RestrictionLevel::ModeratelyRestrictive => "Moderately restrictive",
+        RestrictionLevel::MinimallyRestrictive => "Minimally restrictive",
+        RestrictionLevel::Unrestricted => "Unrestricted",
+    }
+}
+
+fn level_help(level: RestrictionLevel) -> &'static str {
+    match level {
+        RestrictionLevel::ASCIIOnly => "Only ASCII characters.",
+        RestrictionLevel::SingleScript => "All characters come from a single script.",
+        RestrictionLevel::HighlyRestrictive => {
+            "Latin mixed with Han and Hiragana/Katakana, Han and Bopomofo, or Han and Hangul."
+        }
+        RestrictionLevel::ModeratelyRestrictive => {
+            "Latin mixed with one other recommended script other than Cyrillic or Greek."
+        }
+        RestrictionLevel::MinimallyRestrictive => {
+            "Mixes scripts in a way that is commonly used for spoofing."
+        }
+        RestrictionLevel::Unrestricted => "Contains characters not allowed in identifiers.",
+    }
+}
+
+/// The script most characters of a mixed-script string belong to, ignoring
+/// Common and Inherited. Ties go to the script seen first.
+fn dominant_script(s: &str) -> Option<Script> {
+    let mut counts: HashMap<Script, (usize, usize)> = HashMap::new();
+    for (i, c) in s.chars().enumerate() {
+        let script = c.script();
+        if script != Script::Common && script != Script::Inherited {
+            counts.entry(script).or_insert((0, i)).0 += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(_, (count, first))| (count, std::cmp::Reverse(first)))
+        .map(|(script, _)| script)
+}
+
+pub struct CharInfo {
+    pub c: char,
+    pub scripts: String,
+    pub prototype: String,
+    pub allowed: bool,
+    /// Outside the dominant script of a mixed-script string.
+    pub suspicious: bool,
+}
+
+pub fn analyze(s: &str) -> Vec<CharInfo> {
+    let dominant = if s.is_single_script() {
+        None
+    } else {
+        dominant_script(s)
+    };
+    s.chars()
+        .map(|c| {
+            let ext = ScriptExtension::from(c);
+            CharInfo {
+                c,
+                scripts: AugmentedScriptSet::for_char(c).to_string(),
+                prototype: skeleton(&c.to_string()),
+                allowed: c.identifier_allowed(),
+                suspicious: dominant.is_some_and(|script| {
+                    !ext.is_common() && !ext.is_inherited() && !ext.contains_script(script)
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Whether the input looks like a domain name, which is checked in its
+/// Unicode form.
+fn is_domain(s: &str) -> bool {
+    s.contains('.') && !s.contains(|c: char| c.is_whitespace() || c == '/' || c == '@')
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    input: String,
+    #[prop_or_default]
+    other: String,
+}
+
+pub enum Msg {
+    Input(String),
+    Other(String),
+}
+
+impl Model {
+    fn view_domain(&self) -> Html {
+        let input = self.props.input.trim();
+        if !is_domain(input) {
+            return html! {};
+        }
+        let (unicode, result) = idna::domain_to_unicode(input);
+        let ascii = idna::domain_to_ascii(input);
+        let error = result.is_err() || ascii.is_err();
+
+        html! {
+            <table class="table is-narrow">
+                <tbody>
+                    <tr>
+                        <th>{"Domain (Unicode)"}</th>
+                        <td><code>{ unicode }</code></td>
+                    </tr>
+                    <tr>
+                        <th>{"Domain (Punycode)"}</th>
+                        <td><code>{ ascii.unwrap_or_default() }</code></td>
+                    </tr>
+                    {
+                        if error {
+                            html! {
+                                <tr>
+                                    <td colspan=2 class="has-text-danger">{"Not a valid internationalized domain name"}</td>
+                                </tr>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </tbody>
+            </table>
+        }
+    }
+
+    fn view_compare(&self, text: &str) -> Html {
+        let other = self.props.other.trim();
+        let other = if is_domain(other) {
+            idna::domain_to_unicode(other).0
+        } else {
+            other.to_string()
+        };
+        let a = skeleton(text);
+        let b = skeleton(&other);
+
+        html! {
+            <>
+            <div class="field">
+                <label class="label">{"Compare with"}</label>
+                <div class="control">
+                    <input class="input" type="text" placeholder="paypal.com" value=&self.props.other
+                        oninput=self.link.callback(|e: InputData| Msg::Other(e.value))/>
+                </div>
+            </div>
+            {
+                if other.is_empty() {
+                    html! {}
+                } else if text == other {
+                    html! { <div class="notification">{"The strings are identical."}</div> }
+                } else if a == b {
+                    html! {
+                        <div class="notification is-danger">
+                            { format!("Confusable: both have the skeleton \"{}\".", a) }
+                        </div>
+                    }
+                } else {
+                    html! {
+                        <div class="notification is-success">
+                            { format!("Not confusable: the skeletons \"{}\" and \"{}\" differ.", a, b) }
+                        </div>
+                    }
+                }
+            }
+            </>
+        }
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(txt) => self.props.input = txt,
+            Msg::Other(txt) => self.props.other = txt,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let input = self.props.input.trim();
+        let text = if is_domain(input) {
+            idna::domain_to_unicode(input).0
+        } else {
+            input.to_string()
+        };
+        let level = text.as_str().detect_restriction_level();
+        let script_set = AugmentedScriptSet::for_str(&text);
+        let chars = analyze(&text);
+        let level_class = if level > RestrictionLevel::HighlyRestrictive {
+            "tag is-danger"
+        } else {
+            "tag is-success"
+        };
+        let script_class = if script_set.is_empty() {
+            "tag is-danger"
+        } else {
+            "tag is-success"
+        };
+
+        html! {
+            <>
+            <div class="field">
+                <label class="label">{"Text"}</label>
+                <div class="control">
+                    <input class="input" type="text" placeholder="pаypal.com" value=&self.props.input
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))/>
+                </div>
+                <p class="help">{"A username, identifier or domain name. Domains in Punycode are decoded first."}</p>
+            </div>
+
+            {
+                if text.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <>
+                        <div class="tags">
+                            <span class=level_class>{ level_name(level) }</span>
+                            <span class=script_class>
+                            {
+                                if script_set.is_empty() {
+                                    "Mixed script".to_string()
+                                } else {
+                                    format!("Scripts: {}", script_set)
+                                }
+                            }
+                            </span>
+                        </div>
+                        <p class="mb-3">{ level_help(level) }</p>
+                        { self.view_domain() }
+                        <table class="table is-narrow">
+                            <tbody>
+                                <tr>
+                                    <th>{"Skeleton"}</th>
+                                    <td><code>{ skeleton(&text) }</code></td>
+                                </tr>
+                            </tbody>
+                        </table>
+                        <table class="table is-narrow is-fullwidth">
+                            <thead>
+                                <tr>
+                                    <th>{"Character"}</th>
+                                    <th>{"Code point"}</th>
+                                    <th>{"Name"}</th>
+                                    <th>{"Scripts"}</th>
+                                    <th>{"Prototype"}</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                { for chars.iter().map(view_char) }
+                            </tbody>
+                        </table>
+                        </>
+                    }
+                }
+            }
+
+            { self.view_compare(&text) }
+            </>
+        }
+    }
+}
+
+fn view_char(info: &CharInfo) -> Html {
+    let c = info.c;
+    let confusable = info.prototype != c.to_string();
+    let row_class = if info.suspicious {
+        "has-background-danger-light"
+    } else {
+        ""
+    };
+
+    html! {
+        <tr class=row_class>
+            <td>{ c }</td>
+            <td><code>{ format!("U+{:04X}", c as u32) }</code></td>
+            <td>
+                { unicode::name(c) }
+                {
+                    if info.allowed {
+                        html! {}
+                    } else {
+                        html! { <span class="tag is-warning is-light ml-2">{"not allowed in identifiers"}</span> }
+                    }
+                }
+            </td>
+            <td>{ &info.scripts }</td>
+            <td>
+            {
+                if confusable {
+                    html! { <code>{ format!("{} ({})", info.prototype, unicode::code_points(&info.prototype)) }</code> }
+                } else {
+                    html! {}
+                }
+            }
+            </td>
+        </tr>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skeletons_of_confusable_strings_match() {
+        assert_eq!(skeleton("p\u{430}ypal.com"), skeleton("paypal.com"));
+        assert_eq!(skeleton("rn"), skeleton("m"));
+        assert_eq!(skeleton("\u{417}\u{4E00}"), skeleton("3\u{30FC}"));
+        assert_ne!(skeleton("paypal"), skeleton("paypa1x"));
+    }
+
+    #[test]
+    fn restriction_levels() {
+        let level = |s: &str| s.detect_restriction_level();
+        assert!(level("paypal") == RestrictionLevel::ASCIIOnly);
+        assert!(level("日本語") == RestrictionLevel::SingleScript);
+        assert!(level("日本語abc") == RestrictionLevel::HighlyRestrictive);
+        assert!(level("p\u{430}ypal") == RestrictionLevel::MinimallyRestrictive);
+    }
+
+    #[test]
+    fn only_characters_outside_the_dominant_script_are_suspicious() {
+        let suspicious: Vec<char> = analyze("p\u{430}ypal.com")
+            .iter()
+            .filter(|info| info.suspicious)
+            .map(|info| info.c)
+            .collect();
+        assert_eq!(suspicious, vec!['\u{430}']);
+        assert!(analyze("paypal.com").iter().all(|info| !info.suspicious));
+    }
+}
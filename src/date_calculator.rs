@@ -0,0 +1,562 @@
+use crate::holidays::japanese_holidays;
+use crate::util::horizontal_field;
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+use yew::prelude::*;
+
+/// Business-day arithmetic walks one day at a time; this bounds the walk.
+const MAX_BUSINESS_DAYS: i64 = 100_000;
+
+/// Ranges longer than this are not listed day by day in the holiday table.
+const MAX_LISTED_DAYS: i64 = 3 * 366;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Unit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+    BusinessDays,
+}
+
+const UNITS: &[Unit] = &[
+    Unit::Days,
+    Unit::Weeks,
+    Unit::Months,
+    Unit::Years,
+    Unit::BusinessDays,
+];
+
+impl Unit {
+    fn name(self) -> &'static str {
+        match self {
+            Unit::Days => "days",
+            Unit::Weeks => "weeks",
+            Unit::Months => "months",
+            Unit::Years => "years",
+            Unit::BusinessDays => "business days",
+        }
+    }
+}
+
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    let text = text.trim();
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(text, "%Y/%m/%d"))
+        .ok()
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d (%a)").to_string()
+}
+
+/// Holidays and weekends used for business-day arithmetic.
+struct Calendar {
+    holidays: BTreeMap<NaiveDate, String>,
+    /// Dates listed with `!`, which are working days even if they are
+    /// built-in holidays.
+    working: BTreeSet<NaiveDate>,
+}
+
+impl Calendar {
+    /// Builds the calendar for `years`, from the built-in Japanese holidays
+    /// if `builtin` is set and from `custom`, one entry per line:
+    /// `2024-12-30 年末休暇`, a range `2024-12-28..2025-01-03 年末年始`, or
+    /// `!2024-05-06` to work on a holiday. Returns the numbers of the lines
+    /// that could not be parsed as well.
+    fn new(builtin: bool, custom: &str, years: (i32, i32)) -> (Self, Vec<usize>) {
+        let mut holidays = BTreeMap::new();
+        if builtin {
+            for year in years.0..=years.1 {
+                holidays.extend(
+                    japanese_holidays(year)
+                        .into_iter()
+                        .map(|(day, name)| (day, name.to_string())),
+                );
+            }
+        }
+
+        let mut working = BTreeSet::new();
+        let mut errors = vec![];
+        for (i, line) in custom.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(day) = line.strip_prefix('!') {
+                match parse_date(day) {
+                    Some(day) => {
+                        working.insert(day);
+                    }
+                    None => errors.push(i + 1),
+                }
+                continue;
+            }
+
+            let (range, name) = match line.find(char::is_whitespace) {
+                Some(j) => (&line[..j], line[j..].trim()),
+                None => (line, ""),
+            };
+            let (first, last) = match range.find("..") {
+                Some(j) => (parse_date(&range[..j]), parse_date(&range[j + 2..])),
+                None => (parse_date(range), parse_date(range)),
+            };
+            match (first, last) {
+                (Some(first), Some(last))
+                    if first <= last && last - first <= Duration::days(366) =>
+                {
+                    let name = if name.is_empty() { "Holiday" } else { name };
+                    for day in first.iter_days().take_while(|&day| day <= last) {
+                        holidays.insert(day, name.to_string());
+                    }
+                }
+                _ => errors.push(i + 1),
+            }
+        }
+
+        (Self { holidays, working }, errors)
+    }
+
+    fn holiday(&self, day: NaiveDate) -> Option<&str> {
+        if self.working.contains(&day) {
+            None
+        } else {
+            self.holidays.get(&day).map(|name| name.as_str())
+        }
+    }
+
+    fn is_business_day(&self, day: NaiveDate) -> bool {
+        !matches!(day.weekday(), Weekday::Sat | Weekday::Sun) && self.holiday(day).is_none()
+    }
+
+    /// Counts business days from `first` to `last`, both included.
+    fn business_days(&self, first: NaiveDate, last: NaiveDate) -> i64 {
+        first
+            .iter_days()
+            .take_while(|&day| day <= last)
+            .filter(|&day| self.is_business_day(day))
+            .count() as i64
+    }
+
+    /// Moves `n` business days from `day`; `day` itself is not counted.
+    fn add_business_days(&self, mut day: NaiveDate, n: i64) -> Option<NaiveDate> {
+        let step = Duration::days(n.signum());
+        for _ in 0..n.abs() {
+            loop {
+                day = day.checked_add_signed(step)?;
+                if self.is_business_day(day) {
+                    break;
+                }
+            }
+        }
+        Some(day)
+    }
+}
+
+/// Splits the span from `first` to `last` into whole months and the days
+/// left over, so that `first` plus the months plus the days is `last`.
+fn months_and_days(first: NaiveDate, last: NaiveDate) -> (u32, i64) {
+    let mut months = ((last.year() - first.year()) * 12 + last.month() as i32
+        - first.month() as i32)
+        .max(0) as u32;
+    while months > 0 && first + Months::new(months) > last {
+        months -= 1;
+    }
+    (months, (last - (first + Months::new(months))).num_days())
+}
+
+fn add(calendar: &Calendar, base: NaiveDate, amount: i64, unit: Unit) -> Option<NaiveDate> {
+    let months = |n: i64| {
+        let m = Months::new(u32::try_from(n.unsigned_abs()).ok()?);
+        if n < 0 {
+            base.checked_sub_months(m)
+        } else {
+            base.checked_add_months(m)
+        }
+    };
+    match unit {
+        Unit::Days => base.checked_add_signed(Duration::try_days(amount)?),
+        Unit::Weeks => base.checked_add_signed(Duration::try_weeks(amount)?),
+        Unit::Months => months(amount),
+        Unit::Years => months(amount.checked_mul(12)?),
+        Unit::BusinessDays if amount.abs() <= MAX_BUSINESS_DAYS => {
+            calendar.add_business_days(base, amount)
+        }
+        Unit::BusinessDays => None,
+    }
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    start: String,
+    #[prop_or_default]
+    end: String,
+    #[prop_or_default]
+    base: String,
+    #[prop_or_default]
+    amount: String,
+    #[prop_or(Unit::Days)]
+    unit: Unit,
+    #[prop_or(true)]
+    builtin_holidays: bool,
+    #[prop_or_default]
+    custom_holidays: String,
+}
+
+pub enum Msg {
+    Start(String),
+    End(String),
+    Base(String),
+    Amount(String),
+    Unit(Unit),
+    BuiltinHolidays,
+    CustomHolidays(String),
+}
+
+impl Model {
+    fn date_input(&self, value: &str, callback: Callback<InputData>) -> Html {
+        let mut classes = vec!["input"];
+        if !value.is_empty() && parse_date(value).is_none() {
+            classes.push("is-danger");
+        }
+        html! {
+            <input class=classes type="date" value=value.to_string() oninput=callback/>
+        }
+    }
+
+    fn describe_day(&self, calendar: &Calendar, day: NaiveDate) -> String {
+        let mut s = format!("{}, ISO week {}", format_date(day), day.format("%G-W%V-%u"));
+        if let Some(name) = calendar.holiday(day) {
+            s += &format!(", {}", name);
+        }
+        s
+    }
+
+    fn view_difference(&self, calendar: &Calendar, start: NaiveDate, end: NaiveDate) -> Html {
+        let input = |s: String| {
+            html! {
+                <input class="input" type="text" value=s readonly=true/>
+            }
+        };
+        let (first, last, sign) = if start <= end {
+            (start, end, "")
+        } else {
+            (end, start, "-")
+        };
+        let days = (last - first).num_days();
+        let (months, rest) = months_and_days(first, last);
+        let weekdays = first
+            .iter_days()
+            .take_while(|&day| day <= last)
+            .filter(|day| !matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+            .count() as i64;
+        let business = calendar.business_days(first, last);
+
+        html! {
+            <>
+            { horizontal_field("Start", input(self.describe_day(calendar, start))) }
+            { horizontal_field("End", input(self.describe_day(calendar, end))) }
+            { horizontal_field("Days", input(format!("{}{} ({} including both ends)", sign, days, days + 1))) }
+            { horizontal_field("Weeks", input(format!("{}{} weeks {} days", sign, days / 7, days % 7))) }
+            {
+                horizontal_field("Months", input(format!(
+                    "{}{} years {} months {} days ({}{} months)",
+                    sign, months / 12, months % 12, rest, sign, months
+                )))
+            }
+            { horizontal_field("Hours", input(format!("{}{}", sign, days * 24))) }
+            {
+                horizontal_field("Business days", html!{
+                    <>
+                    { input(format!("{}", business)) }
+                    <p class="help">
+                        { format!("Both ends included; {} weekdays, {} of them holidays", weekdays, weekdays - business) }
+                    </p>
+                    </>
+                })
+            }
+            </>
+        }
+    }
+
+    fn view_holidays(&self, calendar: &Calendar, first: NaiveDate, last: NaiveDate) -> Html {
+        html! {
+            <table class="table is-narrow">
+                <tbody>
+                {
+                    for first.iter_days().take_while(|&day| day <= last).filter_map(|day| {
+                        let name = calendar.holiday(day)?;
+                        Some(html! {
+                            <tr>
+                                <td>{ format_date(day) }</td>
+                                <td>{ name }</td>
+                            </tr>
+                        })
+                    })
+                }
+                </tbody>
+            </table>
+        }
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Start(text) => self.props.start = text,
+            Msg::End(text) => self.props.end = text,
+            Msg::Base(text) => self.props.base = text,
+            Msg::Amount(text) => self.props.amount = text,
+            Msg::Unit(unit) => self.props.unit = unit,
+            Msg::BuiltinHolidays => self.props.builtin_holidays = !self.props.builtin_holidays,
+            Msg::CustomHolidays(text) => self.props.custom_holidays = text,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let start = parse_date(&self.props.start);
+        let end = parse_date(&self.props.end);
+        let base = parse_date(&self.props.base);
+        let amount = self.props.amount.trim().parse::<i64>().ok();
+
+        // Business-day arithmetic may run past the dates entered, so cover
+        // a margin proportional to the amount.
+        let margin = amount.map_or(0, |n| (n.unsigned_abs() / 200).min(100) as i32) + 1;
+        let mut years: Vec<i32> = [start, end].iter().flatten().map(|d| d.year()).collect();
+        years.extend(
+            base.iter()
+                .flat_map(|d| vec![d.year() - margin, d.year() + margin]),
+        );
+        let years = (
+            years.iter().copied().min().unwrap_or(0),
+            years.iter().copied().max().unwrap_or(0),
+        );
+        let (calendar, errors) = Calendar::new(
+            self.props.builtin_holidays,
+            &self.props.custom_holidays,
+            years,
+        );
+
+        let mut amount_classes = vec!["input"];
+        if !self.props.amount.trim().is_empty() && amount.is_none() {
+            amount_classes.push("is-danger");
+        }
+        let mut custom_classes = vec!["textarea"];
+        if !errors.is_empty() {
+            custom_classes.push("is-danger");
+        }
+
+        let result = match (base, amount) {
+            (Some(base), Some(amount)) => Some(add(&calendar, base, amount, self.props.unit)),
+            _ => None,
+        };
+
+        html! {
+            <>
+            <h2 class="title is-4">{"Difference"}</h2>
+            { horizontal_field("From", self.date_input(&self.props.start, self.link.callback(|e: InputData| Msg::Start(e.value)))) }
+            { horizontal_field("To", self.date_input(&self.props.end, self.link.callback(|e: InputData| Msg::End(e.value)))) }
+            {
+                match (start, end) {
+                    (Some(start), Some(end)) => self.view_difference(&calendar, start, end),
+                    _ => html! {},
+                }
+            }
+
+            <h2 class="title is-4">{"Add or subtract"}</h2>
+            { horizontal_field("Date", self.date_input(&self.props.base, self.link.callback(|e: InputData| Msg::Base(e.value)))) }
+            {
+                horizontal_field("Amount", html!{
+                    <div class="field has-addons">
+                        <div class="control is-expanded">
+                            <input class=amount_classes type="text" placeholder="-10"
+                                value=self.props.amount.clone()
+                                oninput=self.link.callback(|e: InputData| Msg::Amount(e.value))/>
+                        </div>
+                        <div class="control">
+                            <div class="select">
+                                <select onchange=self.link.callback(|e: ChangeData| match e {
+                                    ChangeData::Select(s) => Msg::Unit(UNITS[s.selected_index().max(0) as usize]),
+                                    _ => unreachable!(),
+                                })>
+                                    {
+                                        for UNITS.iter().map(|&unit| html!{
+                                            <option selected=self.props.unit == unit>{ unit.name() }</option>
+                                        })
+                                    }
+                                </select>
+                            </div>
+                        </div>
+                    </div>
+                })
+            }
+            {
+                match result {
+                    Some(Some(day)) => horizontal_field("Result", html! {
+                        <input class="input" type="text" value=self.describe_day(&calendar, day) readonly=true/>
+                    }),
+                    Some(None) => horizontal_field("Result", html! {
+                        <p class="help is-danger">{"The result is out of range"}</p>
+                    }),
+                    None => html! {},
+                }
+            }
+
+            <h2 class="title is-4">{"Holidays"}</h2>
+            {
+                horizontal_field("", html!{
+                    <label class="checkbox">
+                        <input type="checkbox"
+                            checked=self.props.builtin_holidays
+                            onchange=self.link.callback(|_| Msg::BuiltinHolidays)/>
+                        {" Japanese national holidays"}
+                    </label>
+                })
+            }
+            {
+                horizontal_field("Custom", html!{
+                    <>
+                    <textarea class=custom_classes
+                        placeholder="2024-12-28..2025-01-03 年末年始\n2025-08-13 夏季休暇\n!2025-05-06"
+                        value=self.props.custom_holidays.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::CustomHolidays(e.value))/>
+                    <p class=if errors.is_empty() { "help" } else { "help is-danger" }>
+                        {
+                            if errors.is_empty() {
+                                "One date or range per line, followed by a name. Prefix a date with ! to make it a working day.".to_string()
+                            } else {
+                                format!("Invalid lines: {}", errors.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(", "))
+                            }
+                        }
+                    </p>
+                    </>
+                })
+            }
+            {
+                match (start, end) {
+                    (Some(start), Some(end)) if (end - start).num_days().abs() <= MAX_LISTED_DAYS => {
+                        self.view_holidays(&calendar, start.min(end), start.max(end))
+                    }
+                    _ => html! {},
+                }
+            }
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn calendar(custom: &str) -> Calendar {
+        let (calendar, errors) = Calendar::new(true, custom, (2023, 2025));
+        assert!(errors.is_empty());
+        calendar
+    }
+
+    #[test]
+    fn adds_across_month_ends() {
+        let calendar = calendar("");
+        let add = |base, amount, unit| add(&calendar, base, amount, unit);
+        assert_eq!(
+            add(ymd(2024, 1, 31), 1, Unit::Months),
+            Some(ymd(2024, 2, 29))
+        );
+        assert_eq!(
+            add(ymd(2023, 1, 31), 1, Unit::Months),
+            Some(ymd(2023, 2, 28))
+        );
+        assert_eq!(
+            add(ymd(2024, 3, 31), -1, Unit::Months),
+            Some(ymd(2024, 2, 29))
+        );
+        assert_eq!(
+            add(ymd(2024, 8, 31), 1, Unit::Months),
+            Some(ymd(2024, 9, 30))
+        );
+        assert_eq!(
+            add(ymd(2024, 12, 31), 2, Unit::Months),
+            Some(ymd(2025, 2, 28))
+        );
+        assert_eq!(add(ymd(2024, 2, 28), 1, Unit::Days), Some(ymd(2024, 2, 29)));
+        assert_eq!(add(ymd(2023, 2, 28), 1, Unit::Days), Some(ymd(2023, 3, 1)));
+        assert_eq!(add(ymd(2024, 3, 1), -1, Unit::Days), Some(ymd(2024, 2, 29)));
+        assert_eq!(
+            add(ymd(2024, 2, 22), 1, Unit::Weeks),
+            Some(ymd(2024, 2, 29))
+        );
+    }
+
+    #[test]
+    fn adds_years_to_february_29() {
+        let calendar = calendar("");
+        let add = |amount| add(&calendar, ymd(2024, 2, 29), amount, Unit::Years);
+        assert_eq!(add(1), Some(ymd(2025, 2, 28)));
+        assert_eq!(add(-1), Some(ymd(2023, 2, 28)));
+        assert_eq!(add(4), Some(ymd(2028, 2, 29)));
+        assert_eq!(add(100), Some(ymd(2124, 2, 29)));
+        assert_eq!(add(i64::MAX), None);
+        assert_eq!(months_and_days(ymd(2024, 2, 29), ymd(2025, 2, 28)), (12, 0));
+        assert_eq!(months_and_days(ymd(2024, 1, 31), ymd(2024, 2, 29)), (1, 0));
+        assert_eq!(months_and_days(ymd(2024, 1, 31), ymd(2024, 3, 1)), (1, 1));
+        assert_eq!(months_and_days(ymd(2024, 3, 1), ymd(2024, 2, 1)), (0, -29));
+    }
+
+    #[test]
+    fn business_days_skip_golden_week() {
+        // 2024-04-29 (Mon) and 2024-05-03 to 05-06 (Fri to Mon) are
+        // holidays.
+        let calendar = calendar("");
+        let add = |base, amount| add(&calendar, base, amount, Unit::BusinessDays);
+        assert_eq!(add(ymd(2024, 4, 26), 1), Some(ymd(2024, 4, 30)));
+        assert_eq!(add(ymd(2024, 5, 2), 1), Some(ymd(2024, 5, 7)));
+        assert_eq!(add(ymd(2024, 5, 7), -1), Some(ymd(2024, 5, 2)));
+        assert_eq!(add(ymd(2024, 5, 4), 1), Some(ymd(2024, 5, 7)));
+        assert_eq!(add(ymd(2024, 5, 4), 0), Some(ymd(2024, 5, 4)));
+        assert_eq!(add(ymd(2024, 4, 26), 5), Some(ymd(2024, 5, 8)));
+        assert_eq!(add(ymd(2024, 4, 26), MAX_BUSINESS_DAYS + 1), None);
+        assert_eq!(calendar.business_days(ymd(2024, 4, 27), ymd(2024, 5, 6)), 3);
+        assert!(calendar.holiday(ymd(2024, 5, 6)).is_some());
+    }
+
+    #[test]
+    fn custom_holidays_and_working_days() {
+        let calendar = calendar("# 年末年始\n2024-12-28..2025-01-03 年末年始\n!2024-05-06\n");
+        let add = |base, amount| add(&calendar, base, amount, Unit::BusinessDays);
+        assert_eq!(add(ymd(2024, 5, 2), 1), Some(ymd(2024, 5, 6)));
+        assert_eq!(calendar.holiday(ymd(2024, 5, 6)), None);
+        assert_eq!(add(ymd(2024, 12, 27), 1), Some(ymd(2025, 1, 6)));
+        assert_eq!(calendar.holiday(ymd(2024, 12, 31)), Some("年末年始"));
+
+        let (_, errors) = Calendar::new(
+            false,
+            "2024-02-30\n2024-01-02\n2024-01-05..2024-01-01\n!tomorrow\n2024-01-01..2026-01-01",
+            (2024, 2024),
+        );
+        assert_eq!(errors, vec![1, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,398 @@
+use crate::html_entities::{ENTITIES, NAMES};
+use crate::unicode;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Syntax {
+    Js,
+    Rust,
+    Hex,
+    HtmlNumeric,
+    HtmlNamed,
+    Css,
+    Python,
+    Json,
+}
+
+pub const SYNTAXES: &[Syntax] = &[
+    Syntax::Js,
+    Syntax::Rust,
+    Syntax::Hex,
+    Syntax::HtmlNumeric,
+    Syntax::HtmlNamed,
+    Syntax::Css,
+    Syntax::Python,
+    Syntax::Json,
+];
+
+impl Syntax {
+    pub fn name(self) -> &'static str {
+        match self {
+            Syntax::Js => "\\uXXXX (JavaScript, Java, C#)",
+            Syntax::Rust => "\\u{...} (Rust, ES2015, Swift)",
+            Syntax::Hex => "\\x.. (UTF-8 bytes)",
+            Syntax::HtmlNumeric => "HTML numeric (&#x...;)",
+            Syntax::HtmlNamed => "HTML named (&amp;)",
+            Syntax::Css => "CSS (\\XXXX)",
+            Syntax::Python => "Python \\N{NAME}",
+            Syntax::Json => "JSON string literal",
+        }
+    }
+
+    pub fn placeholder(self) -> &'static str {
+        match self {
+            Syntax::Js => "\\u3042\\uD83D\\uDE00",
+            Syntax::Rust => "\\u{3042}\\u{1F600}",
+            Syntax::Hex => "\\xE3\\x81\\x82",
+            Syntax::HtmlNumeric => "&#x3042;&#128512;",
+            Syntax::HtmlNamed => "&lt;&eacute;&gt;",
+            Syntax::Css => "\\3042 \\1F600",
+            Syntax::Python => "\\N{HIRAGANA LETTER A}",
+            Syntax::Json => "\"\\u3042\\n\"",
+        }
+    }
+}
+
+/// Characters that have to be escaped even when they are ASCII, so that
+/// the output unescapes back to the input.
+fn must_escape(c: char, syntax: Syntax) -> bool {
+    match syntax {
+        Syntax::HtmlNumeric | Syntax::HtmlNamed => matches!(c, '&' | '<' | '>' | '"' | '\''),
+        Syntax::Json => c == '"' || c == '\\' || c.is_control(),
+        _ => c == '\\',
+    }
+}
+
+fn escape_char(c: char, next: Option<char>, syntax: Syntax) -> String {
+    let s = c.to_string();
+    match syntax {
+        Syntax::Js => unicode::js_escape(&s),
+        Syntax::Rust => unicode::rust_escape(&s),
+        Syntax::Hex => s.bytes().map(|b| format!("\\x{:02X}", b)).collect(),
+        Syntax::HtmlNumeric => unicode::html_escape(&s),
+        Syntax::HtmlNamed => match NAMES.binary_search_by_key(&c, |&(c, _)| c) {
+            Ok(i) => format!("&{};", NAMES[i].1),
+            Err(_) => unicode::html_escape(&s),
+        },
+        Syntax::Css => {
+            // A space ends the escape when the next character would be read
+            // as part of it, or swallowed as the whitespace that ends it.
+            let space = next.is_some_and(|n| n.is_ascii_hexdigit() || " \t\n\r\u{C}".contains(n));
+            format!("\\{:X}{}", c as u32, if space { " " } else { "" })
+        }
+        Syntax::Python => match unicode_names2::name(c) {
+            Some(name) => format!("\\N{{{}}}", name),
+            None => unicode::python_escape(&s),
+        },
+        Syntax::Json => match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            '\u{8}' => "\\b".to_string(),
+            '\u{C}' => "\\f".to_string(),
+            _ => unicode::js_escape(&s),
+        },
+    }
+}
+
+/// Escapes every non-ASCII character, and with `escape_ascii` every ASCII
+/// character too. JSON output is wrapped in quotes.
+pub fn escape(text: &str, syntax: Syntax, escape_ascii: bool) -> String {
+    let mut ret = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_ascii() && !escape_ascii && !must_escape(c, syntax) {
+            ret.push(c);
+        } else {
+            ret += &escape_char(c, chars.peek().copied(), syntax);
+        }
+    }
+    if syntax == Syntax::Json {
+        format!("\"{}\"", ret)
+    } else {
+        ret
+    }
+}
+
+pub fn unescape(text: &str, syntax: Syntax) -> Result<String, String> {
+    match syntax {
+        Syntax::HtmlNumeric | Syntax::HtmlNamed => unescape_html(text),
+        Syntax::Css => Ok(unescape_css(text)),
+        Syntax::Json => {
+            let text = text.trim();
+            let quoted = text.len() >= 2 && text.starts_with('"') && text.ends_with('"');
+            unescape_backslash(if quoted {
+                &text[1..text.len() - 1]
+            } else {
+                text
+            })
+        }
+        Syntax::Js | Syntax::Rust | Syntax::Hex | Syntax::Python => unescape_backslash(text),
+    }
+}
+
+/// Reads exactly `len` hex digits at `start`.
+fn hex_digits(chars: &[char], start: usize, len: usize) -> Option<u32> {
+    let digits: String = chars.get(start..start + len)?.iter().collect();
+    if digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        u32::from_str_radix(&digits, 16).ok()
+    } else {
+        None
+    }
+}
+
+/// Decodes a run of `\x` bytes as UTF-8. Bytes that are not part of a
+/// UTF-8 sequence are read as Latin-1, as `\xE9` means é in JavaScript and
+/// Python strings.
+fn flush_bytes(bytes: &mut Vec<u8>, ret: &mut String) {
+    let mut rest = &bytes[..];
+    while !rest.is_empty() {
+        match std::str::from_utf8(rest) {
+            Ok(s) => {
+                ret.push_str(s);
+                break;
+            }
+            Err(err) => {
+                let (valid, invalid) = rest.split_at(err.valid_up_to());
+                ret.push_str(std::str::from_utf8(valid).unwrap());
+                let len = err.error_len().unwrap_or(invalid.len());
+                ret.extend(invalid[..len].iter().map(|&b| char::from(b)));
+                rest = &invalid[len..];
+            }
+        }
+    }
+    bytes.clear();
+}
+
+/// Decodes the escape at `chars[i]`, a backslash, into a character and the
+/// length of the escape.
+fn backslash_escape(chars: &[char], i: usize) -> Result<(char, usize), String> {
+    let seq = |len: usize| -> String { chars[i..(i + len).min(chars.len())].iter().collect() };
+    let invalid = |len: usize| format!("Invalid escape sequence: {}", seq(len));
+    let next = *chars.get(i + 1).ok_or("Trailing backslash")?;
+    let (cp, len) = match next {
+        'u' if chars.get(i + 2) == Some(&'{') => {
+            let end = (i + 3..chars.len())
+                .find(|&j| chars[j] == '}')
+                .ok_or_else(|| invalid(3))?;
+            let digits: String = chars[i + 3..end].iter().collect();
+            let cp = u32::from_str_radix(&digits, 16).map_err(|_| invalid(end + 1 - i))?;
+            (cp, end + 1 - i)
+        }
+        'u' => {
+            let u = hex_digits(chars, i + 2, 4).ok_or_else(|| invalid(6))?;
+            match u {
+                0xD800..=0xDBFF => {
+                    let low = if chars.get(i + 6) == Some(&'\\') && chars.get(i + 7) == Some(&'u') {
+                        hex_digits(chars, i + 8, 4).filter(|l| (0xDC00..=0xDFFF).contains(l))
+                    } else {
+                        None
+                    };
+                    let low = low.ok_or_else(|| format!("Unpaired surrogate: {}", seq(6)))?;
+                    (0x10000 + ((u - 0xD800) << 10) + (low - 0xDC00), 12)
+                }
+                0xDC00..=0xDFFF => return Err(format!("Unpaired surrogate: {}", seq(6))),
+                _ => (u, 6),
+            }
+        }
+        'U' => (hex_digits(chars, i + 2, 8).ok_or_else(|| invalid(10))?, 10),
+        'N' if chars.get(i + 2) == Some(&'{') => {
+            let end = (i + 3..chars.len())
+                .find(|&j| chars[j] == '}')
+                .ok_or_else(|| invalid(3))?;
+            let name: String = chars[i + 3..end].iter().collect();
+            let c = unicode_names2::character(&name)
+                .ok_or_else(|| format!("Unknown character name: {}", name))?;
+            (c as u32, end + 1 - i)
+        }
+        'n' => ('\n' as u32, 2),
+        'r' => ('\r' as u32, 2),
+        't' => ('\t' as u32, 2),
+        'b' => (0x8, 2),
+        'f' => (0xC, 2),
+        '0' => (0, 2),
+        '\\' | '"' | '\'' | '/' => (next as u32, 2),
+        _ => return Err(format!("Unknown escape sequence: {}", seq(2))),
+    };
+    let c = char::from_u32(cp).ok_or_else(|| format!("Invalid code point: {}", seq(len)))?;
+    Ok((c, len))
+}
+
+/// Unescapes `\uXXXX` with surrogate pairs, `\u{...}`, `\UXXXXXXXX`,
+/// `\x..` bytes, `\N{NAME}` and the usual one-letter escapes.
+fn unescape_backslash(text: &str) -> Result<String, String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut ret = String::new();
+    let mut bytes = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] != '\\' {
+            flush_bytes(&mut bytes, &mut ret);
+            ret.push(chars[i]);
+            i += 1;
+        } else if chars.get(i + 1) == Some(&'x') {
+            let b = hex_digits(&chars, i + 2, 2).ok_or_else(|| {
+                let seq: String = chars[i..(i + 4).min(chars.len())].iter().collect();
+                format!("Invalid escape sequence: {}", seq)
+            })?;
+            bytes.push(b as u8);
+            i += 4;
+        } else {
+            flush_bytes(&mut bytes, &mut ret);
+            let (c, len) = backslash_escape(&chars, i)?;
+            ret.push(c);
+            i += len;
+        }
+    }
+    flush_bytes(&mut bytes, &mut ret);
+    Ok(ret)
+}
+
+/// Decodes the body of a character reference between `&` and `;`. Unknown
+/// names are `None` and stay as they are, as in browsers.
+fn decode_entity(body: &str) -> Result<Option<String>, String> {
+    let invalid = || format!("Invalid character reference: &{};", body);
+    let number = if let Some(hex) = body.strip_prefix("#x").or_else(|| body.strip_prefix("#X")) {
+        Some(u32::from_str_radix(hex, 16))
+    } else {
+        body.strip_prefix('#').map(|dec| dec.parse())
+    };
+    match number {
+        Some(n) => {
+            let c = n.ok().and_then(char::from_u32).ok_or_else(invalid)?;
+            Ok(Some(c.to_string()))
+        }
+        None => Ok(ENTITIES
+            .binary_search_by_key(&body, |&(name, _)| name)
+            .ok()
+            .map(|i| ENTITIES[i].1.to_string())),
+    }
+}
+
+fn unescape_html(text: &str) -> Result<String, String> {
+    let mut ret = String::new();
+    let mut rest = text;
+    while let Some(p) = rest.find('&') {
+        ret.push_str(&rest[..p]);
+        rest = &rest[p..];
+        let body_len = rest[1..]
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '#')
+            .unwrap_or(rest.len() - 1);
+        let terminated = rest[1 + body_len..].starts_with(';');
+        let decoded = if terminated && body_len > 0 {
+            decode_entity(&rest[1..1 + body_len])?
+        } else {
+            None
+        };
+        match decoded {
+            Some(s) => {
+                ret.push_str(&s);
+                rest = &rest[body_len + 2..];
+            }
+            None => {
+                ret.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    ret.push_str(rest);
+    Ok(ret)
+}
+
+/// Unescapes CSS the way the CSS Syntax spec does: up to six hex digits and
+/// an optional whitespace, an escaped newline is dropped, and any other
+/// escaped character stands for itself.
+fn unescape_css(text: &str) -> String {
+    let mut ret = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            ret.push(c);
+            continue;
+        }
+        match chars.peek().copied() {
+            None => ret.push('\u{FFFD}'),
+            Some('\n') | Some('\u{C}') => {
+                chars.next();
+            }
+            Some('\r') => {
+                chars.next();
+                chars.next_if_eq(&'\n');
+            }
+            Some(h) if h.is_ascii_hexdigit() => {
+                let mut cp = 0;
+                for _ in 0..6 {
+                    match chars.next_if(|c| c.is_ascii_hexdigit()) {
+                        Some(h) => cp = cp * 16 + h.to_digit(16).unwrap(),
+                        None => break,
+                    }
+                }
+                if chars.next_if_eq(&'\r').is_some() {
+                    chars.next_if_eq(&'\n');
+                } else {
+                    chars.next_if(|&c| c == ' ' || c == '\t' || c == '\n' || c == '\u{C}');
+                }
+                ret.push(match cp {
+                    0 => '\u{FFFD}',
+                    _ => char::from_u32(cp).unwrap_or('\u{FFFD}'),
+                });
+            }
+            Some(other) => {
+                chars.next();
+                ret.push(other);
+            }
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_round_trip() {
+        let text = "a<b>&\"c\"\\ \u{3042}\u{1F600}é\n\té\r\n1 ";
+        for &syntax in SYNTAXES {
+            for &escape_ascii in &[false, true] {
+                let escaped = escape(text, syntax, escape_ascii);
+                assert_eq!(
+                    unescape(&escaped, syntax).as_deref(),
+                    Ok(text),
+                    "{} {}",
+                    syntax.name(),
+                    escaped
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn escapes_in_each_syntax() {
+        assert_eq!(escape("あ", Syntax::Hex, false), "\\xE3\\x81\\x82");
+        assert_eq!(escape("<é>", Syntax::HtmlNamed, false), "&lt;&eacute;&gt;");
+        assert_eq!(escape("あa😀1", Syntax::Css, false), "\\3042 a\\1F600 1");
+        assert_eq!(
+            escape("あ", Syntax::Python, false),
+            "\\N{HIRAGANA LETTER A}"
+        );
+        assert_eq!(escape("\"\n", Syntax::Json, false), "\"\\\"\\n\"");
+    }
+
+    #[test]
+    fn unescapes_surrogates_bytes_and_css() {
+        assert_eq!(
+            unescape("\\uD83D\\uDE00\\xE3\\x81\\x82\\xE9", Syntax::Js).as_deref(),
+            Ok("😀あé")
+        );
+        assert_eq!(
+            unescape("\\3042 \\1F600x\\41", Syntax::Css).as_deref(),
+            Ok("あ😀xA")
+        );
+        assert_eq!(
+            unescape("&#x3042;&#128512;&amp;", Syntax::HtmlNumeric).as_deref(),
+            Ok("あ😀&")
+        );
+    }
+}
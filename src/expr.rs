@@ -0,0 +1,447 @@
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+/// Results larger than this many bits are rejected so that a typo such as
+/// `2**2**64` cannot hang the page.
+const MAX_BITS: u64 = 1 << 20;
+
+/// Largest modulus and exponent for `modpow`. A 4096-bit modpow takes
+/// about 70 ms natively, and doubling the size makes it eight times slower.
+const MAX_MODPOW_BITS: u64 = 4096;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Num(BigInt),
+    Ident(String),
+    Op(&'static str),
+}
+
+const OPS: &[&str] = &[
+    "**", "<<", ">>", "+", "-", "*", "/", "%", "&", "|", "^", "~", "(", ")", ",",
+];
+
+fn tokenize(s: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    let mut rest = s.trim_start();
+
+    while !rest.is_empty() {
+        if let Some(op) = OPS.iter().find(|op| rest.starts_with(*op)) {
+            tokens.push(Token::Op(op));
+            rest = &rest[op.len()..];
+        } else if rest.starts_with(|c: char| c.is_ascii_digit()) {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                .unwrap_or(rest.len());
+            // `1e+9` is a single literal; `1 + 9` is not.
+            let len = match rest[..len].chars().last() {
+                Some('e') | Some('E')
+                    if !rest.get(..2).is_some_and(|p| p.eq_ignore_ascii_case("0x"))
+                        && rest[len..].starts_with(['+', '-']) =>
+                {
+                    len + 1
+                        + rest[len + 1..]
+                            .find(|c: char| !c.is_ascii_digit())
+                            .unwrap_or(rest.len() - len - 1)
+                }
+                _ => len,
+            };
+            tokens.push(Token::Num(parse_number(&rest[..len])?));
+            rest = &rest[len..];
+        } else if rest.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Ident(rest[..len].to_ascii_lowercase()));
+            rest = &rest[len..];
+        } else {
+            return Err(format!(
+                "unexpected character '{}'",
+                rest.chars().next().unwrap()
+            ));
+        }
+        rest = rest.trim_start();
+    }
+
+    Ok(tokens)
+}
+
+fn parse_number(s: &str) -> Result<BigInt, String> {
+    let err = || format!("invalid number '{}'", s);
+    let digits = s.replace('_', "");
+    let lower = digits.to_ascii_lowercase();
+
+    for (prefix, radix) in &[("0x", 16), ("0o", 8), ("0b", 2)] {
+        if let Some(digits) = lower.strip_prefix(prefix) {
+            return check_size(BigInt::parse_bytes(digits.as_bytes(), *radix).ok_or_else(err)?);
+        }
+    }
+
+    // Decimal, possibly written in scientific notation such as `1e9` or
+    // `2.5e3`, as long as the value is an integer.
+    let (mantissa, exp) = match lower.find('e') {
+        Some(i) => (
+            &lower[..i],
+            lower[i + 1..].parse::<i64>().map_err(|_| err())?,
+        ),
+        None => (&lower[..], 0),
+    };
+    let (int, frac) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    let frac = frac.trim_end_matches('0');
+    let exp = exp - frac.len() as i64;
+    let value = BigInt::parse_bytes(format!("{}{}", int, frac).as_bytes(), 10).ok_or_else(err)?;
+    if exp.unsigned_abs() > MAX_BITS / 3 {
+        return if value.is_zero() {
+            Ok(value)
+        } else if exp > 0 {
+            Err("result is too large".to_string())
+        } else {
+            Err(format!("'{}' is not an integer", s))
+        };
+    }
+    if exp >= 0 {
+        check_size(value * BigInt::from(10).pow(exp as u32))
+    } else {
+        let scale = BigInt::from(10).pow((-exp) as u32);
+        if (&value % &scale).is_zero() {
+            Ok(value / scale)
+        } else {
+            Err(format!("'{}' is not an integer", s))
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+/// Binary operators from the loosest to the tightest binding, as in Python.
+const LEVELS: &[&[&str]] = &[
+    &["|"],
+    &["^"],
+    &["&"],
+    &["<<", ">>"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, op: &'static str) -> bool {
+        if self.peek() == Some(&Token::Op(op)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, op: &'static str) -> Result<(), String> {
+        if self.eat(op) {
+            Ok(())
+        } else {
+            Err(format!("expected '{}'", op))
+        }
+    }
+
+    fn binary(&mut self, level: usize) -> Result<BigInt, String> {
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+        let mut lhs = self.binary(level + 1)?;
+        'outer: loop {
+            for op in LEVELS[level] {
+                if self.eat(op) {
+                    let rhs = self.binary(level + 1)?;
+                    lhs = apply(op, lhs, rhs)?;
+                    continue 'outer;
+                }
+            }
+            return Ok(lhs);
+        }
+    }
+
+    fn unary(&mut self) -> Result<BigInt, String> {
+        if self.eat("-") {
+            Ok(-self.unary()?)
+        } else if self.eat("+") {
+            self.unary()
+        } else if self.eat("~") {
+            Ok(!self.unary()?)
+        } else {
+            self.power()
+        }
+    }
+
+    fn power(&mut self) -> Result<BigInt, String> {
+        let base = self.atom()?;
+        if self.eat("**") {
+            let exp = self.unary()?;
+            pow(&base, &exp)
+        } else {
+            Ok(base)
+        }
+    }
+
+    fn atom(&mut self) -> Result<BigInt, String> {
+        let token = self.peek().cloned();
+        self.pos += 1;
+        match token {
+            Some(Token::Num(n)) => Ok(n),
+            Some(Token::Op("(")) => {
+                let value = self.binary(0)?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            Some(Token::Ident(name)) => {
+                self.expect("(")?;
+                let mut args = vec![];
+                if !self.eat(")") {
+                    loop {
+                        args.push(self.binary(0)?);
+                        if self.eat(")") {
+                            break;
+                        }
+                        self.expect(",")?;
+                    }
+                }
+                call(&name, &args)
+            }
+            Some(Token::Op(op)) => Err(format!("unexpected '{}'", op)),
+            None => Err("unexpected end of expression".to_string()),
+        }
+    }
+}
+
+fn check_size(value: BigInt) -> Result<BigInt, String> {
+    if value.bits() > MAX_BITS {
+        Err("result is too large".to_string())
+    } else {
+        Ok(value)
+    }
+}
+
+fn shift_amount(n: &BigInt) -> Result<usize, String> {
+    if n.is_negative() {
+        return Err("negative shift count".to_string());
+    }
+    n.to_usize()
+        .filter(|&n| n as u64 <= MAX_BITS)
+        .ok_or_else(|| "shift count is too large".to_string())
+}
+
+fn apply(op: &str, lhs: BigInt, rhs: BigInt) -> Result<BigInt, String> {
+    let nonzero = |rhs: &BigInt| {
+        if rhs.is_zero() {
+            Err("division by zero".to_string())
+        } else {
+            Ok(())
+        }
+    };
+    let value = match op {
+        "|" => lhs | rhs,
+        "^" => lhs ^ rhs,
+        "&" => lhs & rhs,
+        "<<" => lhs << shift_amount(&rhs)?,
+        ">>" => lhs >> shift_amount(&rhs)?,
+        "+" => lhs + rhs,
+        "-" => lhs - rhs,
+        "*" => lhs * rhs,
+        "/" => {
+            nonzero(&rhs)?;
+            lhs.div_floor(&rhs)
+        }
+        "%" => {
+            nonzero(&rhs)?;
+            lhs.mod_floor(&rhs)
+        }
+        _ => unreachable!(),
+    };
+    check_size(value)
+}
+
+fn pow(base: &BigInt, exp: &BigInt) -> Result<BigInt, String> {
+    if exp.is_negative() {
+        return Err("negative exponent".to_string());
+    }
+    if exp.is_zero() {
+        return Ok(BigInt::one());
+    }
+    if base.magnitude() <= &BigUint::one() {
+        return Ok(if base.is_negative() && exp.is_even() {
+            BigInt::one()
+        } else {
+            base.clone()
+        });
+    }
+    match exp.to_u32() {
+        // A lower bound on the size of the result; the result is checked
+        // exactly once computed.
+        Some(e) if base.bits().saturating_sub(1).saturating_mul(e as u64) <= MAX_BITS => {
+            check_size(base.pow(e))
+        }
+        _ => Err("result is too large".to_string()),
+    }
+}
+
+fn call(name: &str, args: &[BigInt]) -> Result<BigInt, String> {
+    let arity = |n: usize| {
+        if args.len() == n {
+            Ok(())
+        } else {
+            Err(format!("{}() takes {} arguments", name, n))
+        }
+    };
+    match name {
+        "gcd" | "lcm" => {
+            if args.is_empty() {
+                return Err(format!("{}() takes at least 1 argument", name));
+            }
+            let f: fn(BigInt, &BigInt) -> BigInt = if name == "gcd" {
+                |a, b| a.gcd(b)
+            } else {
+                |a, b| a.lcm(b)
+            };
+            check_size(args[1..].iter().fold(args[0].abs(), f))
+        }
+        "pow" => {
+            arity(2)?;
+            pow(&args[0], &args[1])
+        }
+        "modpow" => {
+            arity(3)?;
+            let (base, exp, modulus) = (&args[0], &args[1], &args[2]);
+            if !modulus.is_positive() {
+                return Err("modulus must be positive".to_string());
+            }
+            if exp.is_negative() {
+                return Err("negative exponent".to_string());
+            }
+            if modulus.bits() > MAX_MODPOW_BITS || exp.bits() > MAX_MODPOW_BITS {
+                return Err(format!(
+                    "modpow() takes a modulus and exponent of at most {} bits",
+                    MAX_MODPOW_BITS
+                ));
+            }
+            Ok(base.mod_floor(modulus).modpow(exp, modulus))
+        }
+        "abs" => {
+            arity(1)?;
+            Ok(args[0].abs())
+        }
+        _ => Err(format!("unknown function '{}'", name)),
+    }
+}
+
+/// Evaluates an integer expression such as `0xff << 64 | 0b1010` or
+/// `modpow(3, 10**18, 998244353)`.
+///
+/// Operators follow Python: `**` binds tightest and is right associative,
+/// `/` and `%` round towards negative infinity, and the bitwise operators
+/// act on two's complement.
+pub fn eval(s: &str) -> Result<BigInt, String> {
+    let mut parser = Parser {
+        tokens: tokenize(s)?,
+        pos: 0,
+    };
+    let value = parser.binary(0)?;
+    match parser.peek() {
+        None => Ok(value),
+        Some(Token::Op(op)) => Err(format!("unexpected '{}'", op)),
+        Some(_) => Err("expected an operator".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval_str(s: &str) -> Result<String, String> {
+        eval(s).map(|n| n.to_string())
+    }
+
+    fn ok(s: &str) -> Result<String, String> {
+        Ok(s.to_string())
+    }
+
+    #[test]
+    fn precedence_follows_python() {
+        assert_eq!(eval_str("1 + 2 * 3"), ok("7"));
+        assert_eq!(eval_str("(1 + 2) * 3"), ok("9"));
+        assert_eq!(eval_str("1 << 2 + 1"), ok("8"));
+        assert_eq!(eval_str("6 & 3 | 8 ^ 1"), ok("11"));
+        assert_eq!(eval_str("-2 ** 2"), ok("-4"));
+        assert!(eval_str("2 ** -1 + 1").is_err());
+        assert_eq!(eval_str("~0"), ok("-1"));
+        assert_eq!(eval_str("-7 / 2"), ok("-4"));
+        assert_eq!(eval_str("-7 % 2"), ok("1"));
+    }
+
+    #[test]
+    fn power_is_right_associative() {
+        assert_eq!(eval_str("2 ** 3 ** 2"), ok("512"));
+        assert_eq!(eval_str("(2 ** 3) ** 2"), ok("64"));
+        assert_eq!(eval_str("2 ** -(-3)"), ok("8"));
+    }
+
+    #[test]
+    fn literals() {
+        assert_eq!(eval_str("0xff + 0o17 + 0b11 + 1_000"), ok("1273"));
+        assert_eq!(eval_str("1e9"), ok("1000000000"));
+        assert_eq!(eval_str("2.5e3"), ok("2500"));
+        assert_eq!(eval_str("1e+3 - 1E-0"), ok("999"));
+        assert_eq!(eval_str("1e3+5"), ok("1005"));
+        assert_eq!(eval_str("0x1e+5"), ok("35"));
+        assert_eq!(eval_str("0X1E+5"), ok("35"));
+        assert_eq!(eval_str("1.5"), Err("'1.5' is not an integer".to_string()));
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(eval_str("gcd(12, 18, -30)"), ok("6"));
+        assert_eq!(eval_str("lcm(4, 6)"), ok("12"));
+        assert_eq!(eval_str("pow(3, 4)"), ok("81"));
+        assert_eq!(eval_str("modpow(3, 10**18, 998244353)"), ok("865857325"));
+        assert_eq!(eval_str("abs(-5)"), ok("5"));
+    }
+
+    #[test]
+    fn errors() {
+        let err = |s: &str| eval_str(s).unwrap_err();
+        assert_eq!(err("1 / 0"), "division by zero");
+        assert_eq!(err("1 +"), "unexpected end of expression");
+        assert_eq!(err("(1"), "expected ')'");
+        assert_eq!(err("1 2"), "expected an operator");
+        assert_eq!(err("1 $ 2"), "unexpected character '$'");
+        assert_eq!(err("foo(1)"), "unknown function 'foo'");
+        assert_eq!(err("abs(1, 2)"), "abs() takes 1 arguments");
+        assert_eq!(err("1 << -1"), "negative shift count");
+        assert_eq!(err("modpow(2, 3, 0)"), "modulus must be positive");
+    }
+
+    #[test]
+    fn results_are_bounded() {
+        let too_large = Err("result is too large".to_string());
+        assert_eq!(eval_str("3 ** (1 << 20)"), too_large);
+        assert_eq!(eval_str("2 ** 2 ** 64"), too_large);
+        assert_eq!(
+            eval_str("1 << (1 << 21)"),
+            Err("shift count is too large".to_string())
+        );
+        assert_eq!(eval_str("1e1000000"), too_large);
+        assert_eq!(
+            eval(&format!("0x{}", "f".repeat(300_000))).err(),
+            too_large.err()
+        );
+        assert!(eval_str("modpow(3, 5, 1 << 5000)").is_err());
+        assert!(eval_str("modpow(3, 1 << 5000, 7)").is_err());
+        assert_eq!(eval("2 ** (1 << 19)").map(|n| n.bits()), Ok((1 << 19) + 1));
+    }
+}
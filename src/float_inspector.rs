@@ -0,0 +1,635 @@
+use crate::util::horizontal_field;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{ToPrimitive, Zero};
+use std::cmp::max;
+use yew::prelude::*;
+
+/// Decimal exponents beyond this are rejected instead of being expanded into
+/// a huge `BigUint`. Every format overflows or underflows long before it.
+const MAX_EXPONENT: i64 = 5000;
+
+#[derive(Clone, Copy)]
+struct Format {
+    name: &'static str,
+    exp_bits: u32,
+    frac_bits: u32,
+}
+
+const FORMATS: &[Format] = &[
+    Format {
+        name: "Half precision (binary16)",
+        exp_bits: 5,
+        frac_bits: 10,
+    },
+    Format {
+        name: "bfloat16",
+        exp_bits: 8,
+        frac_bits: 7,
+    },
+    Format {
+        name: "Single precision (binary32)",
+        exp_bits: 8,
+        frac_bits: 23,
+    },
+    Format {
+        name: "Double precision (binary64)",
+        exp_bits: 11,
+        frac_bits: 52,
+    },
+];
+
+impl Format {
+    fn width(self) -> u32 {
+        1 + self.exp_bits + self.frac_bits
+    }
+
+    fn bias(self) -> i64 {
+        (1 << (self.exp_bits - 1)) - 1
+    }
+
+    fn sign_bit(self) -> u64 {
+        1 << (self.width() - 1)
+    }
+
+    fn exp_mask(self) -> u64 {
+        (1 << self.exp_bits) - 1
+    }
+
+    fn frac_mask(self) -> u64 {
+        (1 << self.frac_bits) - 1
+    }
+
+    fn infinity(self) -> u64 {
+        self.exp_mask() << self.frac_bits
+    }
+
+    fn fields(self, bits: u64) -> (bool, u64, u64) {
+        (
+            bits & self.sign_bit() != 0,
+            (bits >> self.frac_bits) & self.exp_mask(),
+            bits & self.frac_mask(),
+        )
+    }
+
+    /// The exact value stored in `bits`.
+    fn value(self, bits: u64) -> Value {
+        let (negative, exp, frac) = self.fields(bits);
+        if exp == self.exp_mask() {
+            return if frac == 0 {
+                Value::Infinity { negative }
+            } else {
+                Value::Nan
+            };
+        }
+        let (significand, exp) = if exp == 0 {
+            (frac, 1 - self.bias())
+        } else {
+            (frac | 1 << self.frac_bits, exp as i64 - self.bias())
+        };
+        Value::from_dyadic(
+            negative,
+            BigUint::from(significand),
+            exp - self.frac_bits as i64,
+        )
+    }
+
+    /// Rounds `value` to the nearest representable bit pattern, ties to even.
+    fn round(self, value: &Value) -> u64 {
+        match value {
+            Value::Nan => self.infinity() | 1 << (self.frac_bits - 1),
+            Value::Infinity { negative } => self.sign(*negative) | self.infinity(),
+            Value::Finite {
+                negative,
+                digits,
+                scale,
+            } => {
+                self.sign(*negative)
+                    | self.round_magnitude(digits, &BigUint::from(10u32).pow(*scale))
+            }
+        }
+    }
+
+    fn sign(self, negative: bool) -> u64 {
+        if negative {
+            self.sign_bit()
+        } else {
+            0
+        }
+    }
+
+    fn round_magnitude(self, num: &BigUint, den: &BigUint) -> u64 {
+        if num.is_zero() {
+            return 0;
+        }
+
+        // floor(log2(num / den))
+        let mut exp = num.bits() as i64 - den.bits() as i64;
+        let below = if exp >= 0 {
+            *num < den << exp as usize
+        } else {
+            (num << (-exp) as usize) < *den
+        };
+        if below {
+            exp -= 1;
+        }
+        let exp = max(exp, 1 - self.bias());
+
+        // num / den * 2^(frac_bits - exp), which has frac_bits + 1 integer bits
+        // for normal numbers and fewer for subnormal ones.
+        let shift = self.frac_bits as i64 - exp;
+        let (num, den) = if shift >= 0 {
+            (num << shift as usize, den.clone())
+        } else {
+            (num.clone(), den << (-shift) as usize)
+        };
+        let quot = &num / &den;
+        let rem2 = (num - &quot * &den) << 1usize;
+        let mut quot = quot.to_u64().unwrap();
+        if rem2 > den || (rem2 == den && quot & 1 == 1) {
+            quot += 1;
+        }
+
+        let mut exp = exp;
+        if quot == 1 << (self.frac_bits + 1) {
+            quot >>= 1;
+            exp += 1;
+        }
+        if exp > self.bias() {
+            self.infinity()
+        } else if quot < 1 << self.frac_bits {
+            quot
+        } else {
+            ((exp + self.bias()) as u64) << self.frac_bits | (quot & self.frac_mask())
+        }
+    }
+
+    fn next_up(self, bits: u64) -> u64 {
+        let magnitude = bits & !self.sign_bit();
+        if magnitude > self.infinity() || bits == self.infinity() {
+            bits
+        } else if magnitude == 0 {
+            1
+        } else if bits & self.sign_bit() == 0 {
+            bits + 1
+        } else {
+            bits - 1
+        }
+    }
+
+    fn next_down(self, bits: u64) -> u64 {
+        self.next_up(bits ^ self.sign_bit()) ^ self.sign_bit()
+    }
+
+    fn to_hex(self, bits: u64) -> String {
+        format!("{:0width$x}", bits, width = (self.width() / 4) as usize)
+    }
+
+    fn to_binary(self, bits: u64) -> String {
+        format!("{:0width$b}", bits, width = self.width() as usize)
+    }
+
+    fn parse_bits(self, text: &str, radix: u32) -> Option<u64> {
+        let text = text.trim().replace(['_', ' '], "");
+        let prefix = if radix == 16 { "0x" } else { "0b" };
+        let text = text.strip_prefix(prefix).unwrap_or(&text);
+        u64::from_str_radix(text, radix)
+            .ok()
+            .filter(|&bits| bits >> (self.width() - 1) >> 1 == 0)
+    }
+}
+
+/// A real number that is exactly representable as a decimal fraction,
+/// `digits / 10^scale`. Every binary floating-point value is one of these.
+#[derive(Clone, PartialEq)]
+enum Value {
+    Nan,
+    Infinity {
+        negative: bool,
+    },
+    Finite {
+        negative: bool,
+        digits: BigUint,
+        scale: u32,
+    },
+}
+
+impl Value {
+    fn parse(text: &str) -> Option<Value> {
+        let text = text.trim().to_ascii_lowercase();
+        let (negative, text) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, &text[..]),
+        };
+
+        match text {
+            "nan" => return Some(Value::Nan),
+            "inf" | "infinity" => return Some(Value::Infinity { negative }),
+            _ => {}
+        }
+
+        let (mantissa, exp) = match text.find('e') {
+            Some(i) => (&text[..i], text[i + 1..].parse::<i64>().ok()?),
+            None => (text, 0),
+        };
+        if exp.unsigned_abs() > MAX_EXPONENT as u64 {
+            return None;
+        }
+        let (int, frac) = match mantissa.find('.') {
+            Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+            None => (mantissa, ""),
+        };
+        if int.is_empty() && frac.is_empty()
+            || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let mut digits = BigUint::parse_bytes(format!("{}{}", int, frac).as_bytes(), 10)?;
+        let mut scale = frac.len() as i64 - exp;
+        if scale < 0 {
+            digits *= BigUint::from(10u32).pow((-scale) as u32);
+            scale = 0;
+        }
+        Some(
+            Value::Finite {
+                negative,
+                digits,
+                scale: scale as u32,
+            }
+            .normalized(),
+        )
+    }
+
+    fn from_dyadic(negative: bool, significand: BigUint, exp: i64) -> Value {
+        let value = if exp >= 0 {
+            Value::Finite {
+                negative,
+                digits: significand << exp as usize,
+                scale: 0,
+            }
+        } else {
+            let scale = (-exp) as u32;
+            Value::Finite {
+                negative,
+                digits: significand * BigUint::from(5u32).pow(scale),
+                scale,
+            }
+        };
+        value.normalized()
+    }
+
+    fn normalized(self) -> Value {
+        match self {
+            Value::Finite {
+                negative,
+                mut digits,
+                mut scale,
+            } => {
+                let ten = BigUint::from(10u32);
+                while scale > 0 && (&digits % &ten).is_zero() {
+                    digits /= &ten;
+                    scale -= 1;
+                }
+                Value::Finite {
+                    negative,
+                    digits,
+                    scale,
+                }
+            }
+            value => value,
+        }
+    }
+
+    fn to_scaled(&self, scale: u32) -> Option<BigInt> {
+        match self {
+            Value::Finite {
+                negative,
+                digits,
+                scale: s,
+            } => {
+                let sign = if *negative { Sign::Minus } else { Sign::Plus };
+                let digits = digits * BigUint::from(10u32).pow(scale - s);
+                Some(BigInt::from_biguint(sign, digits))
+            }
+            _ => None,
+        }
+    }
+
+    /// `self - other`, if both are finite.
+    fn sub(&self, other: &Value) -> Option<Value> {
+        let scale = match (self, other) {
+            (Value::Finite { scale: a, .. }, Value::Finite { scale: b, .. }) => max(*a, *b),
+            _ => return None,
+        };
+        let diff = self.to_scaled(scale)? - other.to_scaled(scale)?;
+        Some(
+            Value::Finite {
+                negative: diff.sign() == Sign::Minus,
+                digits: diff.magnitude().clone(),
+                scale,
+            }
+            .normalized(),
+        )
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Nan => write!(f, "NaN"),
+            Value::Infinity { negative } => {
+                write!(f, "{}Infinity", if *negative { "-" } else { "" })
+            }
+            Value::Finite {
+                negative,
+                digits,
+                scale,
+            } => {
+                let scale = *scale as usize;
+                let mut s = digits.to_str_radix(10);
+                if s.len() <= scale {
+                    s = "0".repeat(scale - s.len() + 1) + &s;
+                }
+                if scale > 0 {
+                    s.insert(s.len() - scale, '.');
+                }
+                write!(f, "{}{}", if *negative { "-" } else { "" }, s)
+            }
+        }
+    }
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Source {
+    Decimal,
+    Hex(usize),
+    Binary(usize),
+}
+
+pub enum Msg {
+    Input { source: Source, text: String },
+    ToggleBit { format: usize, bit: u32 },
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    input: String,
+    #[prop_or_default]
+    value: Option<Value>,
+    #[prop_or_default]
+    bits: Vec<u64>,
+    #[prop_or_default]
+    error: Option<Source>,
+}
+
+impl Model {
+    fn set_bits(&mut self, format: usize, bits: u64) {
+        let value = FORMATS[format].value(bits);
+        self.props.bits = FORMATS.iter().map(|f| f.round(&value)).collect();
+        // Keep NaN payloads and the exact pattern the user entered.
+        self.props.bits[format] = bits;
+        self.props.input = value.to_string();
+        self.props.value = Some(value);
+        self.props.error = None;
+    }
+
+    fn view_format(&self, index: usize, format: Format, bits: u64) -> Html {
+        let value = self.props.value.as_ref().unwrap();
+        let stored = format.value(bits);
+        let (negative, exp, frac) = format.fields(bits);
+
+        let bit_grid = (0..format.width()).rev().map(|bit| {
+            let field = if bit == format.width() - 1 {
+                "is-danger"
+            } else if bit >= format.frac_bits {
+                "is-info"
+            } else {
+                "is-success"
+            };
+            let mut classes = vec!["tag", field];
+            if bits >> bit & 1 == 0 {
+                classes.push("is-light");
+            }
+            let gap = if bit == format.width() - 1 || bit == format.frac_bits {
+                "margin-right: 0.75em;"
+            } else {
+                ""
+            };
+            html! {
+                <a class=classes style=gap title=format!("bit {}", bit)
+                    onclick=self.link.callback(move |_| Msg::ToggleBit { format: index, bit })>
+                    { bits >> bit & 1 }
+                </a>
+            }
+        });
+
+        let exponent = if exp == 0 {
+            format!("{} (subnormal, 2^{})", exp, 1 - format.bias())
+        } else if exp == format.exp_mask() {
+            format!("{} (special)", exp)
+        } else {
+            format!("{} (2^{})", exp, exp as i64 - format.bias())
+        };
+        let mantissa = format!(
+            "0x{:x} ({}{}/2^{})",
+            frac,
+            if exp == 0 { "0 + " } else { "1 + " },
+            frac,
+            format.frac_bits
+        );
+
+        let neighbour = |bits: u64| format!("{} (0x{})", format.value(bits), format.to_hex(bits));
+        let error = stored.sub(value).map_or("-".to_string(), |e| e.to_string());
+
+        let input = |s: String| {
+            html! {
+                <input class="input" type="text" value=s readonly=true/>
+            }
+        };
+        let bits_input = |source: Source, s: String| {
+            let mut classes = vec!["input", "is-family-monospace"];
+            if self.props.error == Some(source) {
+                classes.push("is-danger");
+            }
+            html! {
+                <input class=classes type="text" value=s
+                    oninput=self.link.callback(move |e: InputData| Msg::Input { source, text: e.value })/>
+            }
+        };
+
+        html! {
+            <div class="box">
+                <h3 class="title is-5">{ format.name }</h3>
+                { horizontal_field("Bits", html!{ <div class="tags">{ for bit_grid }</div> }) }
+                { horizontal_field("Hex", bits_input(Source::Hex(index), format.to_hex(bits))) }
+                { horizontal_field("Binary", bits_input(Source::Binary(index), format.to_binary(bits))) }
+                { horizontal_field("Sign", input(format!("{} ({})", negative as u8, if negative { "-" } else { "+" }))) }
+                { horizontal_field("Exponent", input(exponent)) }
+                { horizontal_field("Mantissa", input(mantissa)) }
+                { horizontal_field("Exact value", input(stored.to_string())) }
+                { horizontal_field("Next up", input(neighbour(format.next_up(bits)))) }
+                { horizontal_field("Next down", input(neighbour(format.next_down(bits)))) }
+                { horizontal_field("Rounding error", input(error)) }
+            </div>
+        }
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let mut model = Self { link, props };
+        if model.props.value.is_none() {
+            model.set_bits(FORMATS.len() - 1, 0);
+            model.props.input.clear();
+        }
+        model
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input {
+                source: Source::Decimal,
+                text,
+            } => {
+                let value = if text.trim().is_empty() {
+                    Value::parse("0")
+                } else {
+                    Value::parse(&text)
+                };
+                if let Some(value) = value {
+                    self.props.bits = FORMATS.iter().map(|f| f.round(&value)).collect();
+                    self.props.value = Some(value);
+                    self.props.error = None;
+                } else {
+                    self.props.error = Some(Source::Decimal);
+                }
+                self.props.input = text;
+            }
+            Msg::Input { source, text } => {
+                let (format, radix) = match source {
+                    Source::Hex(format) => (format, 16),
+                    Source::Binary(format) => (format, 2),
+                    Source::Decimal => unreachable!(),
+                };
+                if let Some(bits) = FORMATS[format].parse_bits(&text, radix) {
+                    self.set_bits(format, bits);
+                } else {
+                    self.props.error = Some(source);
+                }
+            }
+            Msg::ToggleBit { format, bit } => {
+                let bits = self.props.bits[format] ^ 1 << bit;
+                self.set_bits(format, bits);
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["input"];
+        if self.props.error == Some(Source::Decimal) {
+            classes.push("is-danger");
+        }
+
+        html! {
+            <>
+            {
+                horizontal_field("Decimal", html!{
+                    <input class=classes type="text" placeholder="0"
+                        value=self.props.input.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::Input { source: Source::Decimal, text: e.value })/>
+                })
+            }
+            {
+                for self.props.bits.iter().enumerate().map(|(i, &bits)| self.view_format(i, FORMATS[i], bits))
+            }
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF: Format = FORMATS[0];
+    const SINGLE: Format = FORMATS[2];
+    const DOUBLE: Format = FORMATS[3];
+
+    fn round(format: Format, text: &str) -> u64 {
+        format.round(&Value::parse(text).unwrap())
+    }
+
+    #[test]
+    fn rounds_like_the_standard_library() {
+        let inputs = [
+            "0.1",
+            "-2.5",
+            "1e308",
+            "1.7976931348623158e308",
+            "1.7976931348623159e308",
+            "4.9e-324",
+            "2.4703282292062327e-324",
+            "2.4703282292062328e-324",
+            "9007199254740993",
+            "3.4028235677973366e38",
+            "1e-45",
+            "0",
+            "-0",
+        ];
+        for text in &inputs {
+            assert_eq!(
+                round(DOUBLE, text),
+                text.parse::<f64>().unwrap().to_bits(),
+                "{}",
+                text
+            );
+            assert_eq!(
+                round(SINGLE, text),
+                text.parse::<f32>().unwrap().to_bits() as u64,
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn rounds_half_precision() {
+        assert_eq!(round(HALF, "1"), 0x3c00);
+        assert_eq!(round(HALF, "65504"), 0x7bff);
+        assert_eq!(round(HALF, "65520"), 0x7c00);
+        assert_eq!(round(HALF, "2049"), 0x6800);
+        assert_eq!(round(HALF, "2051"), 0x6802);
+    }
+
+    #[test]
+    fn exact_value_of_a_double() {
+        let bits = 0.1f64.to_bits();
+        assert_eq!(
+            DOUBLE.value(bits).to_string(),
+            "0.1000000000000000055511151231257827021181583404541015625"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_out_of_range_exponents() {
+        assert!(Value::parse("1e5000").is_some());
+        assert!(Value::parse("1e5001").is_none());
+        assert!(Value::parse("1e-9223372036854775808").is_none());
+        assert!(Value::parse("1e9223372036854775807").is_none());
+    }
+}
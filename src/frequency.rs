@@ -0,0 +1,387 @@
+use std::collections::{HashMap, HashSet};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// English function words left out when stop words are enabled.
+pub const STOP_WORDS: &[&str] = &[
+    "a",
+    "about",
+    "above",
+    "after",
+    "again",
+    "against",
+    "all",
+    "am",
+    "an",
+    "and",
+    "any",
+    "are",
+    "as",
+    "at",
+    "be",
+    "because",
+    "been",
+    "before",
+    "being",
+    "below",
+    "between",
+    "both",
+    "but",
+    "by",
+    "can",
+    "could",
+    "did",
+    "do",
+    "does",
+    "doing",
+    "down",
+    "during",
+    "each",
+    "few",
+    "for",
+    "from",
+    "further",
+    "had",
+    "has",
+    "have",
+    "having",
+    "he",
+    "her",
+    "here",
+    "hers",
+    "herself",
+    "him",
+    "himself",
+    "his",
+    "how",
+    "i",
+    "if",
+    "in",
+    "into",
+    "is",
+    "it",
+    "its",
+    "itself",
+    "just",
+    "me",
+    "more",
+    "most",
+    "my",
+    "myself",
+    "no",
+    "nor",
+    "not",
+    "now",
+    "of",
+    "off",
+    "on",
+    "once",
+    "only",
+    "or",
+    "other",
+    "our",
+    "ours",
+    "ourselves",
+    "out",
+    "over",
+    "own",
+    "same",
+    "she",
+    "should",
+    "so",
+    "some",
+    "such",
+    "than",
+    "that",
+    "the",
+    "their",
+    "theirs",
+    "them",
+    "themselves",
+    "then",
+    "there",
+    "these",
+    "they",
+    "this",
+    "those",
+    "through",
+    "to",
+    "too",
+    "under",
+    "until",
+    "up",
+    "very",
+    "was",
+    "we",
+    "were",
+    "what",
+    "when",
+    "where",
+    "which",
+    "while",
+    "who",
+    "whom",
+    "why",
+    "will",
+    "with",
+    "would",
+    "you",
+    "your",
+    "yours",
+    "yourself",
+    "yourselves",
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Unit {
+    Chars,
+    Words,
+    CharNgrams,
+    WordNgrams,
+}
+
+pub const UNITS: &[Unit] = &[Unit::Chars, Unit::Words, Unit::CharNgrams, Unit::WordNgrams];
+
+impl Unit {
+    pub fn name(self) -> &'static str {
+        match self {
+            Unit::Chars => "Characters",
+            Unit::Words => "Words",
+            Unit::CharNgrams => "Character n-grams",
+            Unit::WordNgrams => "Word n-grams",
+        }
+    }
+
+    pub fn has_n(self) -> bool {
+        matches!(self, Unit::CharNgrams | Unit::WordNgrams)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SortKey {
+    Token,
+    Count,
+}
+
+#[derive(Clone)]
+pub struct Options {
+    pub unit: Unit,
+    pub n: usize,
+    pub fold_case: bool,
+    /// Words to drop before counting words and word n-grams.
+    pub stop_words: HashSet<String>,
+}
+
+pub struct Entry {
+    pub token: String,
+    pub count: usize,
+}
+
+/// Characters are grapheme clusters, whitespace excluded. Words follow the
+/// Unicode word boundary rules, so punctuation is dropped and CJK text is
+/// split per ideograph. N-grams never span a line break.
+fn tokens(text: &str, options: &Options) -> Vec<String> {
+    let text = if options.fold_case {
+        text.to_lowercase()
+    } else {
+        text.to_string()
+    };
+    let n = options.n.max(1);
+    let words = |line: &str| -> Vec<String> {
+        line.unicode_words()
+            .filter(|w| !options.stop_words.contains(&w.to_lowercase()))
+            .map(str::to_string)
+            .collect()
+    };
+
+    match options.unit {
+        Unit::Chars => text
+            .graphemes(true)
+            .filter(|g| !g.chars().all(char::is_whitespace))
+            .map(str::to_string)
+            .collect(),
+        Unit::Words => words(&text),
+        Unit::CharNgrams => text
+            .lines()
+            .flat_map(|line| {
+                let graphemes: Vec<&str> = line.graphemes(true).collect();
+                graphemes
+                    .windows(n)
+                    .filter(|w| !w.iter().all(|g| g.chars().all(char::is_whitespace)))
+                    .map(|w| w.concat())
+                    .collect::<Vec<_>>()
+            })
+            .collect(),
+        Unit::WordNgrams => text
+            .lines()
+            .flat_map(|line| {
+                words(line)
+                    .windows(n)
+                    .map(|w| w.join(" "))
+                    .collect::<Vec<_>>()
+            })
+            .collect(),
+    }
+}
+
+/// Counts the tokens of `text`, sorted by `key` with ties broken by token.
+pub fn frequencies(text: &str, options: &Options, key: SortKey, descending: bool) -> Vec<Entry> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for token in tokens(text, options) {
+        *counts.entry(token).or_default() += 1;
+    }
+    let mut entries: Vec<Entry> = counts
+        .into_iter()
+        .map(|(token, count)| Entry { token, count })
+        .collect();
+    entries.sort_by(|a, b| {
+        let order = match key {
+            SortKey::Token => a.token.cmp(&b.token),
+            SortKey::Count => a.count.cmp(&b.count),
+        };
+        let order = if descending { order.reverse() } else { order };
+        order.then_with(|| a.token.cmp(&b.token))
+    });
+    entries
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) || s.starts_with(' ') || s.ends_with(' ') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// RFC 4180 CSV with a header row.
+pub fn to_csv(entries: &[Entry], total: usize) -> String {
+    let mut csv = "token,count,percent\r\n".to_string();
+    for entry in entries {
+        csv += &format!(
+            "{},{},{:.2}\r\n",
+            csv_field(&entry.token),
+            entry.count,
+            entry.count as f64 * 100.0 / total as f64
+        );
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(unit: Unit, n: usize, fold_case: bool, stop_words: &[&str]) -> Options {
+        Options {
+            unit,
+            n,
+            fold_case,
+            stop_words: stop_words.iter().map(|w| w.to_string()).collect(),
+        }
+    }
+
+    fn counts(text: &str, options: &Options) -> Vec<(String, usize)> {
+        frequencies(text, options, SortKey::Count, true)
+            .into_iter()
+            .map(|e| (e.token, e.count))
+            .collect()
+    }
+
+    fn pairs(expected: &[(&str, usize)]) -> Vec<(String, usize)> {
+        expected.iter().map(|&(t, c)| (t.to_string(), c)).collect()
+    }
+
+    #[test]
+    fn counts_characters() {
+        let chars = options(Unit::Chars, 1, false, &[]);
+        assert_eq!(counts("aab a\n", &chars), pairs(&[("a", 3), ("b", 1)]));
+        assert_eq!(counts("日本日", &chars), pairs(&[("日", 2), ("本", 1)]));
+        assert_eq!(
+            counts("👍🏽👍🏽e\u{301}", &chars),
+            pairs(&[("👍🏽", 2), ("e\u{301}", 1)])
+        );
+        assert_eq!(counts("Aa", &chars), pairs(&[("A", 1), ("a", 1)]));
+        assert_eq!(
+            counts("Aa", &options(Unit::Chars, 1, true, &[])),
+            pairs(&[("a", 2)])
+        );
+    }
+
+    #[test]
+    fn counts_words() {
+        let text = "The cat, the CAT.";
+        assert_eq!(
+            counts(text, &options(Unit::Words, 1, false, &[])),
+            pairs(&[("CAT", 1), ("The", 1), ("cat", 1), ("the", 1)])
+        );
+        assert_eq!(
+            counts(text, &options(Unit::Words, 1, true, &[])),
+            pairs(&[("cat", 2), ("the", 2)])
+        );
+        assert_eq!(
+            counts(text, &options(Unit::Words, 1, false, &["the"])),
+            pairs(&[("CAT", 1), ("cat", 1)])
+        );
+        assert_eq!(
+            counts("東京都の都", &options(Unit::Words, 1, false, &[])),
+            pairs(&[("都", 2), ("の", 1), ("京", 1), ("東", 1)])
+        );
+    }
+
+    #[test]
+    fn counts_ngrams_within_lines() {
+        let bigrams = options(Unit::CharNgrams, 2, false, &[]);
+        assert_eq!(counts("abab", &bigrams), pairs(&[("ab", 2), ("ba", 1)]));
+        assert_eq!(counts("ab\ncd", &bigrams), pairs(&[("ab", 1), ("cd", 1)]));
+        assert_eq!(counts("a  b", &bigrams), pairs(&[(" b", 1), ("a ", 1)]));
+        assert_eq!(counts("a", &bigrams), pairs(&[]));
+        assert_eq!(
+            counts("ab", &options(Unit::CharNgrams, 0, false, &[])),
+            pairs(&[("a", 1), ("b", 1)])
+        );
+
+        let words = options(Unit::WordNgrams, 2, true, &["the"]);
+        assert_eq!(
+            counts("The cat sat, the cat sat\nsat down", &words),
+            pairs(&[("cat sat", 2), ("sat cat", 1), ("sat down", 1)])
+        );
+    }
+
+    #[test]
+    fn sorts_with_ties_broken_by_token() {
+        let words = options(Unit::Words, 1, false, &[]);
+        let sorted = |key, descending| -> Vec<String> {
+            frequencies("b a a c c", &words, key, descending)
+                .into_iter()
+                .map(|e| e.token)
+                .collect()
+        };
+        assert_eq!(sorted(SortKey::Count, true), ["a", "c", "b"]);
+        assert_eq!(sorted(SortKey::Count, false), ["b", "a", "c"]);
+        assert_eq!(sorted(SortKey::Token, false), ["a", "b", "c"]);
+        assert_eq!(sorted(SortKey::Token, true), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn escapes_csv_fields() {
+        let entry = |token: &str, count| Entry {
+            token: token.to_string(),
+            count,
+        };
+        let entries = [
+            entry("a,b", 3),
+            entry("say \"hi\"", 2),
+            entry(" x", 1),
+            entry("line\nbreak", 1),
+            entry("plain", 1),
+        ];
+        assert_eq!(
+            to_csv(&entries, 8),
+            "token,count,percent\r\n\
+             \"a,b\",3,37.50\r\n\
+             \"say \"\"hi\"\"\",2,25.00\r\n\
+             \" x\",1,12.50\r\n\
+             \"line\nbreak\",1,12.50\r\n\
+             plain,1,12.50\r\n"
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::BTreeMap;
+
+/// The Act on National Holidays took effect in July 1948; 1949 is the
+/// first full year it covers.
+pub const FIRST_YEAR: i32 = 1949;
+
+/// The equinox formulas below are only fitted up to this year.
+pub const LAST_YEAR: i32 = 2099;
+
+/// One-off holidays for imperial ceremonies.
+const SPECIAL: &[((i32, u32, u32), &str)] = &[
+    ((1959, 4, 10), "皇太子明仁親王の結婚の儀"),
+    ((1989, 2, 24), "昭和天皇の大喪の礼"),
+    ((1990, 11, 12), "即位礼正殿の儀"),
+    ((1993, 6, 9), "皇太子徳仁親王の結婚の儀"),
+    ((2019, 5, 1), "天皇の即位の日"),
+    ((2019, 10, 22), "即位礼正殿の儀"),
+];
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn monday(year: i32, month: u32, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Mon, n).unwrap()
+}
+
+/// The day of the vernal or autumnal equinox in Japan standard time, by
+/// the approximation the National Astronomical Observatory publishes.
+fn equinox(year: i32, autumn: bool) -> u32 {
+    let (base, leap) = match (year < 1980, autumn) {
+        (true, false) => (20.8357, (year - 1983) / 4),
+        (true, true) => (23.2588, (year - 1983) / 4),
+        (false, false) => (20.8431, (year - 1980) / 4),
+        (false, true) => (23.2488, (year - 1980) / 4),
+    };
+    (base + 0.242194 * (year - 1980) as f64 - leap as f64) as u32
+}
+
+/// National holidays (国民の祝日) defined by date or by rule, without
+/// substitute holidays and the days between two holidays.
+fn statutory(year: i32) -> Vec<(NaiveDate, &'static str)> {
+    let mut days = vec![(date(year, 1, 1), "元日")];
+
+    days.push(if year < 2000 {
+        (date(year, 1, 15), "成人の日")
+    } else {
+        (monday(year, 1, 2), "成人の日")
+    });
+    if year >= 1967 {
+        days.push((date(year, 2, 11), "建国記念の日"));
+    }
+    if year >= 2020 {
+        days.push((date(year, 2, 23), "天皇誕生日"));
+    }
+    days.push((date(year, 3, equinox(year, false)), "春分の日"));
+    days.push((
+        date(year, 4, 29),
+        match year {
+            ..=1988 => "天皇誕生日",
+            1989..=2006 => "みどりの日",
+            _ => "昭和の日",
+        },
+    ));
+    days.push((date(year, 5, 3), "憲法記念日"));
+    if year >= 2007 {
+        days.push((date(year, 5, 4), "みどりの日"));
+    }
+    days.push((date(year, 5, 5), "こどもの日"));
+
+    // The 2020 and 2021 holidays were moved around the Tokyo Olympics.
+    match year {
+        1996..=2002 => days.push((date(year, 7, 20), "海の日")),
+        2020 => days.push((date(year, 7, 23), "海の日")),
+        2021 => days.push((date(year, 7, 22), "海の日")),
+        2003.. => days.push((monday(year, 7, 3), "海の日")),
+        _ => {}
+    }
+    match year {
+        2020 => days.push((date(year, 8, 10), "山の日")),
+        2021 => days.push((date(year, 8, 8), "山の日")),
+        2016.. => days.push((date(year, 8, 11), "山の日")),
+        _ => {}
+    }
+    match year {
+        1966..=2002 => days.push((date(year, 9, 15), "敬老の日")),
+        2003.. => days.push((monday(year, 9, 3), "敬老の日")),
+        _ => {}
+    }
+    days.push((date(year, 9, equinox(year, true)), "秋分の日"));
+    match year {
+        1966..=1999 => days.push((date(year, 10, 10), "体育の日")),
+        2000..=2019 => days.push((monday(year, 10, 2), "体育の日")),
+        2020 => days.push((date(year, 7, 24), "スポーツの日")),
+        2021 => days.push((date(year, 7, 23), "スポーツの日")),
+        2022.. => days.push((monday(year, 10, 2), "スポーツの日")),
+        _ => {}
+    }
+    days.push((date(year, 11, 3), "文化の日"));
+    days.push((date(year, 11, 23), "勤労感謝の日"));
+    if (1989..=2018).contains(&year) {
+        days.push((date(year, 12, 23), "天皇誕生日"));
+    }
+
+    days.extend(
+        SPECIAL
+            .iter()
+            .filter(|&&((y, _, _), _)| y == year)
+            .map(|&((y, m, d), name)| (date(y, m, d), name)),
+    );
+    days
+}
+
+/// Japanese national holidays in `year`, including substitute holidays
+/// (振替休日) and days sandwiched between two holidays (国民の休日).
+/// Returns nothing outside `FIRST_YEAR..=LAST_YEAR`.
+pub fn japanese_holidays(year: i32) -> BTreeMap<NaiveDate, &'static str> {
+    if !(FIRST_YEAR..=LAST_YEAR).contains(&year) {
+        return BTreeMap::new();
+    }
+
+    // Look at the neighbouring years too, so that a substitute for a
+    // Sunday on 12/31 or a sandwich across New Year is not missed.
+    let statutory: BTreeMap<NaiveDate, &str> = (year - 1..=year + 1)
+        .filter(|y| (FIRST_YEAR..=LAST_YEAR).contains(y))
+        .flat_map(statutory)
+        .collect();
+    let mut holidays = statutory.clone();
+
+    // Since 1973-04-12, a holiday on a Sunday moves to the next day that is
+    // not a holiday. Until 2006 only the Monday itself could be used.
+    for &day in statutory.keys() {
+        if day.weekday() != Weekday::Sun || day < date(1973, 4, 12) {
+            continue;
+        }
+        let mut next = day + Duration::days(1);
+        if day.year() >= 2007 {
+            while statutory.contains_key(&next) {
+                next += Duration::days(1);
+            }
+        }
+        if !statutory.contains_key(&next) {
+            holidays.insert(next, "振替休日");
+        }
+    }
+
+    // Since 1986, a weekday between two holidays is a holiday as well.
+    for &day in statutory.keys() {
+        let between = day + Duration::days(1);
+        let next = day + Duration::days(2);
+        if between.year() >= 1986
+            && statutory.contains_key(&next)
+            && !holidays.contains_key(&between)
+            && between.weekday() != Weekday::Sun
+        {
+            holidays.insert(between, "国民の休日");
+        }
+    }
+
+    holidays.retain(|day, _| day.year() == year);
+    holidays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holiday(year: i32, month: u32, day: u32) -> Option<&'static str> {
+        japanese_holidays(year)
+            .get(&date(year, month, day))
+            .copied()
+    }
+
+    #[test]
+    fn holidays_in_2024() {
+        let holidays = japanese_holidays(2024);
+        assert_eq!(holidays.len(), 21);
+        assert_eq!(holiday(2024, 1, 8), Some("成人の日"));
+        assert_eq!(holiday(2024, 2, 12), Some("振替休日"));
+        assert_eq!(holiday(2024, 3, 20), Some("春分の日"));
+        assert_eq!(holiday(2024, 5, 6), Some("振替休日"));
+        assert_eq!(holiday(2024, 9, 22), Some("秋分の日"));
+        assert_eq!(holiday(2024, 9, 23), Some("振替休日"));
+    }
+
+    #[test]
+    fn substitute_holidays() {
+        // The first substitute holiday, right after the law took effect.
+        assert_eq!(holiday(1973, 4, 30), Some("振替休日"));
+        // Since 2007 the substitute skips over following holidays.
+        assert_eq!(holiday(2008, 5, 6), Some("振替休日"));
+        assert_eq!(holiday(2020, 2, 24), Some("振替休日"));
+    }
+
+    #[test]
+    fn days_between_two_holidays() {
+        assert_eq!(holiday(1988, 5, 4), Some("国民の休日"));
+        assert_eq!(holiday(2009, 9, 22), Some("国民の休日"));
+        assert_eq!(holiday(2019, 4, 30), Some("国民の休日"));
+        assert_eq!(holiday(2019, 5, 1), Some("天皇の即位の日"));
+        assert_eq!(holiday(2019, 5, 2), Some("国民の休日"));
+    }
+
+    #[test]
+    fn moved_and_renamed_holidays() {
+        assert_eq!(holiday(2018, 12, 23), Some("天皇誕生日"));
+        assert_eq!(holiday(2019, 12, 23), None);
+        assert_eq!(holiday(2020, 7, 24), Some("スポーツの日"));
+        assert_eq!(holiday(2021, 8, 9), Some("振替休日"));
+        assert_eq!(holiday(2006, 4, 29), Some("みどりの日"));
+        assert_eq!(holiday(2007, 4, 29), Some("昭和の日"));
+    }
+
+    #[test]
+    fn years_outside_the_supported_range_are_empty() {
+        assert!(japanese_holidays(FIRST_YEAR - 1).is_empty());
+        assert!(japanese_holidays(LAST_YEAR + 1).is_empty());
+        assert!(!japanese_holidays(LAST_YEAR).is_empty());
+    }
+}
@@ -0,0 +1,3581 @@
+//! HTML named character references, generated from the WHATWG entity list.
+
+/// Entity names without the `&` and `;`, sorted for binary search.
+pub const ENTITIES: &[(&str, &str)] = &[
+    ("AElig", "\u{C6}"),
+    ("AMP", "&"),
+    ("Aacute", "\u{C1}"),
+    ("Abreve", "\u{102}"),
+    ("Acirc", "\u{C2}"),
+    ("Acy", "\u{410}"),
+    ("Afr", "\u{1D504}"),
+    ("Agrave", "\u{C0}"),
+    ("Alpha", "\u{391}"),
+    ("Amacr", "\u{100}"),
+    ("And", "\u{2A53}"),
+    ("Aogon", "\u{104}"),
+    ("Aopf", "\u{1D538}"),
+    ("ApplyFunction", "\u{2061}"),
+    ("Aring", "\u{C5}"),
+    ("Ascr", "\u{1D49C}"),
+    ("Assign", "\u{2254}"),
+    ("Atilde", "\u{C3}"),
+    ("Auml", "\u{C4}"),
+    ("Backslash", "\u{2216}"),
+    ("Barv", "\u{2AE7}"),
+    ("Barwed", "\u{2306}"),
+    ("Bcy", "\u{411}"),
+    ("Because", "\u{2235}"),
+    ("Bernoullis", "\u{212C}"),
+    ("Beta", "\u{392}"),
+    ("Bfr", "\u{1D505}"),
+    ("Bopf", "\u{1D539}"),
+    ("Breve", "\u{2D8}"),
+    ("Bscr", "\u{212C}"),
+    ("Bumpeq", "\u{224E}"),
+    ("CHcy", "\u{427}"),
+    ("COPY", "\u{A9}"),
+    ("Cacute", "\u{106}"),
+    ("Cap", "\u{22D2}"),
+    ("CapitalDifferentialD", "\u{2145}"),
+    ("Cayleys", "\u{212D}"),
+    ("Ccaron", "\u{10C}"),
+    ("Ccedil", "\u{C7}"),
+    ("Ccirc", "\u{108}"),
+    ("Cconint", "\u{2230}"),
+    ("Cdot", "\u{10A}"),
+    ("Cedilla", "\u{B8}"),
+    ("CenterDot", "\u{B7}"),
+    ("Cfr", "\u{212D}"),
+    ("Chi", "\u{3A7}"),
+    ("CircleDot", "\u{2299}"),
+    ("CircleMinus", "\u{2296}"),
+    ("CirclePlus", "\u{2295}"),
+    ("CircleTimes", "\u{2297}"),
+    ("ClockwiseContourIntegral", "\u{2232}"),
+    ("CloseCurlyDoubleQuote", "\u{201D}"),
+    ("CloseCurlyQuote", "\u{2019}"),
+    ("Colon", "\u{2237}"),
+    ("Colone", "\u{2A74}"),
+    ("Congruent", "\u{2261}"),
+    ("Conint", "\u{222F}"),
+    ("ContourIntegral", "\u{222E}"),
+    ("Copf", "\u{2102}"),
+    ("Coproduct", "\u{2210}"),
+    ("CounterClockwiseContourIntegral", "\u{2233}"),
+    ("Cross", "\u{2A2F}"),
+    ("Cscr", "\u{1D49E}"),
+    ("Cup", "\u{22D3}"),
+    ("CupCap", "\u{224D}"),
+    ("DD", "\u{2145}"),
+    ("DDotrahd", "\u{2911}"),
+    ("DJcy", "\u{402}"),
+    ("DScy", "\u{405}"),
+    ("DZcy", "\u{40F}"),
+    ("Dagger", "\u{2021}"),
+    ("Darr", "\u{21A1}"),
+    ("Dashv", "\u{2AE4}"),
+    ("Dcaron", "\u{10E}"),
+    ("Dcy", "\u{414}"),
+    ("Del", "\u{2207}"),
+    ("Delta", "\u{394}"),
+    ("Dfr", "\u{1D507}"),
+    ("DiacriticalAcute", "\u{B4}"),
+    ("DiacriticalDot", "\u{2D9}"),
+    ("DiacriticalDoubleAcute", "\u{2DD}"),
+    ("DiacriticalGrave", "`"),
+    ("DiacriticalTilde", "\u{2DC}"),
+    ("Diamond", "\u{22C4}"),
+    ("DifferentialD", "\u{2146}"),
+    ("Dopf", "\u{1D53B}"),
+    ("Dot", "\u{A8}"),
+    ("DotDot", "\u{20DC}"),
+    ("DotEqual", "\u{2250}"),
+    ("DoubleContourIntegral", "\u{222F}"),
+    ("DoubleDot", "\u{A8}"),
+    ("DoubleDownArrow", "\u{21D3}"),
+    ("DoubleLeftArrow", "\u{21D0}"),
+    ("DoubleLeftRightArrow", "\u{21D4}"),
+    ("DoubleLeftTee", "\u{2AE4}"),
+    ("DoubleLongLeftArrow", "\u{27F8}"),
+    ("DoubleLongLeftRightArrow", "\u{27FA}"),
+    ("DoubleLongRightArrow", "\u{27F9}"),
+    ("DoubleRightArrow", "\u{21D2}"),
+    ("DoubleRightTee", "\u{22A8}"),
+    ("DoubleUpArrow", "\u{21D1}"),
+    ("DoubleUpDownArrow", "\u{21D5}"),
+    ("DoubleVerticalBar", "\u{2225}"),
+    ("DownArrow", "\u{2193}"),
+    ("DownArrowBar", "\u{2913}"),
+    ("DownArrowUpArrow", "\u{21F5}"),
+    ("DownBreve", "\u{311}"),
+    ("DownLeftRightVector", "\u{2950}"),
+    ("DownLeftTeeVector", "\u{295E}"),
+    ("DownLeftVector", "\u{21BD}"),
+    ("DownLeftVectorBar", "\u{2956}"),
+    ("DownRightTeeVector", "\u{295F}"),
+    ("DownRightVector", "\u{21C1}"),
+    ("DownRightVectorBar", "\u{2957}"),
+    ("DownTee", "\u{22A4}"),
+    ("DownTeeArrow", "\u{21A7}"),
+    ("Downarrow", "\u{21D3}"),
+    ("Dscr", "\u{1D49F}"),
+    ("Dstrok", "\u{110}"),
+    ("ENG", "\u{14A}"),
+    ("ETH", "\u{D0}"),
+    ("Eacute", "\u{C9}"),
+    ("Ecaron", "\u{11A}"),
+    ("Ecirc", "\u{CA}"),
+    ("Ecy", "\u{42D}"),
+    ("Edot", "\u{116}"),
+    ("Efr", "\u{1D508}"),
+    ("Egrave", "\u{C8}"),
+    ("Element", "\u{2208}"),
+    ("Emacr", "\u{112}"),
+    ("EmptySmallSquare", "\u{25FB}"),
+    ("EmptyVerySmallSquare", "\u{25AB}"),
+    ("Eogon", "\u{118}"),
+    ("Eopf", "\u{1D53C}"),
+    ("Epsilon", "\u{395}"),
+    ("Equal", "\u{2A75}"),
+    ("EqualTilde", "\u{2242}"),
+    ("Equilibrium", "\u{21CC}"),
+    ("Escr", "\u{2130}"),
+    ("Esim", "\u{2A73}"),
+    ("Eta", "\u{397}"),
+    ("Euml", "\u{CB}"),
+    ("Exists", "\u{2203}"),
+    ("ExponentialE", "\u{2147}"),
+    ("Fcy", "\u{424}"),
+    ("Ffr", "\u{1D509}"),
+    ("FilledSmallSquare", "\u{25FC}"),
+    ("FilledVerySmallSquare", "\u{25AA}"),
+    ("Fopf", "\u{1D53D}"),
+    ("ForAll", "\u{2200}"),
+    ("Fouriertrf", "\u{2131}"),
+    ("Fscr", "\u{2131}"),
+    ("GJcy", "\u{403}"),
+    ("GT", ">"),
+    ("Gamma", "\u{393}"),
+    ("Gammad", "\u{3DC}"),
+    ("Gbreve", "\u{11E}"),
+    ("Gcedil", "\u{122}"),
+    ("Gcirc", "\u{11C}"),
+    ("Gcy", "\u{413}"),
+    ("Gdot", "\u{120}"),
+    ("Gfr", "\u{1D50A}"),
+    ("Gg", "\u{22D9}"),
+    ("Gopf", "\u{1D53E}"),
+    ("GreaterEqual", "\u{2265}"),
+    ("GreaterEqualLess", "\u{22DB}"),
+    ("GreaterFullEqual", "\u{2267}"),
+    ("GreaterGreater", "\u{2AA2}"),
+    ("GreaterLess", "\u{2277}"),
+    ("GreaterSlantEqual", "\u{2A7E}"),
+    ("GreaterTilde", "\u{2273}"),
+    ("Gscr", "\u{1D4A2}"),
+    ("Gt", "\u{226B}"),
+    ("HARDcy", "\u{42A}"),
+    ("Hacek", "\u{2C7}"),
+    ("Hat", "^"),
+    ("Hcirc", "\u{124}"),
+    ("Hfr", "\u{210C}"),
+    ("HilbertSpace", "\u{210B}"),
+    ("Hopf", "\u{210D}"),
+    ("HorizontalLine", "\u{2500}"),
+    ("Hscr", "\u{210B}"),
+    ("Hstrok", "\u{126}"),
+    ("HumpDownHump", "\u{224E}"),
+    ("HumpEqual", "\u{224F}"),
+    ("IEcy", "\u{415}"),
+    ("IJlig", "\u{132}"),
+    ("IOcy", "\u{401}"),
+    ("Iacute", "\u{CD}"),
+    ("Icirc", "\u{CE}"),
+    ("Icy", "\u{418}"),
+    ("Idot", "\u{130}"),
+    ("Ifr", "\u{2111}"),
+    ("Igrave", "\u{CC}"),
+    ("Im", "\u{2111}"),
+    ("Imacr", "\u{12A}"),
+    ("ImaginaryI", "\u{2148}"),
+    ("Implies", "\u{21D2}"),
+    ("Int", "\u{222C}"),
+    ("Integral", "\u{222B}"),
+    ("Intersection", "\u{22C2}"),
+    ("InvisibleComma", "\u{2063}"),
+    ("InvisibleTimes", "\u{2062}"),
+    ("Iogon", "\u{12E}"),
+    ("Iopf", "\u{1D540}"),
+    ("Iota", "\u{399}"),
+    ("Iscr", "\u{2110}"),
+    ("Itilde", "\u{128}"),
+    ("Iukcy", "\u{406}"),
+    ("Iuml", "\u{CF}"),
+    ("Jcirc", "\u{134}"),
+    ("Jcy", "\u{419}"),
+    ("Jfr", "\u{1D50D}"),
+    ("Jopf", "\u{1D541}"),
+    ("Jscr", "\u{1D4A5}"),
+    ("Jsercy", "\u{408}"),
+    ("Jukcy", "\u{404}"),
+    ("KHcy", "\u{425}"),
+    ("KJcy", "\u{40C}"),
+    ("Kappa", "\u{39A}"),
+    ("Kcedil", "\u{136}"),
+    ("Kcy", "\u{41A}"),
+    ("Kfr", "\u{1D50E}"),
+    ("Kopf", "\u{1D542}"),
+    ("Kscr", "\u{1D4A6}"),
+    ("LJcy", "\u{409}"),
+    ("LT", "<"),
+    ("Lacute", "\u{139}"),
+    ("Lambda", "\u{39B}"),
+    ("Lang", "\u{27EA}"),
+    ("Laplacetrf", "\u{2112}"),
+    ("Larr", "\u{219E}"),
+    ("Lcaron", "\u{13D}"),
+    ("Lcedil", "\u{13B}"),
+    ("Lcy", "\u{41B}"),
+    ("LeftAngleBracket", "\u{27E8}"),
+    ("LeftArrow", "\u{2190}"),
+    ("LeftArrowBar", "\u{21E4}"),
+    ("LeftArrowRightArrow", "\u{21C6}"),
+    ("LeftCeiling", "\u{2308}"),
+    ("LeftDoubleBracket", "\u{27E6}"),
+    ("LeftDownTeeVector", "\u{2961}"),
+    ("LeftDownVector", "\u{21C3}"),
+    ("LeftDownVectorBar", "\u{2959}"),
+    ("LeftFloor", "\u{230A}"),
+    ("LeftRightArrow", "\u{2194}"),
+    ("LeftRightVector", "\u{294E}"),
+    ("LeftTee", "\u{22A3}"),
+    ("LeftTeeArrow", "\u{21A4}"),
+    ("LeftTeeVector", "\u{295A}"),
+    ("LeftTriangle", "\u{22B2}"),
+    ("LeftTriangleBar", "\u{29CF}"),
+    ("LeftTriangleEqual", "\u{22B4}"),
+    ("LeftUpDownVector", "\u{2951}"),
+    ("LeftUpTeeVector", "\u{2960}"),
+    ("LeftUpVector", "\u{21BF}"),
+    ("LeftUpVectorBar", "\u{2958}"),
+    ("LeftVector", "\u{21BC}"),
+    ("LeftVectorBar", "\u{2952}"),
+    ("Leftarrow", "\u{21D0}"),
+    ("Leftrightarrow", "\u{21D4}"),
+    ("LessEqualGreater", "\u{22DA}"),
+    ("LessFullEqual", "\u{2266}"),
+    ("LessGreater", "\u{2276}"),
+    ("LessLess", "\u{2AA1}"),
+    ("LessSlantEqual", "\u{2A7D}"),
+    ("LessTilde", "\u{2272}"),
+    ("Lfr", "\u{1D50F}"),
+    ("Ll", "\u{22D8}"),
+    ("Lleftarrow", "\u{21DA}"),
+    ("Lmidot", "\u{13F}"),
+    ("LongLeftArrow", "\u{27F5}"),
+    ("LongLeftRightArrow", "\u{27F7}"),
+    ("LongRightArrow", "\u{27F6}"),
+    ("Longleftarrow", "\u{27F8}"),
+    ("Longleftrightarrow", "\u{27FA}"),
+    ("Longrightarrow", "\u{27F9}"),
+    ("Lopf", "\u{1D543}"),
+    ("LowerLeftArrow", "\u{2199}"),
+    ("LowerRightArrow", "\u{2198}"),
+    ("Lscr", "\u{2112}"),
+    ("Lsh", "\u{21B0}"),
+    ("Lstrok", "\u{141}"),
+    ("Lt", "\u{226A}"),
+    ("Map", "\u{2905}"),
+    ("Mcy", "\u{41C}"),
+    ("MediumSpace", "\u{205F}"),
+    ("Mellintrf", "\u{2133}"),
+    ("Mfr", "\u{1D510}"),
+    ("MinusPlus", "\u{2213}"),
+    ("Mopf", "\u{1D544}"),
+    ("Mscr", "\u{2133}"),
+    ("Mu", "\u{39C}"),
+    ("NJcy", "\u{40A}"),
+    ("Nacute", "\u{143}"),
+    ("Ncaron", "\u{147}"),
+    ("Ncedil", "\u{145}"),
+    ("Ncy", "\u{41D}"),
+    ("NegativeMediumSpace", "\u{200B}"),
+    ("NegativeThickSpace", "\u{200B}"),
+    ("NegativeThinSpace", "\u{200B}"),
+    ("NegativeVeryThinSpace", "\u{200B}"),
+    ("NestedGreaterGreater", "\u{226B}"),
+    ("NestedLessLess", "\u{226A}"),
+    ("NewLine", "\u{A}"),
+    ("Nfr", "\u{1D511}"),
+    ("NoBreak", "\u{2060}"),
+    ("NonBreakingSpace", "\u{A0}"),
+    ("Nopf", "\u{2115}"),
+    ("Not", "\u{2AEC}"),
+    ("NotCongruent", "\u{2262}"),
+    ("NotCupCap", "\u{226D}"),
+    ("NotDoubleVerticalBar", "\u{2226}"),
+    ("NotElement", "\u{2209}"),
+    ("NotEqual", "\u{2260}"),
+    ("NotEqualTilde", "\u{2242}\u{338}"),
+    ("NotExists", "\u{2204}"),
+    ("NotGreater", "\u{226F}"),
+    ("NotGreaterEqual", "\u{2271}"),
+    ("NotGreaterFullEqual", "\u{2267}\u{338}"),
+    ("NotGreaterGreater", "\u{226B}\u{338}"),
+    ("NotGreaterLess", "\u{2279}"),
+    ("NotGreaterSlantEqual", "\u{2A7E}\u{338}"),
+    ("NotGreaterTilde", "\u{2275}"),
+    ("NotHumpDownHump", "\u{224E}\u{338}"),
+    ("NotHumpEqual", "\u{224F}\u{338}"),
+    ("NotLeftTriangle", "\u{22EA}"),
+    ("NotLeftTriangleBar", "\u{29CF}\u{338}"),
+    ("NotLeftTriangleEqual", "\u{22EC}"),
+    ("NotLess", "\u{226E}"),
+    ("NotLessEqual", "\u{2270}"),
+    ("NotLessGreater", "\u{2278}"),
+    ("NotLessLess", "\u{226A}\u{338}"),
+    ("NotLessSlantEqual", "\u{2A7D}\u{338}"),
+    ("NotLessTilde", "\u{2274}"),
+    ("NotNestedGreaterGreater", "\u{2AA2}\u{338}"),
+    ("NotNestedLessLess", "\u{2AA1}\u{338}"),
+    ("NotPrecedes", "\u{2280}"),
+    ("NotPrecedesEqual", "\u{2AAF}\u{338}"),
+    ("NotPrecedesSlantEqual", "\u{22E0}"),
+    ("NotReverseElement", "\u{220C}"),
+    ("NotRightTriangle", "\u{22EB}"),
+    ("NotRightTriangleBar", "\u{29D0}\u{338}"),
+    ("NotRightTriangleEqual", "\u{22ED}"),
+    ("NotSquareSubset", "\u{228F}\u{338}"),
+    ("NotSquareSubsetEqual", "\u{22E2}"),
+    ("NotSquareSuperset", "\u{2290}\u{338}"),
+    ("NotSquareSupersetEqual", "\u{22E3}"),
+    ("NotSubset", "\u{2282}\u{20D2}"),
+    ("NotSubsetEqual", "\u{2288}"),
+    ("NotSucceeds", "\u{2281}"),
+    ("NotSucceedsEqual", "\u{2AB0}\u{338}"),
+    ("NotSucceedsSlantEqual", "\u{22E1}"),
+    ("NotSucceedsTilde", "\u{227F}\u{338}"),
+    ("NotSuperset", "\u{2283}\u{20D2}"),
+    ("NotSupersetEqual", "\u{2289}"),
+    ("NotTilde", "\u{2241}"),
+    ("NotTildeEqual", "\u{2244}"),
+    ("NotTildeFullEqual", "\u{2247}"),
+    ("NotTildeTilde", "\u{2249}"),
+    ("NotVerticalBar", "\u{2224}"),
+    ("Nscr", "\u{1D4A9}"),
+    ("Ntilde", "\u{D1}"),
+    ("Nu", "\u{39D}"),
+    ("OElig", "\u{152}"),
+    ("Oacute", "\u{D3}"),
+    ("Ocirc", "\u{D4}"),
+    ("Ocy", "\u{41E}"),
+    ("Odblac", "\u{150}"),
+    ("Ofr", "\u{1D512}"),
+    ("Ograve", "\u{D2}"),
+    ("Omacr", "\u{14C}"),
+    ("Omega", "\u{3A9}"),
+    ("Omicron", "\u{39F}"),
+    ("Oopf", "\u{1D546}"),
+    ("OpenCurlyDoubleQuote", "\u{201C}"),
+    ("OpenCurlyQuote", "\u{2018}"),
+    ("Or", "\u{2A54}"),
+    ("Oscr", "\u{1D4AA}"),
+    ("Oslash", "\u{D8}"),
+    ("Otilde", "\u{D5}"),
+    ("Otimes", "\u{2A37}"),
+    ("Ouml", "\u{D6}"),
+    ("OverBar", "\u{203E}"),
+    ("OverBrace", "\u{23DE}"),
+    ("OverBracket", "\u{23B4}"),
+    ("OverParenthesis", "\u{23DC}"),
+    ("PartialD", "\u{2202}"),
+    ("Pcy", "\u{41F}"),
+    ("Pfr", "\u{1D513}"),
+    ("Phi", "\u{3A6}"),
+    ("Pi", "\u{3A0}"),
+    ("PlusMinus", "\u{B1}"),
+    ("Poincareplane", "\u{210C}"),
+    ("Popf", "\u{2119}"),
+    ("Pr", "\u{2ABB}"),
+    ("Precedes", "\u{227A}"),
+    ("PrecedesEqual", "\u{2AAF}"),
+    ("PrecedesSlantEqual", "\u{227C}"),
+    ("PrecedesTilde", "\u{227E}"),
+    ("Prime", "\u{2033}"),
+    ("Product", "\u{220F}"),
+    ("Proportion", "\u{2237}"),
+    ("Proportional", "\u{221D}"),
+    ("Pscr", "\u{1D4AB}"),
+    ("Psi", "\u{3A8}"),
+    ("QUOT", "\u{22}"),
+    ("Qfr", "\u{1D514}"),
+    ("Qopf", "\u{211A}"),
+    ("Qscr", "\u{1D4AC}"),
+    ("RBarr", "\u{2910}"),
+    ("REG", "\u{AE}"),
+    ("Racute", "\u{154}"),
+    ("Rang", "\u{27EB}"),
+    ("Rarr", "\u{21A0}"),
+    ("Rarrtl", "\u{2916}"),
+    ("Rcaron", "\u{158}"),
+    ("Rcedil", "\u{156}"),
+    ("Rcy", "\u{420}"),
+    ("Re", "\u{211C}"),
+    ("ReverseElement", "\u{220B}"),
+    ("ReverseEquilibrium", "\u{21CB}"),
+    ("ReverseUpEquilibrium", "\u{296F}"),
+    ("Rfr", "\u{211C}"),
+    ("Rho", "\u{3A1}"),
+    ("RightAngleBracket", "\u{27E9}"),
+    ("RightArrow", "\u{2192}"),
+    ("RightArrowBar", "\u{21E5}"),
+    ("RightArrowLeftArrow", "\u{21C4}"),
+    ("RightCeiling", "\u{2309}"),
+    ("RightDoubleBracket", "\u{27E7}"),
+    ("RightDownTeeVector", "\u{295D}"),
+    ("RightDownVector", "\u{21C2}"),
+    ("RightDownVectorBar", "\u{2955}"),
+    ("RightFloor", "\u{230B}"),
+    ("RightTee", "\u{22A2}"),
+    ("RightTeeArrow", "\u{21A6}"),
+    ("RightTeeVector", "\u{295B}"),
+    ("RightTriangle", "\u{22B3}"),
+    ("RightTriangleBar", "\u{29D0}"),
+    ("RightTriangleEqual", "\u{22B5}"),
+    ("RightUpDownVector", "\u{294F}"),
+    ("RightUpTeeVector", "\u{295C}"),
+    ("RightUpVector", "\u{21BE}"),
+    ("RightUpVectorBar", "\u{2954}"),
+    ("RightVector", "\u{21C0}"),
+    ("RightVectorBar", "\u{2953}"),
+    ("Rightarrow", "\u{21D2}"),
+    ("Ropf", "\u{211D}"),
+    ("RoundImplies", "\u{2970}"),
+    ("Rrightarrow", "\u{21DB}"),
+    ("Rscr", "\u{211B}"),
+    ("Rsh", "\u{21B1}"),
+    ("RuleDelayed", "\u{29F4}"),
+    ("SHCHcy", "\u{429}"),
+    ("SHcy", "\u{428}"),
+    ("SOFTcy", "\u{42C}"),
+    ("Sacute", "\u{15A}"),
+    ("Sc", "\u{2ABC}"),
+    ("Scaron", "\u{160}"),
+    ("Scedil", "\u{15E}"),
+    ("Scirc", "\u{15C}"),
+    ("Scy", "\u{421}"),
+    ("Sfr", "\u{1D516}"),
+    ("ShortDownArrow", "\u{2193}"),
+    ("ShortLeftArrow", "\u{2190}"),
+    ("ShortRightArrow", "\u{2192}"),
+    ("ShortUpArrow", "\u{2191}"),
+    ("Sigma", "\u{3A3}"),
+    ("SmallCircle", "\u{2218}"),
+    ("Sopf", "\u{1D54A}"),
+    ("Sqrt", "\u{221A}"),
+    ("Square", "\u{25A1}"),
+    ("SquareIntersection", "\u{2293}"),
+    ("SquareSubset", "\u{228F}"),
+    ("SquareSubsetEqual", "\u{2291}"),
+    ("SquareSuperset", "\u{2290}"),
+    ("SquareSupersetEqual", "\u{2292}"),
+    ("SquareUnion", "\u{2294}"),
+    ("Sscr", "\u{1D4AE}"),
+    ("Star", "\u{22C6}"),
+    ("Sub", "\u{22D0}"),
+    ("Subset", "\u{22D0}"),
+    ("SubsetEqual", "\u{2286}"),
+    ("Succeeds", "\u{227B}"),
+    ("SucceedsEqual", "\u{2AB0}"),
+    ("SucceedsSlantEqual", "\u{227D}"),
+    ("SucceedsTilde", "\u{227F}"),
+    ("SuchThat", "\u{220B}"),
+    ("Sum", "\u{2211}"),
+    ("Sup", "\u{22D1}"),
+    ("Superset", "\u{2283}"),
+    ("SupersetEqual", "\u{2287}"),
+    ("Supset", "\u{22D1}"),
+    ("THORN", "\u{DE}"),
+    ("TRADE", "\u{2122}"),
+    ("TSHcy", "\u{40B}"),
+    ("TScy", "\u{426}"),
+    ("Tab", "\u{9}"),
+    ("Tau", "\u{3A4}"),
+    ("Tcaron", "\u{164}"),
+    ("Tcedil", "\u{162}"),
+    ("Tcy", "\u{422}"),
+    ("Tfr", "\u{1D517}"),
+    ("Therefore", "\u{2234}"),
+    ("Theta", "\u{398}"),
+    ("ThickSpace", "\u{205F}\u{200A}"),
+    ("ThinSpace", "\u{2009}"),
+    ("Tilde", "\u{223C}"),
+    ("TildeEqual", "\u{2243}"),
+    ("TildeFullEqual", "\u{2245}"),
+    ("TildeTilde", "\u{2248}"),
+    ("Topf", "\u{1D54B}"),
+    ("TripleDot", "\u{20DB}"),
+    ("Tscr", "\u{1D4AF}"),
+    ("Tstrok", "\u{166}"),
+    ("Uacute", "\u{DA}"),
+    ("Uarr", "\u{219F}"),
+    ("Uarrocir", "\u{2949}"),
+    ("Ubrcy", "\u{40E}"),
+    ("Ubreve", "\u{16C}"),
+    ("Ucirc", "\u{DB}"),
+    ("Ucy", "\u{423}"),
+    ("Udblac", "\u{170}"),
+    ("Ufr", "\u{1D518}"),
+    ("Ugrave", "\u{D9}"),
+    ("Umacr", "\u{16A}"),
+    ("UnderBar", "_"),
+    ("UnderBrace", "\u{23DF}"),
+    ("UnderBracket", "\u{23B5}"),
+    ("UnderParenthesis", "\u{23DD}"),
+    ("Union", "\u{22C3}"),
+    ("UnionPlus", "\u{228E}"),
+    ("Uogon", "\u{172}"),
+    ("Uopf", "\u{1D54C}"),
+    ("UpArrow", "\u{2191}"),
+    ("UpArrowBar", "\u{2912}"),
+    ("UpArrowDownArrow", "\u{21C5}"),
+    ("UpDownArrow", "\u{2195}"),
+    ("UpEquilibrium", "\u{296E}"),
+    ("UpTee", "\u{22A5}"),
+    ("UpTeeArrow", "\u{21A5}"),
+    ("Uparrow", "\u{21D1}"),
+    ("Updownarrow", "\u{21D5}"),
+    ("UpperLeftArrow", "\u{2196}"),
+    ("UpperRightArrow", "\u{2197}"),
+    ("Upsi", "\u{3D2}"),
+    ("Upsilon", "\u{3A5}"),
+    ("Uring", "\u{16E}"),
+    ("Uscr", "\u{1D4B0}"),
+    ("Utilde", "\u{168}"),
+    ("Uuml", "\u{DC}"),
+    ("VDash", "\u{22AB}"),
+    ("Vbar", "\u{2AEB}"),
+    ("Vcy", "\u{412}"),
+    ("Vdash", "\u{22A9}"),
+    ("Vdashl", "\u{2AE6}"),
+    ("Vee", "\u{22C1}"),
+    ("Verbar", "\u{2016}"),
+    ("Vert", "\u{2016}"),
+    ("VerticalBar", "\u{2223}"),
+    ("VerticalLine", "|"),
+    ("VerticalSeparator", "\u{2758}"),
+    ("VerticalTilde", "\u{2240}"),
+    ("VeryThinSpace", "\u{200A}"),
+    ("Vfr", "\u{1D519}"),
+    ("Vopf", "\u{1D54D}"),
+    ("Vscr", "\u{1D4B1}"),
+    ("Vvdash", "\u{22AA}"),
+    ("Wcirc", "\u{174}"),
+    ("Wedge", "\u{22C0}"),
+    ("Wfr", "\u{1D51A}"),
+    ("Wopf", "\u{1D54E}"),
+    ("Wscr", "\u{1D4B2}"),
+    ("Xfr", "\u{1D51B}"),
+    ("Xi", "\u{39E}"),
+    ("Xopf", "\u{1D54F}"),
+    ("Xscr", "\u{1D4B3}"),
+    ("YAcy", "\u{42F}"),
+    ("YIcy", "\u{407}"),
+    ("YUcy", "\u{42E}"),
+    ("Yacute", "\u{DD}"),
+    ("Ycirc", "\u{176}"),
+    ("Ycy", "\u{42B}"),
+    ("Yfr", "\u{1D51C}"),
+    ("Yopf", "\u{1D550}"),
+    ("Yscr", "\u{1D4B4}"),
+    ("Yuml", "\u{178}"),
+    ("ZHcy", "\u{416}"),
+    ("Zacute", "\u{179}"),
+    ("Zcaron", "\u{17D}"),
+    ("Zcy", "\u{417}"),
+    ("Zdot", "\u{17B}"),
+    ("ZeroWidthSpace", "\u{200B}"),
+    ("Zeta", "\u{396}"),
+    ("Zfr", "\u{2128}"),
+    ("Zopf", "\u{2124}"),
+    ("Zscr", "\u{1D4B5}"),
+    ("aacute", "\u{E1}"),
+    ("abreve", "\u{103}"),
+    ("ac", "\u{223E}"),
+    ("acE", "\u{223E}\u{333}"),
+    ("acd", "\u{223F}"),
+    ("acirc", "\u{E2}"),
+    ("acute", "\u{B4}"),
+    ("acy", "\u{430}"),
+    ("aelig", "\u{E6}"),
+    ("af", "\u{2061}"),
+    ("afr", "\u{1D51E}"),
+    ("agrave", "\u{E0}"),
+    ("alefsym", "\u{2135}"),
+    ("aleph", "\u{2135}"),
+    ("alpha", "\u{3B1}"),
+    ("amacr", "\u{101}"),
+    ("amalg", "\u{2A3F}"),
+    ("amp", "&"),
+    ("and", "\u{2227}"),
+    ("andand", "\u{2A55}"),
+    ("andd", "\u{2A5C}"),
+    ("andslope", "\u{2A58}"),
+    ("andv", "\u{2A5A}"),
+    ("ang", "\u{2220}"),
+    ("ange", "\u{29A4}"),
+    ("angle", "\u{2220}"),
+    ("angmsd", "\u{2221}"),
+    ("angmsdaa", "\u{29A8}"),
+    ("angmsdab", "\u{29A9}"),
+    ("angmsdac", "\u{29AA}"),
+    ("angmsdad", "\u{29AB}"),
+    ("angmsdae", "\u{29AC}"),
+    ("angmsdaf", "\u{29AD}"),
+    ("angmsdag", "\u{29AE}"),
+    ("angmsdah", "\u{29AF}"),
+    ("angrt", "\u{221F}"),
+    ("angrtvb", "\u{22BE}"),
+    ("angrtvbd", "\u{299D}"),
+    ("angsph", "\u{2222}"),
+    ("angst", "\u{C5}"),
+    ("angzarr", "\u{237C}"),
+    ("aogon", "\u{105}"),
+    ("aopf", "\u{1D552}"),
+    ("ap", "\u{2248}"),
+    ("apE", "\u{2A70}"),
+    ("apacir", "\u{2A6F}"),
+    ("ape", "\u{224A}"),
+    ("apid", "\u{224B}"),
+    ("apos", "'"),
+    ("approx", "\u{2248}"),
+    ("approxeq", "\u{224A}"),
+    ("aring", "\u{E5}"),
+    ("ascr", "\u{1D4B6}"),
+    ("ast", "*"),
+    ("asymp", "\u{2248}"),
+    ("asympeq", "\u{224D}"),
+    ("atilde", "\u{E3}"),
+    ("auml", "\u{E4}"),
+    ("awconint", "\u{2233}"),
+    ("awint", "\u{2A11}"),
+    ("bNot", "\u{2AED}"),
+    ("backcong", "\u{224C}"),
+    ("backepsilon", "\u{3F6}"),
+    ("backprime", "\u{2035}"),
+    ("backsim", "\u{223D}"),
+    ("backsimeq", "\u{22CD}"),
+    ("barvee", "\u{22BD}"),
+    ("barwed", "\u{2305}"),
+    ("barwedge", "\u{2305}"),
+    ("bbrk", "\u{23B5}"),
+    ("bbrktbrk", "\u{23B6}"),
+    ("bcong", "\u{224C}"),
+    ("bcy", "\u{431}"),
+    ("bdquo", "\u{201E}"),
+    ("becaus", "\u{2235}"),
+    ("because", "\u{2235}"),
+    ("bemptyv", "\u{29B0}"),
+    ("bepsi", "\u{3F6}"),
+    ("bernou", "\u{212C}"),
+    ("beta", "\u{3B2}"),
+    ("beth", "\u{2136}"),
+    ("between", "\u{226C}"),
+    ("bfr", "\u{1D51F}"),
+    ("bigcap", "\u{22C2}"),
+    ("bigcirc", "\u{25EF}"),
+    ("bigcup", "\u{22C3}"),
+    ("bigodot", "\u{2A00}"),
+    ("bigoplus", "\u{2A01}"),
+    ("bigotimes", "\u{2A02}"),
+    ("bigsqcup", "\u{2A06}"),
+    ("bigstar", "\u{2605}"),
+    ("bigtriangledown", "\u{25BD}"),
+    ("bigtriangleup", "\u{25B3}"),
+    ("biguplus", "\u{2A04}"),
+    ("bigvee", "\u{22C1}"),
+    ("bigwedge", "\u{22C0}"),
+    ("bkarow", "\u{290D}"),
+    ("blacklozenge", "\u{29EB}"),
+    ("blacksquare", "\u{25AA}"),
+    ("blacktriangle", "\u{25B4}"),
+    ("blacktriangledown", "\u{25BE}"),
+    ("blacktriangleleft", "\u{25C2}"),
+    ("blacktriangleright", "\u{25B8}"),
+    ("blank", "\u{2423}"),
+    ("blk12", "\u{2592}"),
+    ("blk14", "\u{2591}"),
+    ("blk34", "\u{2593}"),
+    ("block", "\u{2588}"),
+    ("bne", "=\u{20E5}"),
+    ("bnequiv", "\u{2261}\u{20E5}"),
+    ("bnot", "\u{2310}"),
+    ("bopf", "\u{1D553}"),
+    ("bot", "\u{22A5}"),
+    ("bottom", "\u{22A5}"),
+    ("bowtie", "\u{22C8}"),
+    ("boxDL", "\u{2557}"),
+    ("boxDR", "\u{2554}"),
+    ("boxDl", "\u{2556}"),
+    ("boxDr", "\u{2553}"),
+    ("boxH", "\u{2550}"),
+    ("boxHD", "\u{2566}"),
+    ("boxHU", "\u{2569}"),
+    ("boxHd", "\u{2564}"),
+    ("boxHu", "\u{2567}"),
+    ("boxUL", "\u{255D}"),
+    ("boxUR", "\u{255A}"),
+    ("boxUl", "\u{255C}"),
+    ("boxUr", "\u{2559}"),
+    ("boxV", "\u{2551}"),
+    ("boxVH", "\u{256C}"),
+    ("boxVL", "\u{2563}"),
+    ("boxVR", "\u{2560}"),
+    ("boxVh", "\u{256B}"),
+    ("boxVl", "\u{2562}"),
+    ("boxVr", "\u{255F}"),
+    ("boxbox", "\u{29C9}"),
+    ("boxdL", "\u{2555}"),
+    ("boxdR", "\u{2552}"),
+    ("boxdl", "\u{2510}"),
+    ("boxdr", "\u{250C}"),
+    ("boxh", "\u{2500}"),
+    ("boxhD", "\u{2565}"),
+    ("boxhU", "\u{2568}"),
+    ("boxhd", "\u{252C}"),
+    ("boxhu", "\u{2534}"),
+    ("boxminus", "\u{229F}"),
+    ("boxplus", "\u{229E}"),
+    ("boxtimes", "\u{22A0}"),
+    ("boxuL", "\u{255B}"),
+    ("boxuR", "\u{2558}"),
+    ("boxul", "\u{2518}"),
+    ("boxur", "\u{2514}"),
+    ("boxv", "\u{2502}"),
+    ("boxvH", "\u{256A}"),
+    ("boxvL", "\u{2561}"),
+    ("boxvR", "\u{255E}"),
+    ("boxvh", "\u{253C}"),
+    ("boxvl", "\u{2524}"),
+    ("boxvr", "\u{251C}"),
+    ("bprime", "\u{2035}"),
+    ("breve", "\u{2D8}"),
+    ("brvbar", "\u{A6}"),
+    ("bscr", "\u{1D4B7}"),
+    ("bsemi", "\u{204F}"),
+    ("bsim", "\u{223D}"),
+    ("bsime", "\u{22CD}"),
+    ("bsol", "\u{5C}"),
+    ("bsolb", "\u{29C5}"),
+    ("bsolhsub", "\u{27C8}"),
+    ("bull", "\u{2022}"),
+    ("bullet", "\u{2022}"),
+    ("bump", "\u{224E}"),
+    ("bumpE", "\u{2AAE}"),
+    ("bumpe", "\u{224F}"),
+    ("bumpeq", "\u{224F}"),
+    ("cacute", "\u{107}"),
+    ("cap", "\u{2229}"),
+    ("capand", "\u{2A44}"),
+    ("capbrcup", "\u{2A49}"),
+    ("capcap", "\u{2A4B}"),
+    ("capcup", "\u{2A47}"),
+    ("capdot", "\u{2A40}"),
+    ("caps", "\u{2229}\u{FE00}"),
+    ("caret", "\u{2041}"),
+    ("caron", "\u{2C7}"),
+    ("ccaps", "\u{2A4D}"),
+    ("ccaron", "\u{10D}"),
+    ("ccedil", "\u{E7}"),
+    ("ccirc", "\u{109}"),
+    ("ccups", "\u{2A4C}"),
+    ("ccupssm", "\u{2A50}"),
+    ("cdot", "\u{10B}"),
+    ("cedil", "\u{B8}"),
+    ("cemptyv", "\u{29B2}"),
+    ("cent", "\u{A2}"),
+    ("centerdot", "\u{B7}"),
+    ("cfr", "\u{1D520}"),
+    ("chcy", "\u{447}"),
+    ("check", "\u{2713}"),
+    ("checkmark", "\u{2713}"),
+    ("chi", "\u{3C7}"),
+    ("cir", "\u{25CB}"),
+    ("cirE", "\u{29C3}"),
+    ("circ", "\u{2C6}"),
+    ("circeq", "\u{2257}"),
+    ("circlearrowleft", "\u{21BA}"),
+    ("circlearrowright", "\u{21BB}"),
+    ("circledR", "\u{AE}"),
+    ("circledS", "\u{24C8}"),
+    ("circledast", "\u{229B}"),
+    ("circledcirc", "\u{229A}"),
+    ("circleddash", "\u{229D}"),
+    ("cire", "\u{2257}"),
+    ("cirfnint", "\u{2A10}"),
+    ("cirmid", "\u{2AEF}"),
+    ("cirscir", "\u{29C2}"),
+    ("clubs", "\u{2663}"),
+    ("clubsuit", "\u{2663}"),
+    ("colon", ":"),
+    ("colone", "\u{2254}"),
+    ("coloneq", "\u{2254}"),
+    ("comma", ","),
+    ("commat", "@"),
+    ("comp", "\u{2201}"),
+    ("compfn", "\u{2218}"),
+    ("complement", "\u{2201}"),
+    ("complexes", "\u{2102}"),
+    ("cong", "\u{2245}"),
+    ("congdot", "\u{2A6D}"),
+    ("conint", "\u{222E}"),
+    ("copf", "\u{1D554}"),
+    ("coprod", "\u{2210}"),
+    ("copy", "\u{A9}"),
+    ("copysr", "\u{2117}"),
+    ("crarr", "\u{21B5}"),
+    ("cross", "\u{2717}"),
+    ("cscr", "\u{1D4B8}"),
+    ("csub", "\u{2ACF}"),
+    ("csube", "\u{2AD1}"),
+    ("csup", "\u{2AD0}"),
+    ("csupe", "\u{2AD2}"),
+    ("ctdot", "\u{22EF}"),
+    ("cudarrl", "\u{2938}"),
+    ("cudarrr", "\u{2935}"),
+    ("cuepr", "\u{22DE}"),
+    ("cuesc", "\u{22DF}"),
+    ("cularr", "\u{21B6}"),
+    ("cularrp", "\u{293D}"),
+    ("cup", "\u{222A}"),
+    ("cupbrcap", "\u{2A48}"),
+    ("cupcap", "\u{2A46}"),
+    ("cupcup", "\u{2A4A}"),
+    ("cupdot", "\u{228D}"),
+    ("cupor", "\u{2A45}"),
+    ("cups", "\u{222A}\u{FE00}"),
+    ("curarr", "\u{21B7}"),
+    ("curarrm", "\u{293C}"),
+    ("curlyeqprec", "\u{22DE}"),
+    ("curlyeqsucc", "\u{22DF}"),
+    ("curlyvee", "\u{22CE}"),
+    ("curlywedge", "\u{22CF}"),
+    ("curren", "\u{A4}"),
+    ("curvearrowleft", "\u{21B6}"),
+    ("curvearrowright", "\u{21B7}"),
+    ("cuvee", "\u{22CE}"),
+    ("cuwed", "\u{22CF}"),
+    ("cwconint", "\u{2232}"),
+    ("cwint", "\u{2231}"),
+    ("cylcty", "\u{232D}"),
+    ("dArr", "\u{21D3}"),
+    ("dHar", "\u{2965}"),
+    ("dagger", "\u{2020}"),
+    ("daleth", "\u{2138}"),
+    ("darr", "\u{2193}"),
+    ("dash", "\u{2010}"),
+    ("dashv", "\u{22A3}"),
+    ("dbkarow", "\u{290F}"),
+    ("dblac", "\u{2DD}"),
+    ("dcaron", "\u{10F}"),
+    ("dcy", "\u{434}"),
+    ("dd", "\u{2146}"),
+    ("ddagger", "\u{2021}"),
+    ("ddarr", "\u{21CA}"),
+    ("ddotseq", "\u{2A77}"),
+    ("deg", "\u{B0}"),
+    ("delta", "\u{3B4}"),
+    ("demptyv", "\u{29B1}"),
+    ("dfisht", "\u{297F}"),
+    ("dfr", "\u{1D521}"),
+    ("dharl", "\u{21C3}"),
+    ("dharr", "\u{21C2}"),
+    ("diam", "\u{22C4}"),
+    ("diamond", "\u{22C4}"),
+    ("diamondsuit", "\u{2666}"),
+    ("diams", "\u{2666}"),
+    ("die", "\u{A8}"),
+    ("digamma", "\u{3DD}"),
+    ("disin", "\u{22F2}"),
+    ("div", "\u{F7}"),
+    ("divide", "\u{F7}"),
+    ("divideontimes", "\u{22C7}"),
+    ("divonx", "\u{22C7}"),
+    ("djcy", "\u{452}"),
+    ("dlcorn", "\u{231E}"),
+    ("dlcrop", "\u{230D}"),
+    ("dollar", "$"),
+    ("dopf", "\u{1D555}"),
+    ("dot", "\u{2D9}"),
+    ("doteq", "\u{2250}"),
+    ("doteqdot", "\u{2251}"),
+    ("dotminus", "\u{2238}"),
+    ("dotplus", "\u{2214}"),
+    ("dotsquare", "\u{22A1}"),
+    ("doublebarwedge", "\u{2306}"),
+    ("downarrow", "\u{2193}"),
+    ("downdownarrows", "\u{21CA}"),
+    ("downharpoonleft", "\u{21C3}"),
+    ("downharpoonright", "\u{21C2}"),
+    ("drbkarow", "\u{2910}"),
+    ("drcorn", "\u{231F}"),
+    ("drcrop", "\u{230C}"),
+    ("dscr", "\u{1D4B9}"),
+    ("dscy", "\u{455}"),
+    ("dsol", "\u{29F6}"),
+    ("dstrok", "\u{111}"),
+    ("dtdot", "\u{22F1}"),
+    ("dtri", "\u{25BF}"),
+    ("dtrif", "\u{25BE}"),
+    ("duarr", "\u{21F5}"),
+    ("duhar", "\u{296F}"),
+    ("dwangle", "\u{29A6}"),
+    ("dzcy", "\u{45F}"),
+    ("dzigrarr", "\u{27FF}"),
+    ("eDDot", "\u{2A77}"),
+    ("eDot", "\u{2251}"),
+    ("eacute", "\u{E9}"),
+    ("easter", "\u{2A6E}"),
+    ("ecaron", "\u{11B}"),
+    ("ecir", "\u{2256}"),
+    ("ecirc", "\u{EA}"),
+    ("ecolon", "\u{2255}"),
+    ("ecy", "\u{44D}"),
+    ("edot", "\u{117}"),
+    ("ee", "\u{2147}"),
+    ("efDot", "\u{2252}"),
+    ("efr", "\u{1D522}"),
+    ("eg", "\u{2A9A}"),
+    ("egrave", "\u{E8}"),
+    ("egs", "\u{2A96}"),
+    ("egsdot", "\u{2A98}"),
+    ("el", "\u{2A99}"),
+    ("elinters", "\u{23E7}"),
+    ("ell", "\u{2113}"),
+    ("els", "\u{2A95}"),
+    ("elsdot", "\u{2A97}"),
+    ("emacr", "\u{113}"),
+    ("empty", "\u{2205}"),
+    ("emptyset", "\u{2205}"),
+    ("emptyv", "\u{2205}"),
+    ("emsp", "\u{2003}"),
+    ("emsp13", "\u{2004}"),
+    ("emsp14", "\u{2005}"),
+    ("eng", "\u{14B}"),
+    ("ensp", "\u{2002}"),
+    ("eogon", "\u{119}"),
+    ("eopf", "\u{1D556}"),
+    ("epar", "\u{22D5}"),
+    ("eparsl", "\u{29E3}"),
+    ("eplus", "\u{2A71}"),
+    ("epsi", "\u{3B5}"),
+    ("epsilon", "\u{3B5}"),
+    ("epsiv", "\u{3F5}"),
+    ("eqcirc", "\u{2256}"),
+    ("eqcolon", "\u{2255}"),
+    ("eqsim", "\u{2242}"),
+    ("eqslantgtr", "\u{2A96}"),
+    ("eqslantless", "\u{2A95}"),
+    ("equals", "="),
+    ("equest", "\u{225F}"),
+    ("equiv", "\u{2261}"),
+    ("equivDD", "\u{2A78}"),
+    ("eqvparsl", "\u{29E5}"),
+    ("erDot", "\u{2253}"),
+    ("erarr", "\u{2971}"),
+    ("escr", "\u{212F}"),
+    ("esdot", "\u{2250}"),
+    ("esim", "\u{2242}"),
+    ("eta", "\u{3B7}"),
+    ("eth", "\u{F0}"),
+    ("euml", "\u{EB}"),
+    ("euro", "\u{20AC}"),
+    ("excl", "!"),
+    ("exist", "\u{2203}"),
+    ("expectation", "\u{2130}"),
+    ("exponentiale", "\u{2147}"),
+    ("fallingdotseq", "\u{2252}"),
+    ("fcy", "\u{444}"),
+    ("female", "\u{2640}"),
+    ("ffilig", "\u{FB03}"),
+    ("fflig", "\u{FB00}"),
+    ("ffllig", "\u{FB04}"),
+    ("ffr", "\u{1D523}"),
+    ("filig", "\u{FB01}"),
+    ("fjlig", "fj"),
+    ("flat", "\u{266D}"),
+    ("fllig", "\u{FB02}"),
+    ("fltns", "\u{25B1}"),
+    ("fnof", "\u{192}"),
+    ("fopf", "\u{1D557}"),
+    ("forall", "\u{2200}"),
+    ("fork", "\u{22D4}"),
+    ("forkv", "\u{2AD9}"),
+    ("fpartint", "\u{2A0D}"),
+    ("frac12", "\u{BD}"),
+    ("frac13", "\u{2153}"),
+    ("frac14", "\u{BC}"),
+    ("frac15", "\u{2155}"),
+    ("frac16", "\u{2159}"),
+    ("frac18", "\u{215B}"),
+    ("frac23", "\u{2154}"),
+    ("frac25", "\u{2156}"),
+    ("frac34", "\u{BE}"),
+    ("frac35", "\u{2157}"),
+    ("frac38", "\u{215C}"),
+    ("frac45", "\u{2158}"),
+    ("frac56", "\u{215A}"),
+    ("frac58", "\u{215D}"),
+    ("frac78", "\u{215E}"),
+    ("frasl", "\u{2044}"),
+    ("frown", "\u{2322}"),
+    ("fscr", "\u{1D4BB}"),
+    ("gE", "\u{2267}"),
+    ("gEl", "\u{2A8C}"),
+    ("gacute", "\u{1F5}"),
+    ("gamma", "\u{3B3}"),
+    ("gammad", "\u{3DD}"),
+    ("gap", "\u{2A86}"),
+    ("gbreve", "\u{11F}"),
+    ("gcirc", "\u{11D}"),
+    ("gcy", "\u{433}"),
+    ("gdot", "\u{121}"),
+    ("ge", "\u{2265}"),
+    ("gel", "\u{22DB}"),
+    ("geq", "\u{2265}"),
+    ("geqq", "\u{2267}"),
+    ("geqslant", "\u{2A7E}"),
+    ("ges", "\u{2A7E}"),
+    ("gescc", "\u{2AA9}"),
+    ("gesdot", "\u{2A80}"),
+    ("gesdoto", "\u{2A82}"),
+    ("gesdotol", "\u{2A84}"),
+    ("gesl", "\u{22DB}\u{FE00}"),
+    ("gesles", "\u{2A94}"),
+    ("gfr", "\u{1D524}"),
+    ("gg", "\u{226B}"),
+    ("ggg", "\u{22D9}"),
+    ("gimel", "\u{2137}"),
+    ("gjcy", "\u{453}"),
+    ("gl", "\u{2277}"),
+    ("glE", "\u{2A92}"),
+    ("gla", "\u{2AA5}"),
+    ("glj", "\u{2AA4}"),
+    ("gnE", "\u{2269}"),
+    ("gnap", "\u{2A8A}"),
+    ("gnapprox", "\u{2A8A}"),
+    ("gne", "\u{2A88}"),
+    ("gneq", "\u{2A88}"),
+    ("gneqq", "\u{2269}"),
+    ("gnsim", "\u{22E7}"),
+    ("gopf", "\u{1D558}"),
+    ("grave", "`"),
+    ("gscr", "\u{210A}"),
+    ("gsim", "\u{2273}"),
+    ("gsime", "\u{2A8E}"),
+    ("gsiml", "\u{2A90}"),
+    ("gt", ">"),
+    ("gtcc", "\u{2AA7}"),
+    ("gtcir", "\u{2A7A}"),
+    ("gtdot", "\u{22D7}"),
+    ("gtlPar", "\u{2995}"),
+    ("gtquest", "\u{2A7C}"),
+    ("gtrapprox", "\u{2A86}"),
+    ("gtrarr", "\u{2978}"),
+    ("gtrdot", "\u{22D7}"),
+    ("gtreqless", "\u{22DB}"),
+    ("gtreqqless", "\u{2A8C}"),
+    ("gtrless", "\u{2277}"),
+    ("gtrsim", "\u{2273}"),
+    ("gvertneqq", "\u{2269}\u{FE00}"),
+    ("gvnE", "\u{2269}\u{FE00}"),
+    ("hArr", "\u{21D4}"),
+    ("hairsp", "\u{200A}"),
+    ("half", "\u{BD}"),
+    ("hamilt", "\u{210B}"),
+    ("hardcy", "\u{44A}"),
+    ("harr", "\u{2194}"),
+    ("harrcir", "\u{2948}"),
+    ("harrw", "\u{21AD}"),
+    ("hbar", "\u{210F}"),
+    ("hcirc", "\u{125}"),
+    ("hearts", "\u{2665}"),
+    ("heartsuit", "\u{2665}"),
+    ("hellip", "\u{2026}"),
+    ("hercon", "\u{22B9}"),
+    ("hfr", "\u{1D525}"),
+    ("hksearow", "\u{2925}"),
+    ("hkswarow", "\u{2926}"),
+    ("hoarr", "\u{21FF}"),
+    ("homtht", "\u{223B}"),
+    ("hookleftarrow", "\u{21A9}"),
+    ("hookrightarrow", "\u{21AA}"),
+    ("hopf", "\u{1D559}"),
+    ("horbar", "\u{2015}"),
+    ("hscr", "\u{1D4BD}"),
+    ("hslash", "\u{210F}"),
+    ("hstrok", "\u{127}"),
+    ("hybull", "\u{2043}"),
+    ("hyphen", "\u{2010}"),
+    ("iacute", "\u{ED}"),
+    ("ic", "\u{2063}"),
+    ("icirc", "\u{EE}"),
+    ("icy", "\u{438}"),
+    ("iecy", "\u{435}"),
+    ("iexcl", "\u{A1}"),
+    ("iff", "\u{21D4}"),
+    ("ifr", "\u{1D526}"),
+    ("igrave", "\u{EC}"),
+    ("ii", "\u{2148}"),
+    ("iiiint", "\u{2A0C}"),
+    ("iiint", "\u{222D}"),
+    ("iinfin", "\u{29DC}"),
+    ("iiota", "\u{2129}"),
+    ("ijlig", "\u{133}"),
+    ("imacr", "\u{12B}"),
+    ("image", "\u{2111}"),
+    ("imagline", "\u{2110}"),
+    ("imagpart", "\u{2111}"),
+    ("imath", "\u{131}"),
+    ("imof", "\u{22B7}"),
+    ("imped", "\u{1B5}"),
+    ("in", "\u{2208}"),
+    ("incare", "\u{2105}"),
+    ("infin", "\u{221E}"),
+    ("infintie", "\u{29DD}"),
+    ("inodot", "\u{131}"),
+    ("int", "\u{222B}"),
+    ("intcal", "\u{22BA}"),
+    ("integers", "\u{2124}"),
+    ("intercal", "\u{22BA}"),
+    ("intlarhk", "\u{2A17}"),
+    ("intprod", "\u{2A3C}"),
+    ("iocy", "\u{451}"),
+    ("iogon", "\u{12F}"),
+    ("iopf", "\u{1D55A}"),
+    ("iota", "\u{3B9}"),
+    ("iprod", "\u{2A3C}"),
+    ("iquest", "\u{BF}"),
+    ("iscr", "\u{1D4BE}"),
+    ("isin", "\u{2208}"),
+    ("isinE", "\u{22F9}"),
+    ("isindot", "\u{22F5}"),
+    ("isins", "\u{22F4}"),
+    ("isinsv", "\u{22F3}"),
+    ("isinv", "\u{2208}"),
+    ("it", "\u{2062}"),
+    ("itilde", "\u{129}"),
+    ("iukcy", "\u{456}"),
+    ("iuml", "\u{EF}"),
+    ("jcirc", "\u{135}"),
+    ("jcy", "\u{439}"),
+    ("jfr", "\u{1D527}"),
+    ("jmath", "\u{237}"),
+    ("jopf", "\u{1D55B}"),
+    ("jscr", "\u{1D4BF}"),
+    ("jsercy", "\u{458}"),
+    ("jukcy", "\u{454}"),
+    ("kappa", "\u{3BA}"),
+    ("kappav", "\u{3F0}"),
+    ("kcedil", "\u{137}"),
+    ("kcy", "\u{43A}"),
+    ("kfr", "\u{1D528}"),
+    ("kgreen", "\u{138}"),
+    ("khcy", "\u{445}"),
+    ("kjcy", "\u{45C}"),
+    ("kopf", "\u{1D55C}"),
+    ("kscr", "\u{1D4C0}"),
+    ("lAarr", "\u{21DA}"),
+    ("lArr", "\u{21D0}"),
+    ("lAtail", "\u{291B}"),
+    ("lBarr", "\u{290E}"),
+    ("lE", "\u{2266}"),
+    ("lEg", "\u{2A8B}"),
+    ("lHar", "\u{2962}"),
+    ("lacute", "\u{13A}"),
+    ("laemptyv", "\u{29B4}"),
+    ("lagran", "\u{2112}"),
+    ("lambda", "\u{3BB}"),
+    ("lang", "\u{27E8}"),
+    ("langd", "\u{2991}"),
+    ("langle", "\u{27E8}"),
+    ("lap", "\u{2A85}"),
+    ("laquo", "\u{AB}"),
+    ("larr", "\u{2190}"),
+    ("larrb", "\u{21E4}"),
+    ("larrbfs", "\u{291F}"),
+    ("larrfs", "\u{291D}"),
+    ("larrhk", "\u{21A9}"),
+    ("larrlp", "\u{21AB}"),
+    ("larrpl", "\u{2939}"),
+    ("larrsim", "\u{2973}"),
+    ("larrtl", "\u{21A2}"),
+    ("lat", "\u{2AAB}"),
+    ("latail", "\u{2919}"),
+    ("late", "\u{2AAD}"),
+    ("lates", "\u{2AAD}\u{FE00}"),
+    ("lbarr", "\u{290C}"),
+    ("lbbrk", "\u{2772}"),
+    ("lbrace", "{"),
+    ("lbrack", "["),
+    ("lbrke", "\u{298B}"),
+    ("lbrksld", "\u{298F}"),
+    ("lbrkslu", "\u{298D}"),
+    ("lcaron", "\u{13E}"),
+    ("lcedil", "\u{13C}"),
+    ("lceil", "\u{2308}"),
+    ("lcub", "{"),
+    ("lcy", "\u{43B}"),
+    ("ldca", "\u{2936}"),
+    ("ldquo", "\u{201C}"),
+    ("ldquor", "\u{201E}"),
+    ("ldrdhar", "\u{2967}"),
+    ("ldrushar", "\u{294B}"),
+    ("ldsh", "\u{21B2}"),
+    ("le", "\u{2264}"),
+    ("leftarrow", "\u{2190}"),
+    ("leftarrowtail", "\u{21A2}"),
+    ("leftharpoondown", "\u{21BD}"),
+    ("leftharpoonup", "\u{21BC}"),
+    ("leftleftarrows", "\u{21C7}"),
+    ("leftrightarrow", "\u{2194}"),
+    ("leftrightarrows", "\u{21C6}"),
+    ("leftrightharpoons", "\u{21CB}"),
+    ("leftrightsquigarrow", "\u{21AD}"),
+    ("leftthreetimes", "\u{22CB}"),
+    ("leg", "\u{22DA}"),
+    ("leq", "\u{2264}"),
+    ("leqq", "\u{2266}"),
+    ("leqslant", "\u{2A7D}"),
+    ("les", "\u{2A7D}"),
+    ("lescc", "\u{2AA8}"),
+    ("lesdot", "\u{2A7F}"),
+    ("lesdoto", "\u{2A81}"),
+    ("lesdotor", "\u{2A83}"),
+    ("lesg", "\u{22DA}\u{FE00}"),
+    ("lesges", "\u{2A93}"),
+    ("lessapprox", "\u{2A85}"),
+    ("lessdot", "\u{22D6}"),
+    ("lesseqgtr", "\u{22DA}"),
+    ("lesseqqgtr", "\u{2A8B}"),
+    ("lessgtr", "\u{2276}"),
+    ("lesssim", "\u{2272}"),
+    ("lfisht", "\u{297C}"),
+    ("lfloor", "\u{230A}"),
+    ("lfr", "\u{1D529}"),
+    ("lg", "\u{2276}"),
+    ("lgE", "\u{2A91}"),
+    ("lhard", "\u{21BD}"),
+    ("lharu", "\u{21BC}"),
+    ("lharul", "\u{296A}"),
+    ("lhblk", "\u{2584}"),
+    ("ljcy", "\u{459}"),
+    ("ll", "\u{226A}"),
+    ("llarr", "\u{21C7}"),
+    ("llcorner", "\u{231E}"),
+    ("llhard", "\u{296B}"),
+    ("lltri", "\u{25FA}"),
+    ("lmidot", "\u{140}"),
+    ("lmoust", "\u{23B0}"),
+    ("lmoustache", "\u{23B0}"),
+    ("lnE", "\u{2268}"),
+    ("lnap", "\u{2A89}"),
+    ("lnapprox", "\u{2A89}"),
+    ("lne", "\u{2A87}"),
+    ("lneq", "\u{2A87}"),
+    ("lneqq", "\u{2268}"),
+    ("lnsim", "\u{22E6}"),
+    ("loang", "\u{27EC}"),
+    ("loarr", "\u{21FD}"),
+    ("lobrk", "\u{27E6}"),
+    ("longleftarrow", "\u{27F5}"),
+    ("longleftrightarrow", "\u{27F7}"),
+    ("longmapsto", "\u{27FC}"),
+    ("longrightarrow", "\u{27F6}"),
+    ("looparrowleft", "\u{21AB}"),
+    ("looparrowright", "\u{21AC}"),
+    ("lopar", "\u{2985}"),
+    ("lopf", "\u{1D55D}"),
+    ("loplus", "\u{2A2D}"),
+    ("lotimes", "\u{2A34}"),
+    ("lowast", "\u{2217}"),
+    ("lowbar", "_"),
+    ("loz", "\u{25CA}"),
+    ("lozenge", "\u{25CA}"),
+    ("lozf", "\u{29EB}"),
+    ("lpar", "("),
+    ("lparlt", "\u{2993}"),
+    ("lrarr", "\u{21C6}"),
+    ("lrcorner", "\u{231F}"),
+    ("lrhar", "\u{21CB}"),
+    ("lrhard", "\u{296D}"),
+    ("lrm", "\u{200E}"),
+    ("lrtri", "\u{22BF}"),
+    ("lsaquo", "\u{2039}"),
+    ("lscr", "\u{1D4C1}"),
+    ("lsh", "\u{21B0}"),
+    ("lsim", "\u{2272}"),
+    ("lsime", "\u{2A8D}"),
+    ("lsimg", "\u{2A8F}"),
+    ("lsqb", "["),
+    ("lsquo", "\u{2018}"),
+    ("lsquor", "\u{201A}"),
+    ("lstrok", "\u{142}"),
+    ("lt", "<"),
+    ("ltcc", "\u{2AA6}"),
+    ("ltcir", "\u{2A79}"),
+    ("ltdot", "\u{22D6}"),
+    ("lthree", "\u{22CB}"),
+    ("ltimes", "\u{22C9}"),
+    ("ltlarr", "\u{2976}"),
+    ("ltquest", "\u{2A7B}"),
+    ("ltrPar", "\u{2996}"),
+    ("ltri", "\u{25C3}"),
+    ("ltrie", "\u{22B4}"),
+    ("ltrif", "\u{25C2}"),
+    ("lurdshar", "\u{294A}"),
+    ("luruhar", "\u{2966}"),
+    ("lvertneqq", "\u{2268}\u{FE00}"),
+    ("lvnE", "\u{2268}\u{FE00}"),
+    ("mDDot", "\u{223A}"),
+    ("macr", "\u{AF}"),
+    ("male", "\u{2642}"),
+    ("malt", "\u{2720}"),
+    ("maltese", "\u{2720}"),
+    ("map", "\u{21A6}"),
+    ("mapsto", "\u{21A6}"),
+    ("mapstodown", "\u{21A7}"),
+    ("mapstoleft", "\u{21A4}"),
+    ("mapstoup", "\u{21A5}"),
+    ("marker", "\u{25AE}"),
+    ("mcomma", "\u{2A29}"),
+    ("mcy", "\u{43C}"),
+    ("mdash", "\u{2014}"),
+    ("measuredangle", "\u{2221}"),
+    ("mfr", "\u{1D52A}"),
+    ("mho", "\u{2127}"),
+    ("micro", "\u{B5}"),
+    ("mid", "\u{2223}"),
+    ("midast", "*"),
+    ("midcir", "\u{2AF0}"),
+    ("middot", "\u{B7}"),
+    ("minus", "\u{2212}"),
+    ("minusb", "\u{229F}"),
+    ("minusd", "\u{2238}"),
+    ("minusdu", "\u{2A2A}"),
+    ("mlcp", "\u{2ADB}"),
+    ("mldr", "\u{2026}"),
+    ("mnplus", "\u{2213}"),
+    ("models", "\u{22A7}"),
+    ("mopf", "\u{1D55E}"),
+    ("mp", "\u{2213}"),
+    ("mscr", "\u{1D4C2}"),
+    ("mstpos", "\u{223E}"),
+    ("mu", "\u{3BC}"),
+    ("multimap", "\u{22B8}"),
+    ("mumap", "\u{22B8}"),
+    ("nGg", "\u{22D9}\u{338}"),
+    ("nGt", "\u{226B}\u{20D2}"),
+    ("nGtv", "\u{226B}\u{338}"),
+    ("nLeftarrow", "\u{21CD}"),
+    ("nLeftrightarrow", "\u{21CE}"),
+    ("nLl", "\u{22D8}\u{338}"),
+    ("nLt", "\u{226A}\u{20D2}"),
+    ("nLtv", "\u{226A}\u{338}"),
+    ("nRightarrow", "\u{21CF}"),
+    ("nVDash", "\u{22AF}"),
+    ("nVdash", "\u{22AE}"),
+    ("nabla", "\u{2207}"),
+    ("nacute", "\u{144}"),
+    ("nang", "\u{2220}\u{20D2}"),
+    ("nap", "\u{2249}"),
+    ("napE", "\u{2A70}\u{338}"),
+    ("napid", "\u{224B}\u{338}"),
+    ("napos", "\u{149}"),
+    ("napprox", "\u{2249}"),
+    ("natur", "\u{266E}"),
+    ("natural", "\u{266E}"),
+    ("naturals", "\u{2115}"),
+    ("nbsp", "\u{A0}"),
+    ("nbump", "\u{224E}\u{338}"),
+    ("nbumpe", "\u{224F}\u{338}"),
+    ("ncap", "\u{2A43}"),
+    ("ncaron", "\u{148}"),
+    ("ncedil", "\u{146}"),
+    ("ncong", "\u{2247}"),
+    ("ncongdot", "\u{2A6D}\u{338}"),
+    ("ncup", "\u{2A42}"),
+    ("ncy", "\u{43D}"),
+    ("ndash", "\u{2013}"),
+    ("ne", "\u{2260}"),
+    ("neArr", "\u{21D7}"),
+    ("nearhk", "\u{2924}"),
+    ("nearr", "\u{2197}"),
+    ("nearrow", "\u{2197}"),
+    ("nedot", "\u{2250}\u{338}"),
+    ("nequiv", "\u{2262}"),
+    ("nesear", "\u{2928}"),
+    ("nesim", "\u{2242}\u{338}"),
+    ("nexist", "\u{2204}"),
+    ("nexists", "\u{2204}"),
+    ("nfr", "\u{1D52B}"),
+    ("ngE", "\u{2267}\u{338}"),
+    ("nge", "\u{2271}"),
+    ("ngeq", "\u{2271}"),
+    ("ngeqq", "\u{2267}\u{338}"),
+    ("ngeqslant", "\u{2A7E}\u{338}"),
+    ("nges", "\u{2A7E}\u{338}"),
+    ("ngsim", "\u{2275}"),
+    ("ngt", "\u{226F}"),
+    ("ngtr", "\u{226F}"),
+    ("nhArr", "\u{21CE}"),
+    ("nharr", "\u{21AE}"),
+    ("nhpar", "\u{2AF2}"),
+    ("ni", "\u{220B}"),
+    ("nis", "\u{22FC}"),
+    ("nisd", "\u{22FA}"),
+    ("niv", "\u{220B}"),
+    ("njcy", "\u{45A}"),
+    ("nlArr", "\u{21CD}"),
+    ("nlE", "\u{2266}\u{338}"),
+    ("nlarr", "\u{219A}"),
+    ("nldr", "\u{2025}"),
+    ("nle", "\u{2270}"),
+    ("nleftarrow", "\u{219A}"),
+    ("nleftrightarrow", "\u{21AE}"),
+    ("nleq", "\u{2270}"),
+    ("nleqq", "\u{2266}\u{338}"),
+    ("nleqslant", "\u{2A7D}\u{338}"),
+    ("nles", "\u{2A7D}\u{338}"),
+    ("nless", "\u{226E}"),
+    ("nlsim", "\u{2274}"),
+    ("nlt", "\u{226E}"),
+    ("nltri", "\u{22EA}"),
+    ("nltrie", "\u{22EC}"),
+    ("nmid", "\u{2224}"),
+    ("nopf", "\u{1D55F}"),
+    ("not", "\u{AC}"),
+    ("notin", "\u{2209}"),
+    ("notinE", "\u{22F9}\u{338}"),
+    ("notindot", "\u{22F5}\u{338}"),
+    ("notinva", "\u{2209}"),
+    ("notinvb", "\u{22F7}"),
+    ("notinvc", "\u{22F6}"),
+    ("notni", "\u{220C}"),
+    ("notniva", "\u{220C}"),
+    ("notnivb", "\u{22FE}"),
+    ("notnivc", "\u{22FD}"),
+    ("npar", "\u{2226}"),
+    ("nparallel", "\u{2226}"),
+    ("nparsl", "\u{2AFD}\u{20E5}"),
+    ("npart", "\u{2202}\u{338}"),
+    ("npolint", "\u{2A14}"),
+    ("npr", "\u{2280}"),
+    ("nprcue", "\u{22E0}"),
+    ("npre", "\u{2AAF}\u{338}"),
+    ("nprec", "\u{2280}"),
+    ("npreceq", "\u{2AAF}\u{338}"),
+    ("nrArr", "\u{21CF}"),
+    ("nrarr", "\u{219B}"),
+    ("nrarrc", "\u{2933}\u{338}"),
+    ("nrarrw", "\u{219D}\u{338}"),
+    ("nrightarrow", "\u{219B}"),
+    ("nrtri", "\u{22EB}"),
+    ("nrtrie", "\u{22ED}"),
+    ("nsc", "\u{2281}"),
+    ("nsccue", "\u{22E1}"),
+    ("nsce", "\u{2AB0}\u{338}"),
+    ("nscr", "\u{1D4C3}"),
+    ("nshortmid", "\u{2224}"),
+    ("nshortparallel", "\u{2226}"),
+    ("nsim", "\u{2241}"),
+    ("nsime", "\u{2244}"),
+    ("nsimeq", "\u{2244}"),
+    ("nsmid", "\u{2224}"),
+    ("nspar", "\u{2226}"),
+    ("nsqsube", "\u{22E2}"),
+    ("nsqsupe", "\u{22E3}"),
+    ("nsub", "\u{2284}"),
+    ("nsubE", "\u{2AC5}\u{338}"),
+    ("nsube", "\u{2288}"),
+    ("nsubset", "\u{2282}\u{20D2}"),
+    ("nsubseteq", "\u{2288}"),
+    ("nsubseteqq", "\u{2AC5}\u{338}"),
+    ("nsucc", "\u{2281}"),
+    ("nsucceq", "\u{2AB0}\u{338}"),
+    ("nsup", "\u{2285}"),
+    ("nsupE", "\u{2AC6}\u{338}"),
+    ("nsupe", "\u{2289}"),
+    ("nsupset", "\u{2283}\u{20D2}"),
+    ("nsupseteq", "\u{2289}"),
+    ("nsupseteqq", "\u{2AC6}\u{338}"),
+    ("ntgl", "\u{2279}"),
+    ("ntilde", "\u{F1}"),
+    ("ntlg", "\u{2278}"),
+    ("ntriangleleft", "\u{22EA}"),
+    ("ntrianglelefteq", "\u{22EC}"),
+    ("ntriangleright", "\u{22EB}"),
+    ("ntrianglerighteq", "\u{22ED}"),
+    ("nu", "\u{3BD}"),
+    ("num", "#"),
+    ("numero", "\u{2116}"),
+    ("numsp", "\u{2007}"),
+    ("nvDash", "\u{22AD}"),
+    ("nvHarr", "\u{2904}"),
+    ("nvap", "\u{224D}\u{20D2}"),
+    ("nvdash", "\u{22AC}"),
+    ("nvge", "\u{2265}\u{20D2}"),
+    ("nvgt", ">\u{20D2}"),
+    ("nvinfin", "\u{29DE}"),
+    ("nvlArr", "\u{2902}"),
+    ("nvle", "\u{2264}\u{20D2}"),
+    ("nvlt", "<\u{20D2}"),
+    ("nvltrie", "\u{22B4}\u{20D2}"),
+    ("nvrArr", "\u{2903}"),
+    ("nvrtrie", "\u{22B5}\u{20D2}"),
+    ("nvsim", "\u{223C}\u{20D2}"),
+    ("nwArr", "\u{21D6}"),
+    ("nwarhk", "\u{2923}"),
+    ("nwarr", "\u{2196}"),
+    ("nwarrow", "\u{2196}"),
+    ("nwnear", "\u{2927}"),
+    ("oS", "\u{24C8}"),
+    ("oacute", "\u{F3}"),
+    ("oast", "\u{229B}"),
+    ("ocir", "\u{229A}"),
+    ("ocirc", "\u{F4}"),
+    ("ocy", "\u{43E}"),
+    ("odash", "\u{229D}"),
+    ("odblac", "\u{151}"),
+    ("odiv", "\u{2A38}"),
+    ("odot", "\u{2299}"),
+    ("odsold", "\u{29BC}"),
+    ("oelig", "\u{153}"),
+    ("ofcir", "\u{29BF}"),
+    ("ofr", "\u{1D52C}"),
+    ("ogon", "\u{2DB}"),
+    ("ograve", "\u{F2}"),
+    ("ogt", "\u{29C1}"),
+    ("ohbar", "\u{29B5}"),
+    ("ohm", "\u{3A9}"),
+    ("oint", "\u{222E}"),
+    ("olarr", "\u{21BA}"),
+    ("olcir", "\u{29BE}"),
+    ("olcross", "\u{29BB}"),
+    ("oline", "\u{203E}"),
+    ("olt", "\u{29C0}"),
+    ("omacr", "\u{14D}"),
+    ("omega", "\u{3C9}"),
+    ("omicron", "\u{3BF}"),
+    ("omid", "\u{29B6}"),
+    ("ominus", "\u{2296}"),
+    ("oopf", "\u{1D560}"),
+    ("opar", "\u{29B7}"),
+    ("operp", "\u{29B9}"),
+    ("oplus", "\u{2295}"),
+    ("or", "\u{2228}"),
+    ("orarr", "\u{21BB}"),
+    ("ord", "\u{2A5D}"),
+    ("order", "\u{2134}"),
+    ("orderof", "\u{2134}"),
+    ("ordf", "\u{AA}"),
+    ("ordm", "\u{BA}"),
+    ("origof", "\u{22B6}"),
+    ("oror", "\u{2A56}"),
+    ("orslope", "\u{2A57}"),
+    ("orv", "\u{2A5B}"),
+    ("oscr", "\u{2134}"),
+    ("oslash", "\u{F8}"),
+    ("osol", "\u{2298}"),
+    ("otilde", "\u{F5}"),
+    ("otimes", "\u{2297}"),
+    ("otimesas", "\u{2A36}"),
+    ("ouml", "\u{F6}"),
+    ("ovbar", "\u{233D}"),
+    ("par", "\u{2225}"),
+    ("para", "\u{B6}"),
+    ("parallel", "\u{2225}"),
+    ("parsim", "\u{2AF3}"),
+    ("parsl", "\u{2AFD}"),
+    ("part", "\u{2202}"),
+    ("pcy", "\u{43F}"),
+    ("percnt", "%"),
+    ("period", "."),
+    ("permil", "\u{2030}"),
+    ("perp", "\u{22A5}"),
+    ("pertenk", "\u{2031}"),
+    ("pfr", "\u{1D52D}"),
+    ("phi", "\u{3C6}"),
+    ("phiv", "\u{3D5}"),
+    ("phmmat", "\u{2133}"),
+    ("phone", "\u{260E}"),
+    ("pi", "\u{3C0}"),
+    ("pitchfork", "\u{22D4}"),
+    ("piv", "\u{3D6}"),
+    ("planck", "\u{210F}"),
+    ("planckh", "\u{210E}"),
+    ("plankv", "\u{210F}"),
+    ("plus", "+"),
+    ("plusacir", "\u{2A23}"),
+    ("plusb", "\u{229E}"),
+    ("pluscir", "\u{2A22}"),
+    ("plusdo", "\u{2214}"),
+    ("plusdu", "\u{2A25}"),
+    ("pluse", "\u{2A72}"),
+    ("plusmn", "\u{B1}"),
+    ("plussim", "\u{2A26}"),
+    ("plustwo", "\u{2A27}"),
+    ("pm", "\u{B1}"),
+    ("pointint", "\u{2A15}"),
+    ("popf", "\u{1D561}"),
+    ("pound", "\u{A3}"),
+    ("pr", "\u{227A}"),
+    ("prE", "\u{2AB3}"),
+    ("prap", "\u{2AB7}"),
+    ("prcue", "\u{227C}"),
+    ("pre", "\u{2AAF}"),
+    ("prec", "\u{227A}"),
+    ("precapprox", "\u{2AB7}"),
+    ("preccurlyeq", "\u{227C}"),
+    ("preceq", "\u{2AAF}"),
+    ("precnapprox", "\u{2AB9}"),
+    ("precneqq", "\u{2AB5}"),
+    ("precnsim", "\u{22E8}"),
+    ("precsim", "\u{227E}"),
+    ("prime", "\u{2032}"),
+    ("primes", "\u{2119}"),
+    ("prnE", "\u{2AB5}"),
+    ("prnap", "\u{2AB9}"),
+    ("prnsim", "\u{22E8}"),
+    ("prod", "\u{220F}"),
+    ("profalar", "\u{232E}"),
+    ("profline", "\u{2312}"),
+    ("profsurf", "\u{2313}"),
+    ("prop", "\u{221D}"),
+    ("propto", "\u{221D}"),
+    ("prsim", "\u{227E}"),
+    ("prurel", "\u{22B0}"),
+    ("pscr", "\u{1D4C5}"),
+    ("psi", "\u{3C8}"),
+    ("puncsp", "\u{2008}"),
+    ("qfr", "\u{1D52E}"),
+    ("qint", "\u{2A0C}"),
+    ("qopf", "\u{1D562}"),
+    ("qprime", "\u{2057}"),
+    ("qscr", "\u{1D4C6}"),
+    ("quaternions", "\u{210D}"),
+    ("quatint", "\u{2A16}"),
+    ("quest", "?"),
+    ("questeq", "\u{225F}"),
+    ("quot", "\u{22}"),
+    ("rAarr", "\u{21DB}"),
+    ("rArr", "\u{21D2}"),
+    ("rAtail", "\u{291C}"),
+    ("rBarr", "\u{290F}"),
+    ("rHar", "\u{2964}"),
+    ("race", "\u{223D}\u{331}"),
+    ("racute", "\u{155}"),
+    ("radic", "\u{221A}"),
+    ("raemptyv", "\u{29B3}"),
+    ("rang", "\u{27E9}"),
+    ("rangd", "\u{2992}"),
+    ("range", "\u{29A5}"),
+    ("rangle", "\u{27E9}"),
+    ("raquo", "\u{BB}"),
+    ("rarr", "\u{2192}"),
+    ("rarrap", "\u{2975}"),
+    ("rarrb", "\u{21E5}"),
+    ("rarrbfs", "\u{2920}"),
+    ("rarrc", "\u{2933}"),
+    ("rarrfs", "\u{291E}"),
+    ("rarrhk", "\u{21AA}"),
+    ("rarrlp", "\u{21AC}"),
+    ("rarrpl", "\u{2945}"),
+    ("rarrsim", "\u{2974}"),
+    ("rarrtl", "\u{21A3}"),
+    ("rarrw", "\u{219D}"),
+    ("ratail", "\u{291A}"),
+    ("ratio", "\u{2236}"),
+    ("rationals", "\u{211A}"),
+    ("rbarr", "\u{290D}"),
+    ("rbbrk", "\u{2773}"),
+    ("rbrace", "}"),
+    ("rbrack", "]"),
+    ("rbrke", "\u{298C}"),
+    ("rbrksld", "\u{298E}"),
+    ("rbrkslu", "\u{2990}"),
+    ("rcaron", "\u{159}"),
+    ("rcedil", "\u{157}"),
+    ("rceil", "\u{2309}"),
+    ("rcub", "}"),
+    ("rcy", "\u{440}"),
+    ("rdca", "\u{2937}"),
+    ("rdldhar", "\u{2969}"),
+    ("rdquo", "\u{201D}"),
+    ("rdquor", "\u{201D}"),
+    ("rdsh", "\u{21B3}"),
+    ("real", "\u{211C}"),
+    ("realine", "\u{211B}"),
+    ("realpart", "\u{211C}"),
+    ("reals", "\u{211D}"),
+    ("rect", "\u{25AD}"),
+    ("reg", "\u{AE}"),
+    ("rfisht", "\u{297D}"),
+    ("rfloor", "\u{230B}"),
+    ("rfr", "\u{1D52F}"),
+    ("rhard", "\u{21C1}"),
+    ("rharu", "\u{21C0}"),
+    ("rharul", "\u{296C}"),
+    ("rho", "\u{3C1}"),
+    ("rhov", "\u{3F1}"),
+    ("rightarrow", "\u{2192}"),
+    ("rightarrowtail", "\u{21A3}"),
+    ("rightharpoondown", "\u{21C1}"),
+    ("rightharpoonup", "\u{21C0}"),
+    ("rightleftarrows", "\u{21C4}"),
+    ("rightleftharpoons", "\u{21CC}"),
+    ("rightrightarrows", "\u{21C9}"),
+    ("rightsquigarrow", "\u{219D}"),
+    ("rightthreetimes", "\u{22CC}"),
+    ("ring", "\u{2DA}"),
+    ("risingdotseq", "\u{2253}"),
+    ("rlarr", "\u{21C4}"),
+    ("rlhar", "\u{21CC}"),
+    ("rlm", "\u{200F}"),
+    ("rmoust", "\u{23B1}"),
+    ("rmoustache", "\u{23B1}"),
+    ("rnmid", "\u{2AEE}"),
+    ("roang", "\u{27ED}"),
+    ("roarr", "\u{21FE}"),
+    ("robrk", "\u{27E7}"),
+    ("ropar", "\u{2986}"),
+    ("ropf", "\u{1D563}"),
+    ("roplus", "\u{2A2E}"),
+    ("rotimes", "\u{2A35}"),
+    ("rpar", ")"),
+    ("rpargt", "\u{2994}"),
+    ("rppolint", "\u{2A12}"),
+    ("rrarr", "\u{21C9}"),
+    ("rsaquo", "\u{203A}"),
+    ("rscr", "\u{1D4C7}"),
+    ("rsh", "\u{21B1}"),
+    ("rsqb", "]"),
+    ("rsquo", "\u{2019}"),
+    ("rsquor", "\u{2019}"),
+    ("rthree", "\u{22CC}"),
+    ("rtimes", "\u{22CA}"),
+    ("rtri", "\u{25B9}"),
+    ("rtrie", "\u{22B5}"),
+    ("rtrif", "\u{25B8}"),
+    ("rtriltri", "\u{29CE}"),
+    ("ruluhar", "\u{2968}"),
+    ("rx", "\u{211E}"),
+    ("sacute", "\u{15B}"),
+    ("sbquo", "\u{201A}"),
+    ("sc", "\u{227B}"),
+    ("scE", "\u{2AB4}"),
+    ("scap", "\u{2AB8}"),
+    ("scaron", "\u{161}"),
+    ("sccue", "\u{227D}"),
+    ("sce", "\u{2AB0}"),
+    ("scedil", "\u{15F}"),
+    ("scirc", "\u{15D}"),
+    ("scnE", "\u{2AB6}"),
+    ("scnap", "\u{2ABA}"),
+    ("scnsim", "\u{22E9}"),
+    ("scpolint", "\u{2A13}"),
+    ("scsim", "\u{227F}"),
+    ("scy", "\u{441}"),
+    ("sdot", "\u{22C5}"),
+    ("sdotb", "\u{22A1}"),
+    ("sdote", "\u{2A66}"),
+    ("seArr", "\u{21D8}"),
+    ("searhk", "\u{2925}"),
+    ("searr", "\u{2198}"),
+    ("searrow", "\u{2198}"),
+    ("sect", "\u{A7}"),
+    ("semi", ";"),
+    ("seswar", "\u{2929}"),
+    ("setminus", "\u{2216}"),
+    ("setmn", "\u{2216}"),
+    ("sext", "\u{2736}"),
+    ("sfr", "\u{1D530}"),
+    ("sfrown", "\u{2322}"),
+    ("sharp", "\u{266F}"),
+    ("shchcy", "\u{449}"),
+    ("shcy", "\u{448}"),
+    ("shortmid", "\u{2223}"),
+    ("shortparallel", "\u{2225}"),
+    ("shy", "\u{AD}"),
+    ("sigma", "\u{3C3}"),
+    ("sigmaf", "\u{3C2}"),
+    ("sigmav", "\u{3C2}"),
+    ("sim", "\u{223C}"),
+    ("simdot", "\u{2A6A}"),
+    ("sime", "\u{2243}"),
+    ("simeq", "\u{2243}"),
+    ("simg", "\u{2A9E}"),
+    ("simgE", "\u{2AA0}"),
+    ("siml", "\u{2A9D}"),
+    ("simlE", "\u{2A9F}"),
+    ("simne", "\u{2246}"),
+    ("simplus", "\u{2A24}"),
+    ("simrarr", "\u{2972}"),
+    ("slarr", "\u{2190}"),
+    ("smallsetminus", "\u{2216}"),
+    ("smashp", "\u{2A33}"),
+    ("smeparsl", "\u{29E4}"),
+    ("smid", "\u{2223}"),
+    ("smile", "\u{2323}"),
+    ("smt", "\u{2AAA}"),
+    ("smte", "\u{2AAC}"),
+    ("smtes", "\u{2AAC}\u{FE00}"),
+    ("softcy", "\u{44C}"),
+    ("sol", "/"),
+    ("solb", "\u{29C4}"),
+    ("solbar", "\u{233F}"),
+    ("sopf", "\u{1D564}"),
+    ("spades", "\u{2660}"),
+    ("spadesuit", "\u{2660}"),
+    ("spar", "\u{2225}"),
+    ("sqcap", "\u{2293}"),
+    ("sqcaps", "\u{2293}\u{FE00}"),
+    ("sqcup", "\u{2294}"),
+    ("sqcups", "\u{2294}\u{FE00}"),
+    ("sqsub", "\u{228F}"),
+    ("sqsube", "\u{2291}"),
+    ("sqsubset", "\u{228F}"),
+    ("sqsubseteq", "\u{2291}"),
+    ("sqsup", "\u{2290}"),
+    ("sqsupe", "\u{2292}"),
+    ("sqsupset", "\u{2290}"),
+    ("sqsupseteq", "\u{2292}"),
+    ("squ", "\u{25A1}"),
+    ("square", "\u{25A1}"),
+    ("squarf", "\u{25AA}"),
+    ("squf", "\u{25AA}"),
+    ("srarr", "\u{2192}"),
+    ("sscr", "\u{1D4C8}"),
+    ("ssetmn", "\u{2216}"),
+    ("ssmile", "\u{2323}"),
+    ("sstarf", "\u{22C6}"),
+    ("star", "\u{2606}"),
+    ("starf", "\u{2605}"),
+    ("straightepsilon", "\u{3F5}"),
+    ("straightphi", "\u{3D5}"),
+    ("strns", "\u{AF}"),
+    ("sub", "\u{2282}"),
+    ("subE", "\u{2AC5}"),
+    ("subdot", "\u{2ABD}"),
+    ("sube", "\u{2286}"),
+    ("subedot", "\u{2AC3}"),
+    ("submult", "\u{2AC1}"),
+    ("subnE", "\u{2ACB}"),
+    ("subne", "\u{228A}"),
+    ("subplus", "\u{2ABF}"),
+    ("subrarr", "\u{2979}"),
+    ("subset", "\u{2282}"),
+    ("subseteq", "\u{2286}"),
+    ("subseteqq", "\u{2AC5}"),
+    ("subsetneq", "\u{228A}"),
+    ("subsetneqq", "\u{2ACB}"),
+    ("subsim", "\u{2AC7}"),
+    ("subsub", "\u{2AD5}"),
+    ("subsup", "\u{2AD3}"),
+    ("succ", "\u{227B}"),
+    ("succapprox", "\u{2AB8}"),
+    ("succcurlyeq", "\u{227D}"),
+    ("succeq", "\u{2AB0}"),
+    ("succnapprox", "\u{2ABA}"),
+    ("succneqq", "\u{2AB6}"),
+    ("succnsim", "\u{22E9}"),
+    ("succsim", "\u{227F}"),
+    ("sum", "\u{2211}"),
+    ("sung", "\u{266A}"),
+    ("sup", "\u{2283}"),
+    ("sup1", "\u{B9}"),
+    ("sup2", "\u{B2}"),
+    ("sup3", "\u{B3}"),
+    ("supE", "\u{2AC6}"),
+    ("supdot", "\u{2ABE}"),
+    ("supdsub", "\u{2AD8}"),
+    ("supe", "\u{2287}"),
+    ("supedot", "\u{2AC4}"),
+    ("suphsol", "\u{27C9}"),
+    ("suphsub", "\u{2AD7}"),
+    ("suplarr", "\u{297B}"),
+    ("supmult", "\u{2AC2}"),
+    ("supnE", "\u{2ACC}"),
+    ("supne", "\u{228B}"),
+    ("supplus", "\u{2AC0}"),
+    ("supset", "\u{2283}"),
+    ("supseteq", "\u{2287}"),
+    ("supseteqq", "\u{2AC6}"),
+    ("supsetneq", "\u{228B}"),
+    ("supsetneqq", "\u{2ACC}"),
+    ("supsim", "\u{2AC8}"),
+    ("supsub", "\u{2AD4}"),
+    ("supsup", "\u{2AD6}"),
+    ("swArr", "\u{21D9}"),
+    ("swarhk", "\u{2926}"),
+    ("swarr", "\u{2199}"),
+    ("swarrow", "\u{2199}"),
+    ("swnwar", "\u{292A}"),
+    ("szlig", "\u{DF}"),
+    ("target", "\u{2316}"),
+    ("tau", "\u{3C4}"),
+    ("tbrk", "\u{23B4}"),
+    ("tcaron", "\u{165}"),
+    ("tcedil", "\u{163}"),
+    ("tcy", "\u{442}"),
+    ("tdot", "\u{20DB}"),
+    ("telrec", "\u{2315}"),
+    ("tfr", "\u{1D531}"),
+    ("there4", "\u{2234}"),
+    ("therefore", "\u{2234}"),
+    ("theta", "\u{3B8}"),
+    ("thetasym", "\u{3D1}"),
+    ("thetav", "\u{3D1}"),
+    ("thickapprox", "\u{2248}"),
+    ("thicksim", "\u{223C}"),
+    ("thinsp", "\u{2009}"),
+    ("thkap", "\u{2248}"),
+    ("thksim", "\u{223C}"),
+    ("thorn", "\u{FE}"),
+    ("tilde", "\u{2DC}"),
+    ("times", "\u{D7}"),
+    ("timesb", "\u{22A0}"),
+    ("timesbar", "\u{2A31}"),
+    ("timesd", "\u{2A30}"),
+    ("tint", "\u{222D}"),
+    ("toea", "\u{2928}"),
+    ("top", "\u{22A4}"),
+    ("topbot", "\u{2336}"),
+    ("topcir", "\u{2AF1}"),
+    ("topf", "\u{1D565}"),
+    ("topfork", "\u{2ADA}"),
+    ("tosa", "\u{2929}"),
+    ("tprime", "\u{2034}"),
+    ("trade", "\u{2122}"),
+    ("triangle", "\u{25B5}"),
+    ("triangledown", "\u{25BF}"),
+    ("triangleleft", "\u{25C3}"),
+    ("trianglelefteq", "\u{22B4}"),
+    ("triangleq", "\u{225C}"),
+    ("triangleright", "\u{25B9}"),
+    ("trianglerighteq", "\u{22B5}"),
+    ("tridot", "\u{25EC}"),
+    ("trie", "\u{225C}"),
+    ("triminus", "\u{2A3A}"),
+    ("triplus", "\u{2A39}"),
+    ("trisb", "\u{29CD}"),
+    ("tritime", "\u{2A3B}"),
+    ("trpezium", "\u{23E2}"),
+    ("tscr", "\u{1D4C9}"),
+    ("tscy", "\u{446}"),
+    ("tshcy", "\u{45B}"),
+    ("tstrok", "\u{167}"),
+    ("twixt", "\u{226C}"),
+    ("twoheadleftarrow", "\u{219E}"),
+    ("twoheadrightarrow", "\u{21A0}"),
+    ("uArr", "\u{21D1}"),
+    ("uHar", "\u{2963}"),
+    ("uacute", "\u{FA}"),
+    ("uarr", "\u{2191}"),
+    ("ubrcy", "\u{45E}"),
+    ("ubreve", "\u{16D}"),
+    ("ucirc", "\u{FB}"),
+    ("ucy", "\u{443}"),
+    ("udarr", "\u{21C5}"),
+    ("udblac", "\u{171}"),
+    ("udhar", "\u{296E}"),
+    ("ufisht", "\u{297E}"),
+    ("ufr", "\u{1D532}"),
+    ("ugrave", "\u{F9}"),
+    ("uharl", "\u{21BF}"),
+    ("uharr", "\u{21BE}"),
+    ("uhblk", "\u{2580}"),
+    ("ulcorn", "\u{231C}"),
+    ("ulcorner", "\u{231C}"),
+    ("ulcrop", "\u{230F}"),
+    ("ultri", "\u{25F8}"),
+    ("umacr", "\u{16B}"),
+    ("uml", "\u{A8}"),
+    ("uogon", "\u{173}"),
+    ("uopf", "\u{1D566}"),
+    ("uparrow", "\u{2191}"),
+    ("updownarrow", "\u{2195}"),
+    ("upharpoonleft", "\u{21BF}"),
+    ("upharpoonright", "\u{21BE}"),
+    ("uplus", "\u{228E}"),
+    ("upsi", "\u{3C5}"),
+    ("upsih", "\u{3D2}"),
+    ("upsilon", "\u{3C5}"),
+    ("upuparrows", "\u{21C8}"),
+    ("urcorn", "\u{231D}"),
+    ("urcorner", "\u{231D}"),
+    ("urcrop", "\u{230E}"),
+    ("uring", "\u{16F}"),
+    ("urtri", "\u{25F9}"),
+    ("uscr", "\u{1D4CA}"),
+    ("utdot", "\u{22F0}"),
+    ("utilde", "\u{169}"),
+    ("utri", "\u{25B5}"),
+    ("utrif", "\u{25B4}"),
+    ("uuarr", "\u{21C8}"),
+    ("uuml", "\u{FC}"),
+    ("uwangle", "\u{29A7}"),
+    ("vArr", "\u{21D5}"),
+    ("vBar", "\u{2AE8}"),
+    ("vBarv", "\u{2AE9}"),
+    ("vDash", "\u{22A8}"),
+    ("vangrt", "\u{299C}"),
+    ("varepsilon", "\u{3F5}"),
+    ("varkappa", "\u{3F0}"),
+    ("varnothing", "\u{2205}"),
+    ("varphi", "\u{3D5}"),
+    ("varpi", "\u{3D6}"),
+    ("varpropto", "\u{221D}"),
+    ("varr", "\u{2195}"),
+    ("varrho", "\u{3F1}"),
+    ("varsigma", "\u{3C2}"),
+    ("varsubsetneq", "\u{228A}\u{FE00}"),
+    ("varsubsetneqq", "\u{2ACB}\u{FE00}"),
+    ("varsupsetneq", "\u{228B}\u{FE00}"),
+    ("varsupsetneqq", "\u{2ACC}\u{FE00}"),
+    ("vartheta", "\u{3D1}"),
+    ("vartriangleleft", "\u{22B2}"),
+    ("vartriangleright", "\u{22B3}"),
+    ("vcy", "\u{432}"),
+    ("vdash", "\u{22A2}"),
+    ("vee", "\u{2228}"),
+    ("veebar", "\u{22BB}"),
+    ("veeeq", "\u{225A}"),
+    ("vellip", "\u{22EE}"),
+    ("verbar", "|"),
+    ("vert", "|"),
+    ("vfr", "\u{1D533}"),
+    ("vltri", "\u{22B2}"),
+    ("vnsub", "\u{2282}\u{20D2}"),
+    ("vnsup", "\u{2283}\u{20D2}"),
+    ("vopf", "\u{1D567}"),
+    ("vprop", "\u{221D}"),
+    ("vrtri", "\u{22B3}"),
+    ("vscr", "\u{1D4CB}"),
+    ("vsubnE", "\u{2ACB}\u{FE00}"),
+    ("vsubne", "\u{228A}\u{FE00}"),
+    ("vsupnE", "\u{2ACC}\u{FE00}"),
+    ("vsupne", "\u{228B}\u{FE00}"),
+    ("vzigzag", "\u{299A}"),
+    ("wcirc", "\u{175}"),
+    ("wedbar", "\u{2A5F}"),
+    ("wedge", "\u{2227}"),
+    ("wedgeq", "\u{2259}"),
+    ("weierp", "\u{2118}"),
+    ("wfr", "\u{1D534}"),
+    ("wopf", "\u{1D568}"),
+    ("wp", "\u{2118}"),
+    ("wr", "\u{2240}"),
+    ("wreath", "\u{2240}"),
+    ("wscr", "\u{1D4CC}"),
+    ("xcap", "\u{22C2}"),
+    ("xcirc", "\u{25EF}"),
+    ("xcup", "\u{22C3}"),
+    ("xdtri", "\u{25BD}"),
+    ("xfr", "\u{1D535}"),
+    ("xhArr", "\u{27FA}"),
+    ("xharr", "\u{27F7}"),
+    ("xi", "\u{3BE}"),
+    ("xlArr", "\u{27F8}"),
+    ("xlarr", "\u{27F5}"),
+    ("xmap", "\u{27FC}"),
+    ("xnis", "\u{22FB}"),
+    ("xodot", "\u{2A00}"),
+    ("xopf", "\u{1D569}"),
+    ("xoplus", "\u{2A01}"),
+    ("xotime", "\u{2A02}"),
+    ("xrArr", "\u{27F9}"),
+    ("xrarr", "\u{27F6}"),
+    ("xscr", "\u{1D4CD}"),
+    ("xsqcup", "\u{2A06}"),
+    ("xuplus", "\u{2A04}"),
+    ("xutri", "\u{25B3}"),
+    ("xvee", "\u{22C1}"),
+    ("xwedge", "\u{22C0}"),
+    ("yacute", "\u{FD}"),
+    ("yacy", "\u{44F}"),
+    ("ycirc", "\u{177}"),
+    ("ycy", "\u{44B}"),
+    ("yen", "\u{A5}"),
+    ("yfr", "\u{1D536}"),
+    ("yicy", "\u{457}"),
+    ("yopf", "\u{1D56A}"),
+    ("yscr", "\u{1D4CE}"),
+    ("yucy", "\u{44E}"),
+    ("yuml", "\u{FF}"),
+    ("zacute", "\u{17A}"),
+    ("zcaron", "\u{17E}"),
+    ("zcy", "\u{437}"),
+    ("zdot", "\u{17C}"),
+    ("zeetrf", "\u{2128}"),
+    ("zeta", "\u{3B6}"),
+    ("zfr", "\u{1D537}"),
+    ("zhcy", "\u{436}"),
+    ("zigrarr", "\u{21DD}"),
+    ("zopf", "\u{1D56B}"),
+    ("zscr", "\u{1D4CF}"),
+    ("zwj", "\u{200D}"),
+    ("zwnj", "\u{200C}"),
+];
+
+/// The preferred name of each character that has one: the shortest, then
+/// lowercase, then alphabetically first. Sorted by character.
+pub const NAMES: &[(char, &str)] = &[
+    ('\u{9}', "Tab"),
+    ('\u{A}', "NewLine"),
+    ('!', "excl"),
+    ('\u{22}', "quot"),
+    ('#', "num"),
+    ('$', "dollar"),
+    ('%', "percnt"),
+    ('&', "amp"),
+    ('\'', "apos"),
+    ('(', "lpar"),
+    (')', "rpar"),
+    ('*', "ast"),
+    ('+', "plus"),
+    (',', "comma"),
+    ('.', "period"),
+    ('/', "sol"),
+    (':', "colon"),
+    (';', "semi"),
+    ('<', "lt"),
+    ('=', "equals"),
+    ('>', "gt"),
+    ('?', "quest"),
+    ('@', "commat"),
+    ('[', "lsqb"),
+    ('\u{5C}', "bsol"),
+    (']', "rsqb"),
+    ('^', "Hat"),
+    ('_', "lowbar"),
+    ('`', "grave"),
+    ('{', "lcub"),
+    ('|', "vert"),
+    ('}', "rcub"),
+    ('\u{A0}', "nbsp"),
+    ('\u{A1}', "iexcl"),
+    ('\u{A2}', "cent"),
+    ('\u{A3}', "pound"),
+    ('\u{A4}', "curren"),
+    ('\u{A5}', "yen"),
+    ('\u{A6}', "brvbar"),
+    ('\u{A7}', "sect"),
+    ('\u{A8}', "die"),
+    ('\u{A9}', "copy"),
+    ('\u{AA}', "ordf"),
+    ('\u{AB}', "laquo"),
+    ('\u{AC}', "not"),
+    ('\u{AD}', "shy"),
+    ('\u{AE}', "reg"),
+    ('\u{AF}', "macr"),
+    ('\u{B0}', "deg"),
+    ('\u{B1}', "pm"),
+    ('\u{B2}', "sup2"),
+    ('\u{B3}', "sup3"),
+    ('\u{B4}', "acute"),
+    ('\u{B5}', "micro"),
+    ('\u{B6}', "para"),
+    ('\u{B7}', "middot"),
+    ('\u{B8}', "cedil"),
+    ('\u{B9}', "sup1"),
+    ('\u{BA}', "ordm"),
+    ('\u{BB}', "raquo"),
+    ('\u{BC}', "frac14"),
+    ('\u{BD}', "half"),
+    ('\u{BE}', "frac34"),
+    ('\u{BF}', "iquest"),
+    ('\u{C0}', "Agrave"),
+    ('\u{C1}', "Aacute"),
+    ('\u{C2}', "Acirc"),
+    ('\u{C3}', "Atilde"),
+    ('\u{C4}', "Auml"),
+    ('\u{C5}', "angst"),
+    ('\u{C6}', "AElig"),
+    ('\u{C7}', "Ccedil"),
+    ('\u{C8}', "Egrave"),
+    ('\u{C9}', "Eacute"),
+    ('\u{CA}', "Ecirc"),
+    ('\u{CB}', "Euml"),
+    ('\u{CC}', "Igrave"),
+    ('\u{CD}', "Iacute"),
+    ('\u{CE}', "Icirc"),
+    ('\u{CF}', "Iuml"),
+    ('\u{D0}', "ETH"),
+    ('\u{D1}', "Ntilde"),
+    ('\u{D2}', "Ograve"),
+    ('\u{D3}', "Oacute"),
+    ('\u{D4}', "Ocirc"),
+    ('\u{D5}', "Otilde"),
+    ('\u{D6}', "Ouml"),
+    ('\u{D7}', "times"),
+    ('\u{D8}', "Oslash"),
+    ('\u{D9}', "Ugrave"),
+    ('\u{DA}', "Uacute"),
+    ('\u{DB}', "Ucirc"),
+    ('\u{DC}', "Uuml"),
+    ('\u{DD}', "Yacute"),
+    ('\u{DE}', "THORN"),
+    ('\u{DF}', "szlig"),
+    ('\u{E0}', "agrave"),
+    ('\u{E1}', "aacute"),
+    ('\u{E2}', "acirc"),
+    ('\u{E3}', "atilde"),
+    ('\u{E4}', "auml"),
+    ('\u{E5}', "aring"),
+    ('\u{E6}', "aelig"),
+    ('\u{E7}', "ccedil"),
+    ('\u{E8}', "egrave"),
+    ('\u{E9}', "eacute"),
+    ('\u{EA}', "ecirc"),
+    ('\u{EB}', "euml"),
+    ('\u{EC}', "igrave"),
+    ('\u{ED}', "iacute"),
+    ('\u{EE}', "icirc"),
+    ('\u{EF}', "iuml"),
+    ('\u{F0}', "eth"),
+    ('\u{F1}', "ntilde"),
+    ('\u{F2}', "ograve"),
+    ('\u{F3}', "oacute"),
+    ('\u{F4}', "ocirc"),
+    ('\u{F5}', "otilde"),
+    ('\u{F6}', "ouml"),
+    ('\u{F7}', "div"),
+    ('\u{F8}', "oslash"),
+    ('\u{F9}', "ugrave"),
+    ('\u{FA}', "uacute"),
+    ('\u{FB}', "ucirc"),
+    ('\u{FC}', "uuml"),
+    ('\u{FD}', "yacute"),
+    ('\u{FE}', "thorn"),
+    ('\u{FF}', "yuml"),
+    ('\u{100}', "Amacr"),
+    ('\u{101}', "amacr"),
+    ('\u{102}', "Abreve"),
+    ('\u{103}', "abreve"),
+    ('\u{104}', "Aogon"),
+    ('\u{105}', "aogon"),
+    ('\u{106}', "Cacute"),
+    ('\u{107}', "cacute"),
+    ('\u{108}', "Ccirc"),
+    ('\u{109}', "ccirc"),
+    ('\u{10A}', "Cdot"),
+    ('\u{10B}', "cdot"),
+    ('\u{10C}', "Ccaron"),
+    ('\u{10D}', "ccaron"),
+    ('\u{10E}', "Dcaron"),
+    ('\u{10F}', "dcaron"),
+    ('\u{110}', "Dstrok"),
+    ('\u{111}', "dstrok"),
+    ('\u{112}', "Emacr"),
+    ('\u{113}', "emacr"),
+    ('\u{116}', "Edot"),
+    ('\u{117}', "edot"),
+    ('\u{118}', "Eogon"),
+    ('\u{119}', "eogon"),
+    ('\u{11A}', "Ecaron"),
+    ('\u{11B}', "ecaron"),
+    ('\u{11C}', "Gcirc"),
+    ('\u{11D}', "gcirc"),
+    ('\u{11E}', "Gbreve"),
+    ('\u{11F}', "gbreve"),
+    ('\u{120}', "Gdot"),
+    ('\u{121}', "gdot"),
+    ('\u{122}', "Gcedil"),
+    ('\u{124}', "Hcirc"),
+    ('\u{125}', "hcirc"),
+    ('\u{126}', "Hstrok"),
+    ('\u{127}', "hstrok"),
+    ('\u{128}', "Itilde"),
+    ('\u{129}', "itilde"),
+    ('\u{12A}', "Imacr"),
+    ('\u{12B}', "imacr"),
+    ('\u{12E}', "Iogon"),
+    ('\u{12F}', "iogon"),
+    ('\u{130}', "Idot"),
+    ('\u{131}', "imath"),
+    ('\u{132}', "IJlig"),
+    ('\u{133}', "ijlig"),
+    ('\u{134}', "Jcirc"),
+    ('\u{135}', "jcirc"),
+    ('\u{136}', "Kcedil"),
+    ('\u{137}', "kcedil"),
+    ('\u{138}', "kgreen"),
+    ('\u{139}', "Lacute"),
+    ('\u{13A}', "lacute"),
+    ('\u{13B}', "Lcedil"),
+    ('\u{13C}', "lcedil"),
+    ('\u{13D}', "Lcaron"),
+    ('\u{13E}', "lcaron"),
+    ('\u{13F}', "Lmidot"),
+    ('\u{140}', "lmidot"),
+    ('\u{141}', "Lstrok"),
+    ('\u{142}', "lstrok"),
+    ('\u{143}', "Nacute"),
+    ('\u{144}', "nacute"),
+    ('\u{145}', "Ncedil"),
+    ('\u{146}', "ncedil"),
+    ('\u{147}', "Ncaron"),
+    ('\u{148}', "ncaron"),
+    ('\u{149}', "napos"),
+    ('\u{14A}', "ENG"),
+    ('\u{14B}', "eng"),
+    ('\u{14C}', "Omacr"),
+    ('\u{14D}', "omacr"),
+    ('\u{150}', "Odblac"),
+    ('\u{151}', "odblac"),
+    ('\u{152}', "OElig"),
+    ('\u{153}', "oelig"),
+    ('\u{154}', "Racute"),
+    ('\u{155}', "racute"),
+    ('\u{156}', "Rcedil"),
+    ('\u{157}', "rcedil"),
+    ('\u{158}', "Rcaron"),
+    ('\u{159}', "rcaron"),
+    ('\u{15A}', "Sacute"),
+    ('\u{15B}', "sacute"),
+    ('\u{15C}', "Scirc"),
+    ('\u{15D}', "scirc"),
+    ('\u{15E}', "Scedil"),
+    ('\u{15F}', "scedil"),
+    ('\u{160}', "Scaron"),
+    ('\u{161}', "scaron"),
+    ('\u{162}', "Tcedil"),
+    ('\u{163}', "tcedil"),
+    ('\u{164}', "Tcaron"),
+    ('\u{165}', "tcaron"),
+    ('\u{166}', "Tstrok"),
+    ('\u{167}', "tstrok"),
+    ('\u{168}', "Utilde"),
+    ('\u{169}', "utilde"),
+    ('\u{16A}', "Umacr"),
+    ('\u{16B}', "umacr"),
+    ('\u{16C}', "Ubreve"),
+    ('\u{16D}', "ubreve"),
+    ('\u{16E}', "Uring"),
+    ('\u{16F}', "uring"),
+    ('\u{170}', "Udblac"),
+    ('\u{171}', "udblac"),
+    ('\u{172}', "Uogon"),
+    ('\u{173}', "uogon"),
+    ('\u{174}', "Wcirc"),
+    ('\u{175}', "wcirc"),
+    ('\u{176}', "Ycirc"),
+    ('\u{177}', "ycirc"),
+    ('\u{178}', "Yuml"),
+    ('\u{179}', "Zacute"),
+    ('\u{17A}', "zacute"),
+    ('\u{17B}', "Zdot"),
+    ('\u{17C}', "zdot"),
+    ('\u{17D}', "Zcaron"),
+    ('\u{17E}', "zcaron"),
+    ('\u{192}', "fnof"),
+    ('\u{1B5}', "imped"),
+    ('\u{1F5}', "gacute"),
+    ('\u{237}', "jmath"),
+    ('\u{2C6}', "circ"),
+    ('\u{2C7}', "caron"),
+    ('\u{2D8}', "breve"),
+    ('\u{2D9}', "dot"),
+    ('\u{2DA}', "ring"),
+    ('\u{2DB}', "ogon"),
+    ('\u{2DC}', "tilde"),
+    ('\u{2DD}', "dblac"),
+    ('\u{311}', "DownBreve"),
+    ('\u{391}', "Alpha"),
+    ('\u{392}', "Beta"),
+    ('\u{393}', "Gamma"),
+    ('\u{394}', "Delta"),
+    ('\u{395}', "Epsilon"),
+    ('\u{396}', "Zeta"),
+    ('\u{397}', "Eta"),
+    ('\u{398}', "Theta"),
+    ('\u{399}', "Iota"),
+    ('\u{39A}', "Kappa"),
+    ('\u{39B}', "Lambda"),
+    ('\u{39C}', "Mu"),
+    ('\u{39D}', "Nu"),
+    ('\u{39E}', "Xi"),
+    ('\u{39F}', "Omicron"),
+    ('\u{3A0}', "Pi"),
+    ('\u{3A1}', "Rho"),
+    ('\u{3A3}', "Sigma"),
+    ('\u{3A4}', "Tau"),
+    ('\u{3A5}', "Upsilon"),
+    ('\u{3A6}', "Phi"),
+    ('\u{3A7}', "Chi"),
+    ('\u{3A8}', "Psi"),
+    ('\u{3A9}', "ohm"),
+    ('\u{3B1}', "alpha"),
+    ('\u{3B2}', "beta"),
+    ('\u{3B3}', "gamma"),
+    ('\u{3B4}', "delta"),
+    ('\u{3B5}', "epsi"),
+    ('\u{3B6}', "zeta"),
+    ('\u{3B7}', "eta"),
+    ('\u{3B8}', "theta"),
+    ('\u{3B9}', "iota"),
+    ('\u{3BA}', "kappa"),
+    ('\u{3BB}', "lambda"),
+    ('\u{3BC}', "mu"),
+    ('\u{3BD}', "nu"),
+    ('\u{3BE}', "xi"),
+    ('\u{3BF}', "omicron"),
+    ('\u{3C0}', "pi"),
+    ('\u{3C1}', "rho"),
+    ('\u{3C2}', "sigmaf"),
+    ('\u{3C3}', "sigma"),
+    ('\u{3C4}', "tau"),
+    ('\u{3C5}', "upsi"),
+    ('\u{3C6}', "phi"),
+    ('\u{3C7}', "chi"),
+    ('\u{3C8}', "psi"),
+    ('\u{3C9}', "omega"),
+    ('\u{3D1}', "thetav"),
+    ('\u{3D2}', "Upsi"),
+    ('\u{3D5}', "phiv"),
+    ('\u{3D6}', "piv"),
+    ('\u{3DC}', "Gammad"),
+    ('\u{3DD}', "gammad"),
+    ('\u{3F0}', "kappav"),
+    ('\u{3F1}', "rhov"),
+    ('\u{3F5}', "epsiv"),
+    ('\u{3F6}', "bepsi"),
+    ('\u{401}', "IOcy"),
+    ('\u{402}', "DJcy"),
+    ('\u{403}', "GJcy"),
+    ('\u{404}', "Jukcy"),
+    ('\u{405}', "DScy"),
+    ('\u{406}', "Iukcy"),
+    ('\u{407}', "YIcy"),
+    ('\u{408}', "Jsercy"),
+    ('\u{409}', "LJcy"),
+    ('\u{40A}', "NJcy"),
+    ('\u{40B}', "TSHcy"),
+    ('\u{40C}', "KJcy"),
+    ('\u{40E}', "Ubrcy"),
+    ('\u{40F}', "DZcy"),
+    ('\u{410}', "Acy"),
+    ('\u{411}', "Bcy"),
+    ('\u{412}', "Vcy"),
+    ('\u{413}', "Gcy"),
+    ('\u{414}', "Dcy"),
+    ('\u{415}', "IEcy"),
+    ('\u{416}', "ZHcy"),
+    ('\u{417}', "Zcy"),
+    ('\u{418}', "Icy"),
+    ('\u{419}', "Jcy"),
+    ('\u{41A}', "Kcy"),
+    ('\u{41B}', "Lcy"),
+    ('\u{41C}', "Mcy"),
+    ('\u{41D}', "Ncy"),
+    ('\u{41E}', "Ocy"),
+    ('\u{41F}', "Pcy"),
+    ('\u{420}', "Rcy"),
+    ('\u{421}', "Scy"),
+    ('\u{422}', "Tcy"),
+    ('\u{423}', "Ucy"),
+    ('\u{424}', "Fcy"),
+    ('\u{425}', "KHcy"),
+    ('\u{426}', "TScy"),
+    ('\u{427}', "CHcy"),
+    ('\u{428}', "SHcy"),
+    ('\u{429}', "SHCHcy"),
+    ('\u{42A}', "HARDcy"),
+    ('\u{42B}', "Ycy"),
+    ('\u{42C}', "SOFTcy"),
+    ('\u{42D}', "Ecy"),
+    ('\u{42E}', "YUcy"),
+    ('\u{42F}', "YAcy"),
+    ('\u{430}', "acy"),
+    ('\u{431}', "bcy"),
+    ('\u{432}', "vcy"),
+    ('\u{433}', "gcy"),
+    ('\u{434}', "dcy"),
+    ('\u{435}', "iecy"),
+    ('\u{436}', "zhcy"),
+    ('\u{437}', "zcy"),
+    ('\u{438}', "icy"),
+    ('\u{439}', "jcy"),
+    ('\u{43A}', "kcy"),
+    ('\u{43B}', "lcy"),
+    ('\u{43C}', "mcy"),
+    ('\u{43D}', "ncy"),
+    ('\u{43E}', "ocy"),
+    ('\u{43F}', "pcy"),
+    ('\u{440}', "rcy"),
+    ('\u{441}', "scy"),
+    ('\u{442}', "tcy"),
+    ('\u{443}', "ucy"),
+    ('\u{444}', "fcy"),
+    ('\u{445}', "khcy"),
+    ('\u{446}', "tscy"),
+    ('\u{447}', "chcy"),
+    ('\u{448}', "shcy"),
+    ('\u{449}', "shchcy"),
+    ('\u{44A}', "hardcy"),
+    ('\u{44B}', "ycy"),
+    ('\u{44C}', "softcy"),
+    ('\u{44D}', "ecy"),
+    ('\u{44E}', "yucy"),
+    ('\u{44F}', "yacy"),
+    ('\u{451}', "iocy"),
+    ('\u{452}', "djcy"),
+    ('\u{453}', "gjcy"),
+    ('\u{454}', "jukcy"),
+    ('\u{455}', "dscy"),
+    ('\u{456}', "iukcy"),
+    ('\u{457}', "yicy"),
+    ('\u{458}', "jsercy"),
+    ('\u{459}', "ljcy"),
+    ('\u{45A}', "njcy"),
+    ('\u{45B}', "tshcy"),
+    ('\u{45C}', "kjcy"),
+    ('\u{45E}', "ubrcy"),
+    ('\u{45F}', "dzcy"),
+    ('\u{2002}', "ensp"),
+    ('\u{2003}', "emsp"),
+    ('\u{2004}', "emsp13"),
+    ('\u{2005}', "emsp14"),
+    ('\u{2007}', "numsp"),
+    ('\u{2008}', "puncsp"),
+    ('\u{2009}', "thinsp"),
+    ('\u{200A}', "hairsp"),
+    ('\u{200B}', "ZeroWidthSpace"),
+    ('\u{200C}', "zwnj"),
+    ('\u{200D}', "zwj"),
+    ('\u{200E}', "lrm"),
+    ('\u{200F}', "rlm"),
+    ('\u{2010}', "dash"),
+    ('\u{2013}', "ndash"),
+    ('\u{2014}', "mdash"),
+    ('\u{2015}', "horbar"),
+    ('\u{2016}', "Vert"),
+    ('\u{2018}', "lsquo"),
+    ('\u{2019}', "rsquo"),
+    ('\u{201A}', "sbquo"),
+    ('\u{201C}', "ldquo"),
+    ('\u{201D}', "rdquo"),
+    ('\u{201E}', "bdquo"),
+    ('\u{2020}', "dagger"),
+    ('\u{2021}', "Dagger"),
+    ('\u{2022}', "bull"),
+    ('\u{2025}', "nldr"),
+    ('\u{2026}', "mldr"),
+    ('\u{2030}', "permil"),
+    ('\u{2031}', "pertenk"),
+    ('\u{2032}', "prime"),
+    ('\u{2033}', "Prime"),
+    ('\u{2034}', "tprime"),
+    ('\u{2035}', "bprime"),
+    ('\u{2039}', "lsaquo"),
+    ('\u{203A}', "rsaquo"),
+    ('\u{203E}', "oline"),
+    ('\u{2041}', "caret"),
+    ('\u{2043}', "hybull"),
+    ('\u{2044}', "frasl"),
+    ('\u{204F}', "bsemi"),
+    ('\u{2057}', "qprime"),
+    ('\u{205F}', "MediumSpace"),
+    ('\u{2060}', "NoBreak"),
+    ('\u{2061}', "af"),
+    ('\u{2062}', "it"),
+    ('\u{2063}', "ic"),
+    ('\u{20AC}', "euro"),
+    ('\u{20DB}', "tdot"),
+    ('\u{20DC}', "DotDot"),
+    ('\u{2102}', "Copf"),
+    ('\u{2105}', "incare"),
+    ('\u{210A}', "gscr"),
+    ('\u{210B}', "Hscr"),
+    ('\u{210C}', "Hfr"),
+    ('\u{210D}', "Hopf"),
+    ('\u{210E}', "planckh"),
+    ('\u{210F}', "hbar"),
+    ('\u{2110}', "Iscr"),
+    ('\u{2111}', "Im"),
+    ('\u{2112}', "Lscr"),
+    ('\u{2113}', "ell"),
+    ('\u{2115}', "Nopf"),
+    ('\u{2116}', "numero"),
+    ('\u{2117}', "copysr"),
+    ('\u{2118}', "wp"),
+    ('\u{2119}', "Popf"),
+    ('\u{211A}', "Qopf"),
+    ('\u{211B}', "Rscr"),
+    ('\u{211C}', "Re"),
+    ('\u{211D}', "Ropf"),
+    ('\u{211E}', "rx"),
+    ('\u{2122}', "trade"),
+    ('\u{2124}', "Zopf"),
+    ('\u{2127}', "mho"),
+    ('\u{2128}', "Zfr"),
+    ('\u{2129}', "iiota"),
+    ('\u{212C}', "Bscr"),
+    ('\u{212D}', "Cfr"),
+    ('\u{212F}', "escr"),
+    ('\u{2130}', "Escr"),
+    ('\u{2131}', "Fscr"),
+    ('\u{2133}', "Mscr"),
+    ('\u{2134}', "oscr"),
+    ('\u{2135}', "aleph"),
+    ('\u{2136}', "beth"),
+    ('\u{2137}', "gimel"),
+    ('\u{2138}', "daleth"),
+    ('\u{2145}', "DD"),
+    ('\u{2146}', "dd"),
+    ('\u{2147}', "ee"),
+    ('\u{2148}', "ii"),
+    ('\u{2153}', "frac13"),
+    ('\u{2154}', "frac23"),
+    ('\u{2155}', "frac15"),
+    ('\u{2156}', "frac25"),
+    ('\u{2157}', "frac35"),
+    ('\u{2158}', "frac45"),
+    ('\u{2159}', "frac16"),
+    ('\u{215A}', "frac56"),
+    ('\u{215B}', "frac18"),
+    ('\u{215C}', "frac38"),
+    ('\u{215D}', "frac58"),
+    ('\u{215E}', "frac78"),
+    ('\u{2190}', "larr"),
+    ('\u{2191}', "uarr"),
+    ('\u{2192}', "rarr"),
+    ('\u{2193}', "darr"),
+    ('\u{2194}', "harr"),
+    ('\u{2195}', "varr"),
+    ('\u{2196}', "nwarr"),
+    ('\u{2197}', "nearr"),
+    ('\u{2198}', "searr"),
+    ('\u{2199}', "swarr"),
+    ('\u{219A}', "nlarr"),
+    ('\u{219B}', "nrarr"),
+    ('\u{219D}', "rarrw"),
+    ('\u{219E}', "Larr"),
+    ('\u{219F}', "Uarr"),
+    ('\u{21A0}', "Rarr"),
+    ('\u{21A1}', "Darr"),
+    ('\u{21A2}', "larrtl"),
+    ('\u{21A3}', "rarrtl"),
+    ('\u{21A4}', "mapstoleft"),
+    ('\u{21A5}', "mapstoup"),
+    ('\u{21A6}', "map"),
+    ('\u{21A7}', "mapstodown"),
+    ('\u{21A9}', "larrhk"),
+    ('\u{21AA}', "rarrhk"),
+    ('\u{21AB}', "larrlp"),
+    ('\u{21AC}', "rarrlp"),
+    ('\u{21AD}', "harrw"),
+    ('\u{21AE}', "nharr"),
+    ('\u{21B0}', "lsh"),
+    ('\u{21B1}', "rsh"),
+    ('\u{21B2}', "ldsh"),
+    ('\u{21B3}', "rdsh"),
+    ('\u{21B5}', "crarr"),
+    ('\u{21B6}', "cularr"),
+    ('\u{21B7}', "curarr"),
+    ('\u{21BA}', "olarr"),
+    ('\u{21BB}', "orarr"),
+    ('\u{21BC}', "lharu"),
+    ('\u{21BD}', "lhard"),
+    ('\u{21BE}', "uharr"),
+    ('\u{21BF}', "uharl"),
+    ('\u{21C0}', "rharu"),
+    ('\u{21C1}', "rhard"),
+    ('\u{21C2}', "dharr"),
+    ('\u{21C3}', "dharl"),
+    ('\u{21C4}', "rlarr"),
+    ('\u{21C5}', "udarr"),
+    ('\u{21C6}', "lrarr"),
+    ('\u{21C7}', "llarr"),
+    ('\u{21C8}', "uuarr"),
+    ('\u{21C9}', "rrarr"),
+    ('\u{21CA}', "ddarr"),
+    ('\u{21CB}', "lrhar"),
+    ('\u{21CC}', "rlhar"),
+    ('\u{21CD}', "nlArr"),
+    ('\u{21CE}', "nhArr"),
+    ('\u{21CF}', "nrArr"),
+    ('\u{21D0}', "lArr"),
+    ('\u{21D1}', "uArr"),
+    ('\u{21D2}', "rArr"),
+    ('\u{21D3}', "dArr"),
+    ('\u{21D4}', "iff"),
+    ('\u{21D5}', "vArr"),
+    ('\u{21D6}', "nwArr"),
+    ('\u{21D7}', "neArr"),
+    ('\u{21D8}', "seArr"),
+    ('\u{21D9}', "swArr"),
+    ('\u{21DA}', "lAarr"),
+    ('\u{21DB}', "rAarr"),
+    ('\u{21DD}', "zigrarr"),
+    ('\u{21E4}', "larrb"),
+    ('\u{21E5}', "rarrb"),
+    ('\u{21F5}', "duarr"),
+    ('\u{21FD}', "loarr"),
+    ('\u{21FE}', "roarr"),
+    ('\u{21FF}', "hoarr"),
+    ('\u{2200}', "forall"),
+    ('\u{2201}', "comp"),
+    ('\u{2202}', "part"),
+    ('\u{2203}', "exist"),
+    ('\u{2204}', "nexist"),
+    ('\u{2205}', "empty"),
+    ('\u{2207}', "Del"),
+    ('\u{2208}', "in"),
+    ('\u{2209}', "notin"),
+    ('\u{220B}', "ni"),
+    ('\u{220C}', "notni"),
+    ('\u{220F}', "prod"),
+    ('\u{2210}', "coprod"),
+    ('\u{2211}', "sum"),
+    ('\u{2212}', "minus"),
+    ('\u{2213}', "mp"),
+    ('\u{2214}', "plusdo"),
+    ('\u{2216}', "setmn"),
+    ('\u{2217}', "lowast"),
+    ('\u{2218}', "compfn"),
+    ('\u{221A}', "Sqrt"),
+    ('\u{221D}', "prop"),
+    ('\u{221E}', "infin"),
+    ('\u{221F}', "angrt"),
+    ('\u{2220}', "ang"),
+    ('\u{2221}', "angmsd"),
+    ('\u{2222}', "angsph"),
+    ('\u{2223}', "mid"),
+    ('\u{2224}', "nmid"),
+    ('\u{2225}', "par"),
+    ('\u{2226}', "npar"),
+    ('\u{2227}', "and"),
+    ('\u{2228}', "or"),
+    ('\u{2229}', "cap"),
+    ('\u{222A}', "cup"),
+    ('\u{222B}', "int"),
+    ('\u{222C}', "Int"),
+    ('\u{222D}', "tint"),
+    ('\u{222E}', "oint"),
+    ('\u{222F}', "Conint"),
+    ('\u{2230}', "Cconint"),
+    ('\u{2231}', "cwint"),
+    ('\u{2232}', "cwconint"),
+    ('\u{2233}', "awconint"),
+    ('\u{2234}', "there4"),
+    ('\u{2235}', "becaus"),
+    ('\u{2236}', "ratio"),
+    ('\u{2237}', "Colon"),
+    ('\u{2238}', "minusd"),
+    ('\u{223A}', "mDDot"),
+    ('\u{223B}', "homtht"),
+    ('\u{223C}', "sim"),
+    ('\u{223D}', "bsim"),
+    ('\u{223E}', "ac"),
+    ('\u{223F}', "acd"),
+    ('\u{2240}', "wr"),
+    ('\u{2241}', "nsim"),
+    ('\u{2242}', "esim"),
+    ('\u{2243}', "sime"),
+    ('\u{2244}', "nsime"),
+    ('\u{2245}', "cong"),
+    ('\u{2246}', "simne"),
+    ('\u{2247}', "ncong"),
+    ('\u{2248}', "ap"),
+    ('\u{2249}', "nap"),
+    ('\u{224A}', "ape"),
+    ('\u{224B}', "apid"),
+    ('\u{224C}', "bcong"),
+    ('\u{224D}', "CupCap"),
+    ('\u{224E}', "bump"),
+    ('\u{224F}', "bumpe"),
+    ('\u{2250}', "doteq"),
+    ('\u{2251}', "eDot"),
+    ('\u{2252}', "efDot"),
+    ('\u{2253}', "erDot"),
+    ('\u{2254}', "colone"),
+    ('\u{2255}', "ecolon"),
+    ('\u{2256}', "ecir"),
+    ('\u{2257}', "cire"),
+    ('\u{2259}', "wedgeq"),
+    ('\u{225A}', "veeeq"),
+    ('\u{225C}', "trie"),
+    ('\u{225F}', "equest"),
+    ('\u{2260}', "ne"),
+    ('\u{2261}', "equiv"),
+    ('\u{2262}', "nequiv"),
+    ('\u{2264}', "le"),
+    ('\u{2265}', "ge"),
+    ('\u{2266}', "lE"),
+    ('\u{2267}', "gE"),
+    ('\u{2268}', "lnE"),
+    ('\u{2269}', "gnE"),
+    ('\u{226A}', "ll"),
+    ('\u{226B}', "gg"),
+    ('\u{226C}', "twixt"),
+    ('\u{226D}', "NotCupCap"),
+    ('\u{226E}', "nlt"),
+    ('\u{226F}', "ngt"),
+    ('\u{2270}', "nle"),
+    ('\u{2271}', "nge"),
+    ('\u{2272}', "lsim"),
+    ('\u{2273}', "gsim"),
+    ('\u{2274}', "nlsim"),
+    ('\u{2275}', "ngsim"),
+    ('\u{2276}', "lg"),
+    ('\u{2277}', "gl"),
+    ('\u{2278}', "ntlg"),
+    ('\u{2279}', "ntgl"),
+    ('\u{227A}', "pr"),
+    ('\u{227B}', "sc"),
+    ('\u{227C}', "prcue"),
+    ('\u{227D}', "sccue"),
+    ('\u{227E}', "prsim"),
+    ('\u{227F}', "scsim"),
+    ('\u{2280}', "npr"),
+    ('\u{2281}', "nsc"),
+    ('\u{2282}', "sub"),
+    ('\u{2283}', "sup"),
+    ('\u{2284}', "nsub"),
+    ('\u{2285}', "nsup"),
+    ('\u{2286}', "sube"),
+    ('\u{2287}', "supe"),
+    ('\u{2288}', "nsube"),
+    ('\u{2289}', "nsupe"),
+    ('\u{228A}', "subne"),
+    ('\u{228B}', "supne"),
+    ('\u{228D}', "cupdot"),
+    ('\u{228E}', "uplus"),
+    ('\u{228F}', "sqsub"),
+    ('\u{2290}', "sqsup"),
+    ('\u{2291}', "sqsube"),
+    ('\u{2292}', "sqsupe"),
+    ('\u{2293}', "sqcap"),
+    ('\u{2294}', "sqcup"),
+    ('\u{2295}', "oplus"),
+    ('\u{2296}', "ominus"),
+    ('\u{2297}', "otimes"),
+    ('\u{2298}', "osol"),
+    ('\u{2299}', "odot"),
+    ('\u{229A}', "ocir"),
+    ('\u{229B}', "oast"),
+    ('\u{229D}', "odash"),
+    ('\u{229E}', "plusb"),
+    ('\u{229F}', "minusb"),
+    ('\u{22A0}', "timesb"),
+    ('\u{22A1}', "sdotb"),
+    ('\u{22A2}', "vdash"),
+    ('\u{22A3}', "dashv"),
+    ('\u{22A4}', "top"),
+    ('\u{22A5}', "bot"),
+    ('\u{22A7}', "models"),
+    ('\u{22A8}', "vDash"),
+    ('\u{22A9}', "Vdash"),
+    ('\u{22AA}', "Vvdash"),
+    ('\u{22AB}', "VDash"),
+    ('\u{22AC}', "nvdash"),
+    ('\u{22AD}', "nvDash"),
+    ('\u{22AE}', "nVdash"),
+    ('\u{22AF}', "nVDash"),
+    ('\u{22B0}', "prurel"),
+    ('\u{22B2}', "vltri"),
+    ('\u{22B3}', "vrtri"),
+    ('\u{22B4}', "ltrie"),
+    ('\u{22B5}', "rtrie"),
+    ('\u{22B6}', "origof"),
+    ('\u{22B7}', "imof"),
+    ('\u{22B8}', "mumap"),
+    ('\u{22B9}', "hercon"),
+    ('\u{22BA}', "intcal"),
+    ('\u{22BB}', "veebar"),
+    ('\u{22BD}', "barvee"),
+    ('\u{22BE}', "angrtvb"),
+    ('\u{22BF}', "lrtri"),
+    ('\u{22C0}', "Wedge"),
+    ('\u{22C1}', "Vee"),
+    ('\u{22C2}', "xcap"),
+    ('\u{22C3}', "xcup"),
+    ('\u{22C4}', "diam"),
+    ('\u{22C5}', "sdot"),
+    ('\u{22C6}', "Star"),
+    ('\u{22C7}', "divonx"),
+    ('\u{22C8}', "bowtie"),
+    ('\u{22C9}', "ltimes"),
+    ('\u{22CA}', "rtimes"),
+    ('\u{22CB}', "lthree"),
+    ('\u{22CC}', "rthree"),
+    ('\u{22CD}', "bsime"),
+    ('\u{22CE}', "cuvee"),
+    ('\u{22CF}', "cuwed"),
+    ('\u{22D0}', "Sub"),
+    ('\u{22D1}', "Sup"),
+    ('\u{22D2}', "Cap"),
+    ('\u{22D3}', "Cup"),
+    ('\u{22D4}', "fork"),
+    ('\u{22D5}', "epar"),
+    ('\u{22D6}', "ltdot"),
+    ('\u{22D7}', "gtdot"),
+    ('\u{22D8}', "Ll"),
+    ('\u{22D9}', "Gg"),
+    ('\u{22DA}', "leg"),
+    ('\u{22DB}', "gel"),
+    ('\u{22DE}', "cuepr"),
+    ('\u{22DF}', "cuesc"),
+    ('\u{22E0}', "nprcue"),
+    ('\u{22E1}', "nsccue"),
+    ('\u{22E2}', "nsqsube"),
+    ('\u{22E3}', "nsqsupe"),
+    ('\u{22E6}', "lnsim"),
+    ('\u{22E7}', "gnsim"),
+    ('\u{22E8}', "prnsim"),
+    ('\u{22E9}', "scnsim"),
+    ('\u{22EA}', "nltri"),
+    ('\u{22EB}', "nrtri"),
+    ('\u{22EC}', "nltrie"),
+    ('\u{22ED}', "nrtrie"),
+    ('\u{22EE}', "vellip"),
+    ('\u{22EF}', "ctdot"),
+    ('\u{22F0}', "utdot"),
+    ('\u{22F1}', "dtdot"),
+    ('\u{22F2}', "disin"),
+    ('\u{22F3}', "isinsv"),
+    ('\u{22F4}', "isins"),
+    ('\u{22F5}', "isindot"),
+    ('\u{22F6}', "notinvc"),
+    ('\u{22F7}', "notinvb"),
+    ('\u{22F9}', "isinE"),
+    ('\u{22FA}', "nisd"),
+    ('\u{22FB}', "xnis"),
+    ('\u{22FC}', "nis"),
+    ('\u{22FD}', "notnivc"),
+    ('\u{22FE}', "notnivb"),
+    ('\u{2305}', "barwed"),
+    ('\u{2306}', "Barwed"),
+    ('\u{2308}', "lceil"),
+    ('\u{2309}', "rceil"),
+    ('\u{230A}', "lfloor"),
+    ('\u{230B}', "rfloor"),
+    ('\u{230C}', "drcrop"),
+    ('\u{230D}', "dlcrop"),
+    ('\u{230E}', "urcrop"),
+    ('\u{230F}', "ulcrop"),
+    ('\u{2310}', "bnot"),
+    ('\u{2312}', "profline"),
+    ('\u{2313}', "profsurf"),
+    ('\u{2315}', "telrec"),
+    ('\u{2316}', "target"),
+    ('\u{231C}', "ulcorn"),
+    ('\u{231D}', "urcorn"),
+    ('\u{231E}', "dlcorn"),
+    ('\u{231F}', "drcorn"),
+    ('\u{2322}', "frown"),
+    ('\u{2323}', "smile"),
+    ('\u{232D}', "cylcty"),
+    ('\u{232E}', "profalar"),
+    ('\u{2336}', "topbot"),
+    ('\u{233D}', "ovbar"),
+    ('\u{233F}', "solbar"),
+    ('\u{237C}', "angzarr"),
+    ('\u{23B0}', "lmoust"),
+    ('\u{23B1}', "rmoust"),
+    ('\u{23B4}', "tbrk"),
+    ('\u{23B5}', "bbrk"),
+    ('\u{23B6}', "bbrktbrk"),
+    ('\u{23DC}', "OverParenthesis"),
+    ('\u{23DD}', "UnderParenthesis"),
+    ('\u{23DE}', "OverBrace"),
+    ('\u{23DF}', "UnderBrace"),
+    ('\u{23E2}', "trpezium"),
+    ('\u{23E7}', "elinters"),
+    ('\u{2423}', "blank"),
+    ('\u{24C8}', "oS"),
+    ('\u{2500}', "boxh"),
+    ('\u{2502}', "boxv"),
+    ('\u{250C}', "boxdr"),
+    ('\u{2510}', "boxdl"),
+    ('\u{2514}', "boxur"),
+    ('\u{2518}', "boxul"),
+    ('\u{251C}', "boxvr"),
+    ('\u{2524}', "boxvl"),
+    ('\u{252C}', "boxhd"),
+    ('\u{2534}', "boxhu"),
+    ('\u{253C}', "boxvh"),
+    ('\u{2550}', "boxH"),
+    ('\u{2551}', "boxV"),
+    ('\u{2552}', "boxdR"),
+    ('\u{2553}', "boxDr"),
+    ('\u{2554}', "boxDR"),
+    ('\u{2555}', "boxdL"),
+    ('\u{2556}', "boxDl"),
+    ('\u{2557}', "boxDL"),
+    ('\u{2558}', "boxuR"),
+    ('\u{2559}', "boxUr"),
+    ('\u{255A}', "boxUR"),
+    ('\u{255B}', "boxuL"),
+    ('\u{255C}', "boxUl"),
+    ('\u{255D}', "boxUL"),
+    ('\u{255E}', "boxvR"),
+    ('\u{255F}', "boxVr"),
+    ('\u{2560}', "boxVR"),
+    ('\u{2561}', "boxvL"),
+    ('\u{2562}', "boxVl"),
+    ('\u{2563}', "boxVL"),
+    ('\u{2564}', "boxHd"),
+    ('\u{2565}', "boxhD"),
+    ('\u{2566}', "boxHD"),
+    ('\u{2567}', "boxHu"),
+    ('\u{2568}', "boxhU"),
+    ('\u{2569}', "boxHU"),
+    ('\u{256A}', "boxvH"),
+    ('\u{256B}', "boxVh"),
+    ('\u{256C}', "boxVH"),
+    ('\u{2580}', "uhblk"),
+    ('\u{2584}', "lhblk"),
+    ('\u{2588}', "block"),
+    ('\u{2591}', "blk14"),
+    ('\u{2592}', "blk12"),
+    ('\u{2593}', "blk34"),
+    ('\u{25A1}', "squ"),
+    ('\u{25AA}', "squf"),
+    ('\u{25AB}', "EmptyVerySmallSquare"),
+    ('\u{25AD}', "rect"),
+    ('\u{25AE}', "marker"),
+    ('\u{25B1}', "fltns"),
+    ('\u{25B3}', "xutri"),
+    ('\u{25B4}', "utrif"),
+    ('\u{25B5}', "utri"),
+    ('\u{25B8}', "rtrif"),
+    ('\u{25B9}', "rtri"),
+    ('\u{25BD}', "xdtri"),
+    ('\u{25BE}', "dtrif"),
+    ('\u{25BF}', "dtri"),
+    ('\u{25C2}', "ltrif"),
+    ('\u{25C3}', "ltri"),
+    ('\u{25CA}', "loz"),
+    ('\u{25CB}', "cir"),
+    ('\u{25EC}', "tridot"),
+    ('\u{25EF}', "xcirc"),
+    ('\u{25F8}', "ultri"),
+    ('\u{25F9}', "urtri"),
+    ('\u{25FA}', "lltri"),
+    ('\u{25FB}', "EmptySmallSquare"),
+    ('\u{25FC}', "FilledSmallSquare"),
+    ('\u{2605}', "starf"),
+    ('\u{2606}', "star"),
+    ('\u{260E}', "phone"),
+    ('\u{2640}', "female"),
+    ('\u{2642}', "male"),
+    ('\u{2660}', "spades"),
+    ('\u{2663}', "clubs"),
+    ('\u{2665}', "hearts"),
+    ('\u{2666}', "diams"),
+    ('\u{266A}', "sung"),
+    ('\u{266D}', "flat"),
+    ('\u{266E}', "natur"),
+    ('\u{266F}', "sharp"),
+    ('\u{2713}', "check"),
+    ('\u{2717}', "cross"),
+    ('\u{2720}', "malt"),
+    ('\u{2736}', "sext"),
+    ('\u{2758}', "VerticalSeparator"),
+    ('\u{2772}', "lbbrk"),
+    ('\u{2773}', "rbbrk"),
+    ('\u{27C8}', "bsolhsub"),
+    ('\u{27C9}', "suphsol"),
+    ('\u{27E6}', "lobrk"),
+    ('\u{27E7}', "robrk"),
+    ('\u{27E8}', "lang"),
+    ('\u{27E9}', "rang"),
+    ('\u{27EA}', "Lang"),
+    ('\u{27EB}', "Rang"),
+    ('\u{27EC}', "loang"),
+    ('\u{27ED}', "roang"),
+    ('\u{27F5}', "xlarr"),
+    ('\u{27F6}', "xrarr"),
+    ('\u{27F7}', "xharr"),
+    ('\u{27F8}', "xlArr"),
+    ('\u{27F9}', "xrArr"),
+    ('\u{27FA}', "xhArr"),
+    ('\u{27FC}', "xmap"),
+    ('\u{27FF}', "dzigrarr"),
+    ('\u{2902}', "nvlArr"),
+    ('\u{2903}', "nvrArr"),
+    ('\u{2904}', "nvHarr"),
+    ('\u{2905}', "Map"),
+    ('\u{290C}', "lbarr"),
+    ('\u{290D}', "rbarr"),
+    ('\u{290E}', "lBarr"),
+    ('\u{290F}', "rBarr"),
+    ('\u{2910}', "RBarr"),
+    ('\u{2911}', "DDotrahd"),
+    ('\u{2912}', "UpArrowBar"),
+    ('\u{2913}', "DownArrowBar"),
+    ('\u{2916}', "Rarrtl"),
+    ('\u{2919}', "latail"),
+    ('\u{291A}', "ratail"),
+    ('\u{291B}', "lAtail"),
+    ('\u{291C}', "rAtail"),
+    ('\u{291D}', "larrfs"),
+    ('\u{291E}', "rarrfs"),
+    ('\u{291F}', "larrbfs"),
+    ('\u{2920}', "rarrbfs"),
+    ('\u{2923}', "nwarhk"),
+    ('\u{2924}', "nearhk"),
+    ('\u{2925}', "searhk"),
+    ('\u{2926}', "swarhk"),
+    ('\u{2927}', "nwnear"),
+    ('\u{2928}', "toea"),
+    ('\u{2929}', "tosa"),
+    ('\u{292A}', "swnwar"),
+    ('\u{2933}', "rarrc"),
+    ('\u{2935}', "cudarrr"),
+    ('\u{2936}', "ldca"),
+    ('\u{2937}', "rdca"),
+    ('\u{2938}', "cudarrl"),
+    ('\u{2939}', "larrpl"),
+    ('\u{293C}', "curarrm"),
+    ('\u{293D}', "cularrp"),
+    ('\u{2945}', "rarrpl"),
+    ('\u{2948}', "harrcir"),
+    ('\u{2949}', "Uarrocir"),
+    ('\u{294A}', "lurdshar"),
+    ('\u{294B}', "ldrushar"),
+    ('\u{294E}', "LeftRightVector"),
+    ('\u{294F}', "RightUpDownVector"),
+    ('\u{2950}', "DownLeftRightVector"),
+    ('\u{2951}', "LeftUpDownVector"),
+    ('\u{2952}', "LeftVectorBar"),
+    ('\u{2953}', "RightVectorBar"),
+    ('\u{2954}', "RightUpVectorBar"),
+    ('\u{2955}', "RightDownVectorBar"),
+    ('\u{2956}', "DownLeftVectorBar"),
+    ('\u{2957}', "DownRightVectorBar"),
+    ('\u{2958}', "LeftUpVectorBar"),
+    ('\u{2959}', "LeftDownVectorBar"),
+    ('\u{295A}', "LeftTeeVector"),
+    ('\u{295B}', "RightTeeVector"),
+    ('\u{295C}', "RightUpTeeVector"),
+    ('\u{295D}', "RightDownTeeVector"),
+    ('\u{295E}', "DownLeftTeeVector"),
+    ('\u{295F}', "DownRightTeeVector"),
+    ('\u{2960}', "LeftUpTeeVector"),
+    ('\u{2961}', "LeftDownTeeVector"),
+    ('\u{2962}', "lHar"),
+    ('\u{2963}', "uHar"),
+    ('\u{2964}', "rHar"),
+    ('\u{2965}', "dHar"),
+    ('\u{2966}', "luruhar"),
+    ('\u{2967}', "ldrdhar"),
+    ('\u{2968}', "ruluhar"),
+    ('\u{2969}', "rdldhar"),
+    ('\u{296A}', "lharul"),
+    ('\u{296B}', "llhard"),
+    ('\u{296C}', "rharul"),
+    ('\u{296D}', "lrhard"),
+    ('\u{296E}', "udhar"),
+    ('\u{296F}', "duhar"),
+    ('\u{2970}', "RoundImplies"),
+    ('\u{2971}', "erarr"),
+    ('\u{2972}', "simrarr"),
+    ('\u{2973}', "larrsim"),
+    ('\u{2974}', "rarrsim"),
+    ('\u{2975}', "rarrap"),
+    ('\u{2976}', "ltlarr"),
+    ('\u{2978}', "gtrarr"),
+    ('\u{2979}', "subrarr"),
+    ('\u{297B}', "suplarr"),
+    ('\u{297C}', "lfisht"),
+    ('\u{297D}', "rfisht"),
+    ('\u{297E}', "ufisht"),
+    ('\u{297F}', "dfisht"),
+    ('\u{2985}', "lopar"),
+    ('\u{2986}', "ropar"),
+    ('\u{298B}', "lbrke"),
+    ('\u{298C}', "rbrke"),
+    ('\u{298D}', "lbrkslu"),
+    ('\u{298E}', "rbrksld"),
+    ('\u{298F}', "lbrksld"),
+    ('\u{2990}', "rbrkslu"),
+    ('\u{2991}', "langd"),
+    ('\u{2992}', "rangd"),
+    ('\u{2993}', "lparlt"),
+    ('\u{2994}', "rpargt"),
+    ('\u{2995}', "gtlPar"),
+    ('\u{2996}', "ltrPar"),
+    ('\u{299A}', "vzigzag"),
+    ('\u{299C}', "vangrt"),
+    ('\u{299D}', "angrtvbd"),
+    ('\u{29A4}', "ange"),
+    ('\u{29A5}', "range"),
+    ('\u{29A6}', "dwangle"),
+    ('\u{29A7}', "uwangle"),
+    ('\u{29A8}', "angmsdaa"),
+    ('\u{29A9}', "angmsdab"),
+    ('\u{29AA}', "angmsdac"),
+    ('\u{29AB}', "angmsdad"),
+    ('\u{29AC}', "angmsdae"),
+    ('\u{29AD}', "angmsdaf"),
+    ('\u{29AE}', "angmsdag"),
+    ('\u{29AF}', "angmsdah"),
+    ('\u{29B0}', "bemptyv"),
+    ('\u{29B1}', "demptyv"),
+    ('\u{29B2}', "cemptyv"),
+    ('\u{29B3}', "raemptyv"),
+    ('\u{29B4}', "laemptyv"),
+    ('\u{29B5}', "ohbar"),
+    ('\u{29B6}', "omid"),
+    ('\u{29B7}', "opar"),
+    ('\u{29B9}', "operp"),
+    ('\u{29BB}', "olcross"),
+    ('\u{29BC}', "odsold"),
+    ('\u{29BE}', "olcir"),
+    ('\u{29BF}', "ofcir"),
+    ('\u{29C0}', "olt"),
+    ('\u{29C1}', "ogt"),
+    ('\u{29C2}', "cirscir"),
+    ('\u{29C3}', "cirE"),
+    ('\u{29C4}', "solb"),
+    ('\u{29C5}', "bsolb"),
+    ('\u{29C9}', "boxbox"),
+    ('\u{29CD}', "trisb"),
+    ('\u{29CE}', "rtriltri"),
+    ('\u{29CF}', "LeftTriangleBar"),
+    ('\u{29D0}', "RightTriangleBar"),
+    ('\u{29DC}', "iinfin"),
+    ('\u{29DD}', "infintie"),
+    ('\u{29DE}', "nvinfin"),
+    ('\u{29E3}', "eparsl"),
+    ('\u{29E4}', "smeparsl"),
+    ('\u{29E5}', "eqvparsl"),
+    ('\u{29EB}', "lozf"),
+    ('\u{29F4}', "RuleDelayed"),
+    ('\u{29F6}', "dsol"),
+    ('\u{2A00}', "xodot"),
+    ('\u{2A01}', "xoplus"),
+    ('\u{2A02}', "xotime"),
+    ('\u{2A04}', "xuplus"),
+    ('\u{2A06}', "xsqcup"),
+    ('\u{2A0C}', "qint"),
+    ('\u{2A0D}', "fpartint"),
+    ('\u{2A10}', "cirfnint"),
+    ('\u{2A11}', "awint"),
+    ('\u{2A12}', "rppolint"),
+    ('\u{2A13}', "scpolint"),
+    ('\u{2A14}', "npolint"),
+    ('\u{2A15}', "pointint"),
+    ('\u{2A16}', "quatint"),
+    ('\u{2A17}', "intlarhk"),
+    ('\u{2A22}', "pluscir"),
+    ('\u{2A23}', "plusacir"),
+    ('\u{2A24}', "simplus"),
+    ('\u{2A25}', "plusdu"),
+    ('\u{2A26}', "plussim"),
+    ('\u{2A27}', "plustwo"),
+    ('\u{2A29}', "mcomma"),
+    ('\u{2A2A}', "minusdu"),
+    ('\u{2A2D}', "loplus"),
+    ('\u{2A2E}', "roplus"),
+    ('\u{2A2F}', "Cross"),
+    ('\u{2A30}', "timesd"),
+    ('\u{2A31}', "timesbar"),
+    ('\u{2A33}', "smashp"),
+    ('\u{2A34}', "lotimes"),
+    ('\u{2A35}', "rotimes"),
+    ('\u{2A36}', "otimesas"),
+    ('\u{2A37}', "Otimes"),
+    ('\u{2A38}', "odiv"),
+    ('\u{2A39}', "triplus"),
+    ('\u{2A3A}', "triminus"),
+    ('\u{2A3B}', "tritime"),
+    ('\u{2A3C}', "iprod"),
+    ('\u{2A3F}', "amalg"),
+    ('\u{2A40}', "capdot"),
+    ('\u{2A42}', "ncup"),
+    ('\u{2A43}', "ncap"),
+    ('\u{2A44}', "capand"),
+    ('\u{2A45}', "cupor"),
+    ('\u{2A46}', "cupcap"),
+    ('\u{2A47}', "capcup"),
+    ('\u{2A48}', "cupbrcap"),
+    ('\u{2A49}', "capbrcup"),
+    ('\u{2A4A}', "cupcup"),
+    ('\u{2A4B}', "capcap"),
+    ('\u{2A4C}', "ccups"),
+    ('\u{2A4D}', "ccaps"),
+    ('\u{2A50}', "ccupssm"),
+    ('\u{2A53}', "And"),
+    ('\u{2A54}', "Or"),
+    ('\u{2A55}', "andand"),
+    ('\u{2A56}', "oror"),
+    ('\u{2A57}', "orslope"),
+    ('\u{2A58}', "andslope"),
+    ('\u{2A5A}', "andv"),
+    ('\u{2A5B}', "orv"),
+    ('\u{2A5C}', "andd"),
+    ('\u{2A5D}', "ord"),
+    ('\u{2A5F}', "wedbar"),
+    ('\u{2A66}', "sdote"),
+    ('\u{2A6A}', "simdot"),
+    ('\u{2A6D}', "congdot"),
+    ('\u{2A6E}', "easter"),
+    ('\u{2A6F}', "apacir"),
+    ('\u{2A70}', "apE"),
+    ('\u{2A71}', "eplus"),
+    ('\u{2A72}', "pluse"),
+    ('\u{2A73}', "Esim"),
+    ('\u{2A74}', "Colone"),
+    ('\u{2A75}', "Equal"),
+    ('\u{2A77}', "eDDot"),
+    ('\u{2A78}', "equivDD"),
+    ('\u{2A79}', "ltcir"),
+    ('\u{2A7A}', "gtcir"),
+    ('\u{2A7B}', "ltquest"),
+    ('\u{2A7C}', "gtquest"),
+    ('\u{2A7D}', "les"),
+    ('\u{2A7E}', "ges"),
+    ('\u{2A7F}', "lesdot"),
+    ('\u{2A80}', "gesdot"),
+    ('\u{2A81}', "lesdoto"),
+    ('\u{2A82}', "gesdoto"),
+    ('\u{2A83}', "lesdotor"),
+    ('\u{2A84}', "gesdotol"),
+    ('\u{2A85}', "lap"),
+    ('\u{2A86}', "gap"),
+    ('\u{2A87}', "lne"),
+    ('\u{2A88}', "gne"),
+    ('\u{2A89}', "lnap"),
+    ('\u{2A8A}', "gnap"),
+    ('\u{2A8B}', "lEg"),
+    ('\u{2A8C}', "gEl"),
+    ('\u{2A8D}', "lsime"),
+    ('\u{2A8E}', "gsime"),
+    ('\u{2A8F}', "lsimg"),
+    ('\u{2A90}', "gsiml"),
+    ('\u{2A91}', "lgE"),
+    ('\u{2A92}', "glE"),
+    ('\u{2A93}', "lesges"),
+    ('\u{2A94}', "gesles"),
+    ('\u{2A95}', "els"),
+    ('\u{2A96}', "egs"),
+    ('\u{2A97}', "elsdot"),
+    ('\u{2A98}', "egsdot"),
+    ('\u{2A99}', "el"),
+    ('\u{2A9A}', "eg"),
+    ('\u{2A9D}', "siml"),
+    ('\u{2A9E}', "simg"),
+    ('\u{2A9F}', "simlE"),
+    ('\u{2AA0}', "simgE"),
+    ('\u{2AA1}', "LessLess"),
+    ('\u{2AA2}', "GreaterGreater"),
+    ('\u{2AA4}', "glj"),
+    ('\u{2AA5}', "gla"),
+    ('\u{2AA6}', "ltcc"),
+    ('\u{2AA7}', "gtcc"),
+    ('\u{2AA8}', "lescc"),
+    ('\u{2AA9}', "gescc"),
+    ('\u{2AAA}', "smt"),
+    ('\u{2AAB}', "lat"),
+    ('\u{2AAC}', "smte"),
+    ('\u{2AAD}', "late"),
+    ('\u{2AAE}', "bumpE"),
+    ('\u{2AAF}', "pre"),
+    ('\u{2AB0}', "sce"),
+    ('\u{2AB3}', "prE"),
+    ('\u{2AB4}', "scE"),
+    ('\u{2AB5}', "prnE"),
+    ('\u{2AB6}', "scnE"),
+    ('\u{2AB7}', "prap"),
+    ('\u{2AB8}', "scap"),
+    ('\u{2AB9}', "prnap"),
+    ('\u{2ABA}', "scnap"),
+    ('\u{2ABB}', "Pr"),
+    ('\u{2ABC}', "Sc"),
+    ('\u{2ABD}', "subdot"),
+    ('\u{2ABE}', "supdot"),
+    ('\u{2ABF}', "subplus"),
+    ('\u{2AC0}', "supplus"),
+    ('\u{2AC1}', "submult"),
+    ('\u{2AC2}', "supmult"),
+    ('\u{2AC3}', "subedot"),
+    ('\u{2AC4}', "supedot"),
+    ('\u{2AC5}', "subE"),
+    ('\u{2AC6}', "supE"),
+    ('\u{2AC7}', "subsim"),
+    ('\u{2AC8}', "supsim"),
+    ('\u{2ACB}', "subnE"),
+    ('\u{2ACC}', "supnE"),
+    ('\u{2ACF}', "csub"),
+    ('\u{2AD0}', "csup"),
+    ('\u{2AD1}', "csube"),
+    ('\u{2AD2}', "csupe"),
+    ('\u{2AD3}', "subsup"),
+    ('\u{2AD4}', "supsub"),
+    ('\u{2AD5}', "subsub"),
+    ('\u{2AD6}', "supsup"),
+    ('\u{2AD7}', "suphsub"),
+    ('\u{2AD8}', "supdsub"),
+    ('\u{2AD9}', "forkv"),
+    ('\u{2ADA}', "topfork"),
+    ('\u{2ADB}', "mlcp"),
+    ('\u{2AE4}', "Dashv"),
+    ('\u{2AE6}', "Vdashl"),
+    ('\u{2AE7}', "Barv"),
+    ('\u{2AE8}', "vBar"),
+    ('\u{2AE9}', "vBarv"),
+    ('\u{2AEB}', "Vbar"),
+    ('\u{2AEC}', "Not"),
+    ('\u{2AED}', "bNot"),
+    ('\u{2AEE}', "rnmid"),
+    ('\u{2AEF}', "cirmid"),
+    ('\u{2AF0}', "midcir"),
+    ('\u{2AF1}', "topcir"),
+    ('\u{2AF2}', "nhpar"),
+    ('\u{2AF3}', "parsim"),
+    ('\u{2AFD}', "parsl"),
+    ('\u{FB00}', "fflig"),
+    ('\u{FB01}', "filig"),
+    ('\u{FB02}', "fllig"),
+    ('\u{FB03}', "ffilig"),
+    ('\u{FB04}', "ffllig"),
+    ('\u{1D49C}', "Ascr"),
+    ('\u{1D49E}', "Cscr"),
+    ('\u{1D49F}', "Dscr"),
+    ('\u{1D4A2}', "Gscr"),
+    ('\u{1D4A5}', "Jscr"),
+    ('\u{1D4A6}', "Kscr"),
+    ('\u{1D4A9}', "Nscr"),
+    ('\u{1D4AA}', "Oscr"),
+    ('\u{1D4AB}', "Pscr"),
+    ('\u{1D4AC}', "Qscr"),
+    ('\u{1D4AE}', "Sscr"),
+    ('\u{1D4AF}', "Tscr"),
+    ('\u{1D4B0}', "Uscr"),
+    ('\u{1D4B1}', "Vscr"),
+    ('\u{1D4B2}', "Wscr"),
+    ('\u{1D4B3}', "Xscr"),
+    ('\u{1D4B4}', "Yscr"),
+    ('\u{1D4B5}', "Zscr"),
+    ('\u{1D4B6}', "ascr"),
+    ('\u{1D4B7}', "bscr"),
+    ('\u{1D4B8}', "cscr"),
+    ('\u{1D4B9}', "dscr"),
+    ('\u{1D4BB}', "fscr"),
+    ('\u{1D4BD}', "hscr"),
+    ('\u{1D4BE}', "iscr"),
+    ('\u{1D4BF}', "jscr"),
+    ('\u{1D4C0}', "kscr"),
+    ('\u{1D4C1}', "lscr"),
+    ('\u{1D4C2}', "mscr"),
+    ('\u{1D4C3}', "nscr"),
+    ('\u{1D4C5}', "pscr"),
+    ('\u{1D4C6}', "qscr"),
+    ('\u{1D4C7}', "rscr"),
+    ('\u{1D4C8}', "sscr"),
+    ('\u{1D4C9}', "tscr"),
+    ('\u{1D4CA}', "uscr"),
+    ('\u{1D4CB}', "vscr"),
+    ('\u{1D4CC}', "wscr"),
+    ('\u{1D4CD}', "xscr"),
+    ('\u{1D4CE}', "yscr"),
+    ('\u{1D4CF}', "zscr"),
+    ('\u{1D504}', "Afr"),
+    ('\u{1D505}', "Bfr"),
+    ('\u{1D507}', "Dfr"),
+    ('\u{1D508}', "Efr"),
+    ('\u{1D509}', "Ffr"),
+    ('\u{1D50A}', "Gfr"),
+    ('\u{1D50D}', "Jfr"),
+    ('\u{1D50E}', "Kfr"),
+    ('\u{1D50F}', "Lfr"),
+    ('\u{1D510}', "Mfr"),
+    ('\u{1D511}', "Nfr"),
+    ('\u{1D512}', "Ofr"),
+    ('\u{1D513}', "Pfr"),
+    ('\u{1D514}', "Qfr"),
+    ('\u{1D516}', "Sfr"),
+    ('\u{1D517}', "Tfr"),
+    ('\u{1D518}', "Ufr"),
+    ('\u{1D519}', "Vfr"),
+    ('\u{1D51A}', "Wfr"),
+    ('\u{1D51B}', "Xfr"),
+    ('\u{1D51C}', "Yfr"),
+    ('\u{1D51E}', "afr"),
+    ('\u{1D51F}', "bfr"),
+    ('\u{1D520}', "cfr"),
+    ('\u{1D521}', "dfr"),
+    ('\u{1D522}', "efr"),
+    ('\u{1D523}', "ffr"),
+    ('\u{1D524}', "gfr"),
+    ('\u{1D525}', "hfr"),
+    ('\u{1D526}', "ifr"),
+    ('\u{1D527}', "jfr"),
+    ('\u{1D528}', "kfr"),
+    ('\u{1D529}', "lfr"),
+    ('\u{1D52A}', "mfr"),
+    ('\u{1D52B}', "nfr"),
+    ('\u{1D52C}', "ofr"),
+    ('\u{1D52D}', "pfr"),
+    ('\u{1D52E}', "qfr"),
+    ('\u{1D52F}', "rfr"),
+    ('\u{1D530}', "sfr"),
+    ('\u{1D531}', "tfr"),
+    ('\u{1D532}', "ufr"),
+    ('\u{1D533}', "vfr"),
+    ('\u{1D534}', "wfr"),
+    ('\u{1D535}', "xfr"),
+    ('\u{1D536}', "yfr"),
+    ('\u{1D537}', "zfr"),
+    ('\u{1D538}', "Aopf"),
+    ('\u{1D539}', "Bopf"),
+    ('\u{1D53B}', "Dopf"),
+    ('\u{1D53C}', "Eopf"),
+    ('\u{1D53D}', "Fopf"),
+    ('\u{1D53E}', "Gopf"),
+    ('\u{1D540}', "Iopf"),
+    ('\u{1D541}', "Jopf"),
+    ('\u{1D542}', "Kopf"),
+    ('\u{1D543}', "Lopf"),
+    ('\u{1D544}', "Mopf"),
+    ('\u{1D546}', "Oopf"),
+    ('\u{1D54A}', "Sopf"),
+    ('\u{1D54B}', "Topf"),
+    ('\u{1D54C}', "Uopf"),
+    ('\u{1D54D}', "Vopf"),
+    ('\u{1D54E}', "Wopf"),
+    ('\u{1D54F}', "Xopf"),
+    ('\u{1D550}', "Yopf"),
+    ('\u{1D552}', "aopf"),
+    ('\u{1D553}', "bopf"),
+    ('\u{1D554}', "copf"),
+    ('\u{1D555}', "dopf"),
+    ('\u{1D556}', "eopf"),
+    ('\u{1D557}', "fopf"),
+    ('\u{1D558}', "gopf"),
+    ('\u{1D559}', "hopf"),
+    ('\u{1D55A}', "iopf"),
+    ('\u{1D55B}', "jopf"),
+    ('\u{1D55C}', "kopf"),
+    ('\u{1D55D}', "lopf"),
+    ('\u{1D55E}', "mopf"),
+    ('\u{1D55F}', "nopf"),
+    ('\u{1D560}', "oopf"),
+    ('\u{1D561}', "popf"),
+    ('\u{1D562}', "qopf"),
+    ('\u{1D563}', "ropf"),
+    ('\u{1D564}', "sopf"),
+    ('\u{1D565}', "topf"),
+    ('\u{1D566}', "uopf"),
+    ('\u{1D567}', "vopf"),
+    ('\u{1D568}', "wopf"),
+    ('\u{1D569}', "xopf"),
+    ('\u{1D56A}', "yopf"),
+    ('\u{1D56B}', "zopf"),
+];
@@ -0,0 +1,503 @@
+use crate::unicode;
+use unicode_segmentation::UnicodeSegmentation;
+use yew::prelude::*;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kind {
+    Bidi,
+    ZeroWidth,
+    Tag,
+    SoftHyphen,
+    VariationSelector,
+    Control,
+    Ignorable,
+}
+
+const KINDS: &[Kind] = &[
+    Kind::Bidi,
+    Kind::ZeroWidth,
+    Kind::Tag,
+    Kind::SoftHyphen,
+    Kind::VariationSelector,
+    Kind::Control,
+    Kind::Ignorable,
+];
+
+impl Kind {
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Bidi => "Bidi controls",
+            Kind::ZeroWidth => "Zero-width characters",
+            Kind::Tag => "Tag characters",
+            Kind::SoftHyphen => "Soft hyphens",
+            Kind::VariationSelector => "Variation selectors",
+            Kind::Control => "Control characters",
+            Kind::Ignorable => "Other default-ignorables",
+        }
+    }
+}
+
+/// Classifies characters that render as nothing or change how their
+/// neighbours render. Tab, LF and CR are not reported.
+pub fn classify(c: char) -> Option<Kind> {
+    Some(match c {
+        '\u{061C}'
+        | '\u{200E}'
+        | '\u{200F}'
+        | '\u{202A}'..='\u{202E}'
+        | '\u{2066}'..='\u{2069}' => Kind::Bidi,
+        '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' => Kind::ZeroWidth,
+        '\u{E0000}'..='\u{E007F}' => Kind::Tag,
+        '\u{00AD}' => Kind::SoftHyphen,
+        '\u{180B}'..='\u{180D}'
+        | '\u{180F}'
+        | '\u{FE00}'..='\u{FE0F}'
+        | '\u{E0100}'..='\u{E01EF}' => Kind::VariationSelector,
+        '\t' | '\n' | '\r' => return None,
+        '\u{0}'..='\u{1F}' | '\u{7F}'..='\u{9F}' => Kind::Control,
+        // The rest of Default_Ignorable_Code_Point.
+        '\u{034F}'
+        | '\u{115F}'
+        | '\u{1160}'
+        | '\u{17B4}'
+        | '\u{17B5}'
+        | '\u{180E}'
+        | '\u{2061}'..='\u{2065}'
+        | '\u{206A}'..='\u{206F}'
+        | '\u{3164}'
+        | '\u{FFA0}'
+        | '\u{FFF0}'..='\u{FFF8}'
+        | '\u{1BCA0}'..='\u{1BCA3}'
+        | '\u{1D173}'..='\u{1D17A}'
+        | '\u{E0080}'..='\u{E00FF}'
+        | '\u{E01F0}'..='\u{E0FFF}' => Kind::Ignorable,
+        _ => return None,
+    })
+}
+
+/// A short label for the badge shown in place of `c`.
+fn abbreviation(c: char) -> String {
+    match c {
+        '\u{00AD}' => "SHY".to_string(),
+        '\u{034F}' => "CGJ".to_string(),
+        '\u{061C}' => "ALM".to_string(),
+        '\u{180E}' => "MVS".to_string(),
+        '\u{200B}' => "ZWSP".to_string(),
+        '\u{200C}' => "ZWNJ".to_string(),
+        '\u{200D}' => "ZWJ".to_string(),
+        '\u{200E}' => "LRM".to_string(),
+        '\u{200F}' => "RLM".to_string(),
+        '\u{202A}' => "LRE".to_string(),
+        '\u{202B}' => "RLE".to_string(),
+        '\u{202C}' => "PDF".to_string(),
+        '\u{202D}' => "LRO".to_string(),
+        '\u{202E}' => "RLO".to_string(),
+        '\u{2060}' => "WJ".to_string(),
+        '\u{2066}' => "LRI".to_string(),
+        '\u{2067}' => "RLI".to_string(),
+        '\u{2068}' => "FSI".to_string(),
+        '\u{2069}' => "PDI".to_string(),
+        '\u{FEFF}' => "BOM".to_string(),
+        '\u{FE00}'..='\u{FE0F}' => format!("VS{}", c as u32 - 0xFE00 + 1),
+        '\u{E0100}'..='\u{E01EF}' => format!("VS{}", c as u32 - 0xE0100 + 17),
+        '\u{E0021}'..='\u{E007E}' => format!("TAG {}", tag_ascii(c).unwrap()),
+        '\u{E0020}' => "TAG SP".to_string(),
+        _ => format!("U+{:04X}", c as u32),
+    }
+}
+
+/// The ASCII character a tag character mirrors, which is how text can be
+/// smuggled invisibly.
+fn tag_ascii(c: char) -> Option<char> {
+    match c {
+        '\u{E0020}'..='\u{E007E}' => char::from_u32(c as u32 - 0xE0000),
+        _ => None,
+    }
+}
+
+fn is_pictographic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00A9}'
+            | '\u{00AE}'
+            | '\u{203C}'
+            | '\u{2049}'
+            | '\u{2122}'
+            | '\u{2139}'
+            | '\u{2194}'..='\u{21AA}'
+            | '\u{231A}'..='\u{23FF}'
+            | '\u{24C2}'
+            | '\u{25AA}'..='\u{27BF}'
+            | '\u{2934}'
+            | '\u{2935}'
+            | '\u{2B05}'..='\u{2B55}'
+            | '\u{3030}'
+            | '\u{303D}'
+            | '\u{3297}'
+            | '\u{3299}'
+            | '\u{1F000}'..='\u{1FAFF}'
+    )
+}
+
+/// Which characters of the grapheme `chars` are a well-formed part of an
+/// emoji sequence: VS15 or VS16 right after a pictograph or keycap base,
+/// ZWJ between two pictographs, and the tags of a flag, which are U+1F3F4
+/// followed by E0020-E007E and ended by E007F. Tags and other selectors
+/// also join any emoji grapheme, so anything else is still reported.
+fn emoji_expected(chars: &[char]) -> Vec<bool> {
+    let mut expected = vec![false; chars.len()];
+    let first = match chars.first() {
+        Some(&c) => c,
+        None => return expected,
+    };
+    let keycap = matches!(first, '0'..='9' | '#' | '*') && chars.contains(&'\u{20E3}');
+    if !keycap && !is_pictographic(first) {
+        return expected;
+    }
+
+    if first == '\u{1F3F4}' {
+        let tags = chars[1..]
+            .iter()
+            .take_while(|&&c| ('\u{E0020}'..='\u{E007E}').contains(&c))
+            .count();
+        if tags > 0 && chars.get(tags + 1) == Some(&'\u{E007F}') {
+            expected[1..tags + 2].iter_mut().for_each(|e| *e = true);
+        }
+    }
+    for i in 1..chars.len() {
+        let prev = chars[i - 1];
+        expected[i] |= match chars[i] {
+            '\u{FE0E}' | '\u{FE0F}' => is_pictographic(prev) || (i == 1 && keycap),
+            '\u{200D}' => {
+                let before = if prev == '\u{FE0F}' && i >= 2 {
+                    chars[i - 2]
+                } else {
+                    prev
+                };
+                is_pictographic(before) && chars.get(i + 1).is_some_and(|&c| is_pictographic(c))
+            }
+            _ => false,
+        };
+    }
+    expected
+}
+
+/// The characters of the grapheme `g`, each with its kind if it should be
+/// reported. With `allow_emoji`, the parts of a well-formed emoji sequence
+/// are not.
+fn flag(g: &str, allow_emoji: bool) -> Vec<(char, Option<Kind>)> {
+    let chars: Vec<char> = g.chars().collect();
+    let expected = if allow_emoji {
+        emoji_expected(&chars)
+    } else {
+        vec![false; chars.len()]
+    };
+    chars
+        .into_iter()
+        .zip(expected)
+        .map(|(c, expected)| (c, if expected { None } else { classify(c) }))
+        .collect()
+}
+
+pub struct Finding {
+    pub line: usize,
+    pub column: usize,
+    pub c: char,
+    pub kind: Kind,
+}
+
+/// Finds suspicious characters, with 1-based line and column numbers.
+/// With `allow_emoji`, joiners, variation selectors and tags that form a
+/// valid emoji sequence are skipped.
+pub fn find(text: &str, allow_emoji: bool) -> Vec<Finding> {
+    let mut findings = vec![];
+    let mut line = 1;
+    let mut column = 1;
+    for g in text.graphemes(true) {
+        for (c, kind) in flag(g, allow_emoji) {
+            if let Some(kind) = kind {
+                findings.push(Finding {
+                    line,
+                    column,
+                    c,
+                    kind,
+                });
+            }
+            if c == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+    }
+    findings
+}
+
+/// Lines where an embedding, override or isolate is still open at the end
+/// of the line, so that it reorders the code after it.
+pub fn unterminated_bidi(text: &str) -> Vec<usize> {
+    let mut lines = vec![];
+    for (i, line) in text.split('\n').enumerate() {
+        let mut embeddings = 0usize;
+        let mut isolates = 0usize;
+        for c in line.chars() {
+            match c {
+                '\u{202A}'..='\u{202B}' | '\u{202D}'..='\u{202E}' => embeddings += 1,
+                '\u{202C}' => embeddings = embeddings.saturating_sub(1),
+                '\u{2066}'..='\u{2068}' => isolates += 1,
+                '\u{2069}' => isolates = isolates.saturating_sub(1),
+                _ => {}
+            }
+        }
+        if embeddings > 0 || isolates > 0 {
+            lines.push(i + 1);
+        }
+    }
+    lines
+}
+
+pub fn strip(text: &str, allow_emoji: bool) -> String {
+    text.graphemes(true)
+        .flat_map(|g| flag(g, allow_emoji))
+        .filter(|(_, kind)| kind.is_none())
+        .map(|(c, _)| c)
+        .collect()
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    input: String,
+    #[prop_or(true)]
+    allow_emoji: bool,
+}
+
+pub enum Msg {
+    Input(String),
+    AllowEmoji(bool),
+    Strip,
+}
+
+impl Model {
+    /// The input with each reported character replaced by a badge.
+    fn view_badges(&self) -> Vec<Html> {
+        let mut nodes = vec![];
+        let mut run = String::new();
+        for g in self.props.input.graphemes(true) {
+            for (c, kind) in flag(g, self.props.allow_emoji) {
+                if kind.is_none() {
+                    run.push(c);
+                    continue;
+                }
+                if !run.is_empty() {
+                    nodes.push(html! { { std::mem::take(&mut run) } });
+                }
+                nodes.push(html! {
+                    <span class="tag is-danger is-light" title=format!("U+{:04X} {}", c as u32, unicode::name(c))>{ abbreviation(c) }</span>
+                });
+            }
+        }
+        if !run.is_empty() {
+            nodes.push(html! { { run } });
+        }
+        nodes
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(txt) => self.props.input = txt,
+            Msg::AllowEmoji(b) => self.props.allow_emoji = b,
+            Msg::Strip => self.props.input = strip(&self.props.input, self.props.allow_emoji),
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let props = &self.props;
+        let findings = find(&props.input, props.allow_emoji);
+        let unterminated = unterminated_bidi(&props.input);
+        let allow_emoji = props.allow_emoji;
+        let hidden_tags: String = findings.iter().filter_map(|f| tag_ascii(f.c)).collect();
+
+        html! {
+            <>
+            <div class="field">
+                <label class="label">{"Input"}</label>
+                <div class="control">
+                    <textarea class="textarea" value=&props.input
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))/>
+                </div>
+            </div>
+            <div class="field is-grouped">
+                <div class="control">
+                    <button class="button is-danger" disabled=findings.is_empty()
+                        onclick=self.link.callback(|_| Msg::Strip)>{"Strip them"}</button>
+                </div>
+                <div class="control">
+                    <label class="checkbox">
+                        <input type="checkbox" checked=allow_emoji
+                            onchange=self.link.callback(move |_| Msg::AllowEmoji(!allow_emoji))/>
+                        {" Allow joiners, variation selectors and tags that form valid emoji"}
+                    </label>
+                </div>
+            </div>
+
+            <div class="tags">
+            {
+                for KINDS.iter().map(|&kind| {
+                    let n = findings.iter().filter(|f| f.kind == kind).count();
+                    let classes = if n == 0 { "tag" } else { "tag is-danger" };
+                    html! {
+                        <span class=classes>{ format!("{}: {}", kind.name(), n) }</span>
+                    }
+                })
+            }
+            </div>
+            {
+                if unterminated.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <p class="help is-danger">{ format!("Bidi embeddings or isolates left open at the end of line {}", join(&unterminated)) }</p>
+                    }
+                }
+            }
+            {
+                if hidden_tags.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <p class="help is-danger">{"Text hidden in tag characters: "}<code>{ hidden_tags }</code></p>
+                    }
+                }
+            }
+
+            <div class="box" style="white-space: pre-wrap; word-break: break-all; margin-top: 1em;">
+            { for self.view_badges() }
+            </div>
+
+            <table class="table is-narrow is-fullwidth">
+                <thead>
+                    <tr>
+                        <th>{"Line"}</th>
+                        <th>{"Column"}</th>
+                        <th>{"Code point"}</th>
+                        <th>{"Name"}</th>
+                        <th>{"Kind"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                {
+                    for findings.iter().map(|f| html! {
+                        <tr>
+                            <td>{ f.line }</td>
+                            <td>{ f.column }</td>
+                            <td><code>{ format!("U+{:04X}", f.c as u32) }</code></td>
+                            <td>{ unicode::name(f.c) }</td>
+                            <td>{ f.kind.name() }</td>
+                        </tr>
+                    })
+                }
+                </tbody>
+            </table>
+            </>
+        }
+    }
+}
+
+fn join(lines: &[usize]) -> String {
+    lines
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "a\u{200B}b\n\u{202E}x \u{1F468}\u{200D}\u{1F469} 1\u{FE0F}\u{20E3}";
+
+    #[test]
+    fn finds_invisible_characters_with_positions() {
+        let findings = find(TEXT, true);
+        let found: Vec<(usize, usize, char)> =
+            findings.iter().map(|f| (f.line, f.column, f.c)).collect();
+        assert_eq!(found, vec![(1, 2, '\u{200B}'), (2, 1, '\u{202E}')]);
+    }
+
+    #[test]
+    fn emoji_joiners_are_flagged_unless_allowed() {
+        let chars: Vec<char> = find(TEXT, false).iter().map(|f| f.c).collect();
+        assert_eq!(chars, vec!['\u{200B}', '\u{202E}', '\u{200D}', '\u{FE0F}']);
+    }
+
+    #[test]
+    fn strip_keeps_emoji_sequences() {
+        assert_eq!(
+            strip(TEXT, true),
+            "ab\nx \u{1F468}\u{200D}\u{1F469} 1\u{FE0F}\u{20E3}"
+        );
+        assert_eq!(strip(TEXT, false), "ab\nx \u{1F468}\u{1F469} 1\u{20E3}");
+    }
+
+    #[test]
+    fn tags_and_selectors_smuggled_after_an_emoji_are_reported() {
+        let text = "ok \u{1F600}\u{E0068}\u{E0069}\u{E0100}\u{E0101} done";
+        let chars: Vec<char> = find(text, true).iter().map(|f| f.c).collect();
+        assert_eq!(
+            chars,
+            vec!['\u{E0068}', '\u{E0069}', '\u{E0100}', '\u{E0101}']
+        );
+        assert_eq!(strip(text, true), "ok \u{1F600} done");
+    }
+
+    #[test]
+    fn well_formed_emoji_sequences_are_allowed() {
+        let england = "\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}";
+        let rainbow = "\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}";
+        let technologist = "\u{1F469}\u{1F3FD}\u{200D}\u{1F4BB}";
+        for text in &[
+            england,
+            rainbow,
+            technologist,
+            "#\u{FE0F}\u{20E3}",
+            "\u{2764}\u{FE0E}",
+        ] {
+            assert!(find(text, true).is_empty(), "{}", text);
+            assert!(!find(text, false).is_empty(), "{}", text);
+        }
+        // A flag without its cancel tag, or with more tags after it.
+        assert_eq!(find("\u{1F3F4}\u{E0067}\u{E0062}", true).len(), 2);
+        assert_eq!(find(&format!("{}\u{E0041}", england), true).len(), 1);
+        // A ZWJ at the end of an emoji joins nothing.
+        assert_eq!(find("\u{1F600}\u{200D}", true).len(), 1);
+    }
+
+    #[test]
+    fn unterminated_bidi_controls() {
+        assert_eq!(unterminated_bidi(TEXT), vec![2]);
+        assert!(unterminated_bidi("\u{202E}x\u{202C} \u{2067}y\u{2069}").is_empty());
+        assert_eq!(unterminated_bidi("ok\n\u{2066}\nok\n\u{202B}"), vec![2, 4]);
+    }
+}
@@ -0,0 +1,405 @@
+use crate::kyujitai::KYUJITAI;
+use crate::unicode::east_asian_width;
+use unicode_normalization::char::{compose, decompose_canonical};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Squares per line and lines per page of 400字詰め原稿用紙.
+pub const MANUSCRIPT_COLUMNS: usize = 20;
+pub const MANUSCRIPT_ROWS: usize = 20;
+
+/// Characters that must not begin a line (行頭禁則). On manuscript paper
+/// they hang in the last square of the previous line instead.
+const NO_LINE_START: &str = "、。，．,.）)」』】〕〉》〙〗］]｝}’”";
+
+/// Characters that must not end a line (行末禁則); they move to the next
+/// line, leaving the last square empty.
+const NO_LINE_END: &str = "（(「『【〔〈《〘〖［[｛{‘“";
+
+pub fn is_hiragana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{309F}')
+}
+
+/// Katakana, including the prolonged sound mark ー and half-width katakana
+/// but not the middle dot ・, which is punctuation.
+pub fn is_katakana(c: char) -> bool {
+    matches!(c, '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}')
+        && c != '・'
+}
+
+/// CJK ideographs, including the iteration mark 々 and 〆.
+pub fn is_kanji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3005}'
+            | '\u{3006}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{3FFFF}'
+    )
+}
+
+/// ASCII punctuation, general punctuation such as dashes and quotes, CJK
+/// punctuation and brackets, and their full-width and half-width forms.
+pub fn is_punctuation(c: char) -> bool {
+    c.is_ascii_punctuation()
+        || matches!(
+            c,
+            '\u{2010}'..='\u{205E}'
+                | '\u{3001}'..='\u{3003}'
+                | '\u{3008}'..='\u{3011}'
+                | '\u{3014}'..='\u{301F}'
+                | '\u{30FB}'
+                | '\u{FF01}'..='\u{FF0F}'
+                | '\u{FF1A}'..='\u{FF20}'
+                | '\u{FF3B}'..='\u{FF40}'
+                | '\u{FF5B}'..='\u{FF65}'
+        )
+}
+
+/// Whether `c` takes two columns in a Japanese font: its East Asian Width
+/// is Wide, Fullwidth or Ambiguous, so ○ and ★ are full-width but
+/// half-width katakana are not.
+pub fn is_fullwidth(c: char) -> bool {
+    east_asian_width(c).columns(true) == 2
+}
+
+#[derive(Clone, Default)]
+pub struct Stats {
+    pub hiragana: usize,
+    pub katakana: usize,
+    pub kanji: usize,
+    pub fullwidth: usize,
+    pub halfwidth: usize,
+    pub punctuation: usize,
+}
+
+impl Stats {
+    /// Counts code points by kind. Whitespace and control characters count
+    /// as neither full-width nor half-width.
+    pub fn new(text: &str) -> Self {
+        let mut stats = Self::default();
+        for c in text.chars() {
+            if is_hiragana(c) {
+                stats.hiragana += 1;
+            }
+            if is_katakana(c) {
+                stats.katakana += 1;
+            }
+            if is_kanji(c) {
+                stats.kanji += 1;
+            }
+            if is_punctuation(c) {
+                stats.punctuation += 1;
+            }
+            if c.is_whitespace() || c.is_control() {
+                continue;
+            }
+            if is_fullwidth(c) {
+                stats.fullwidth += 1;
+            } else {
+                stats.halfwidth += 1;
+            }
+        }
+        stats
+    }
+}
+
+/// Counts the lines `text` takes on manuscript paper with `columns`
+/// squares per line. Each paragraph starts on a new line and each
+/// character takes one square, with 禁則 applied: closing brackets and
+/// 句読点 hang at the end of a full line, and an opening bracket never
+/// takes the last square.
+pub fn manuscript_lines(text: &str, columns: usize) -> usize {
+    let mut lines = 0;
+    for paragraph in text.lines() {
+        lines += 1;
+        let mut column = 0;
+        for g in paragraph.graphemes(true) {
+            let hangs = NO_LINE_START.contains(g);
+            if column == columns && !hangs || column == columns - 1 && NO_LINE_END.contains(g) {
+                lines += 1;
+                column = 0;
+            }
+            if column < columns {
+                column += 1;
+            }
+        }
+    }
+    lines
+}
+
+/// Half-width katakana U+FF66 to U+FF9D and their full-width forms.
+const HALFWIDTH_KATAKANA: &str = "ｦｧｨｩｪｫｬｭｮｯｰｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜﾝ";
+const FULLWIDTH_KATAKANA: &str = "ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+
+/// Half-width CJK punctuation and their full-width forms.
+const HALFWIDTH_SYMBOLS: &[(char, char)] = &[
+    ('｡', '。'),
+    ('｢', '「'),
+    ('｣', '」'),
+    ('､', '、'),
+    ('･', '・'),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Width {
+    Keep,
+    Full,
+    Half,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kana {
+    Keep,
+    Hiragana,
+    Katakana,
+}
+
+/// Which conversions to apply. Each kind of character is converted to
+/// full-width or half-width independently.
+#[derive(Clone, Copy)]
+pub struct Conversion {
+    pub alphanumerics: Width,
+    pub symbols: Width,
+    pub spaces: Width,
+    pub katakana: Width,
+    pub kana: Kana,
+    pub shinjitai: bool,
+}
+
+impl Conversion {
+    pub fn apply(&self, text: &str) -> String {
+        let mut text = if self.shinjitai {
+            to_shinjitai(text)
+        } else {
+            text.to_string()
+        };
+        match self.kana {
+            Kana::Keep => {}
+            Kana::Hiragana => text = to_hiragana(&text),
+            Kana::Katakana => text = to_katakana(&text),
+        }
+        match self.katakana {
+            Width::Keep => {}
+            Width::Full => text = fullwidth_katakana(&text),
+            Width::Half => text = halfwidth_katakana(&text),
+        }
+        text.chars()
+            .map(|c| {
+                let width = if c.is_ascii_alphanumeric()
+                    || matches!(c, '０'..='９' | 'Ａ'..='Ｚ' | 'ａ'..='ｚ')
+                {
+                    self.alphanumerics
+                } else if c == ' ' || c == '\u{3000}' {
+                    self.spaces
+                } else {
+                    self.symbols
+                };
+                match width {
+                    Width::Keep => c,
+                    Width::Full => fullwidth(c),
+                    Width::Half => halfwidth(c),
+                }
+            })
+            .collect()
+    }
+}
+
+/// The full-width form of an ASCII character, a space or half-width
+/// punctuation. Half-width katakana are left to `fullwidth_katakana`.
+fn fullwidth(c: char) -> char {
+    match c {
+        ' ' => '\u{3000}',
+        '!'..='~' => char::from_u32(c as u32 + 0xFEE0).unwrap(),
+        _ => HALFWIDTH_SYMBOLS
+            .iter()
+            .find(|&&(half, _)| half == c)
+            .map_or(c, |&(_, full)| full),
+    }
+}
+
+fn halfwidth(c: char) -> char {
+    match c {
+        '\u{3000}' => ' ',
+        '！'..='～' => char::from_u32(c as u32 - 0xFEE0).unwrap(),
+        _ => HALFWIDTH_SYMBOLS
+            .iter()
+            .find(|&&(_, full)| full == c)
+            .map_or(c, |&(half, _)| half),
+    }
+}
+
+/// Converts half-width katakana to full-width, composing a following
+/// voiced or semi-voiced sound mark: ｶﾞ becomes ガ and ｳﾞ becomes ヴ.
+pub fn fullwidth_katakana(text: &str) -> String {
+    let mut ret = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let base = match HALFWIDTH_KATAKANA.chars().position(|h| h == c) {
+            Some(i) => FULLWIDTH_KATAKANA.chars().nth(i).unwrap(),
+            None => {
+                ret.push(match c {
+                    'ﾞ' => '゛',
+                    'ﾟ' => '゜',
+                    _ => c,
+                });
+                continue;
+            }
+        };
+        let mark = match chars.peek() {
+            Some('ﾞ') | Some('\u{3099}') => Some('\u{3099}'),
+            Some('ﾟ') | Some('\u{309A}') => Some('\u{309A}'),
+            _ => None,
+        };
+        match mark.and_then(|mark| compose(base, mark)) {
+            Some(composed) => {
+                chars.next();
+                ret.push(composed);
+            }
+            None => ret.push(base),
+        }
+    }
+    ret
+}
+
+/// Converts full-width katakana to half-width, splitting off the voiced
+/// and semi-voiced sound marks. Katakana without a half-width form, such as
+/// ヶ and ヰ, are kept.
+pub fn halfwidth_katakana(text: &str) -> String {
+    let half = |c: char| {
+        FULLWIDTH_KATAKANA
+            .chars()
+            .position(|f| f == c)
+            .and_then(|i| HALFWIDTH_KATAKANA.chars().nth(i))
+    };
+    let mut ret = String::new();
+    // A combining mark is only converted right after a converted katakana,
+    // so that a hiragana with a combining dakuten stays as it is.
+    let mut after_katakana = false;
+    for c in text.chars() {
+        let mut base = c;
+        let mut mark = None;
+        decompose_canonical(c, |d| match d {
+            '\u{3099}' => mark = Some('ﾞ'),
+            '\u{309A}' => mark = Some('ﾟ'),
+            _ => base = d,
+        });
+        if let Some(h) = half(base) {
+            ret.push(h);
+            ret.extend(mark);
+            after_katakana = true;
+            continue;
+        }
+        ret.push(match c {
+            '゛' => 'ﾞ',
+            '゜' => 'ﾟ',
+            '\u{3099}' if after_katakana => 'ﾞ',
+            '\u{309A}' if after_katakana => 'ﾟ',
+            _ => c,
+        });
+        after_katakana = false;
+    }
+    ret
+}
+
+pub fn to_katakana(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{3041}'..='\u{3096}' | 'ゝ' | 'ゞ' => char::from_u32(c as u32 + 0x60).unwrap(),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Converts katakana, including half-width katakana, to hiragana. ヷ, ヸ, ヹ
+/// and ヺ have no precomposed hiragana and get a combining dakuten.
+pub fn to_hiragana(text: &str) -> String {
+    let mut ret = String::new();
+    for c in fullwidth_katakana(text).chars() {
+        match c {
+            '\u{30A1}'..='\u{30F6}' | 'ヽ' | 'ヾ' => {
+                ret.push(char::from_u32(c as u32 - 0x60).unwrap())
+            }
+            'ヷ'..='ヺ' => {
+                ret.push(char::from_u32(c as u32 - 0x30F7 + 'わ' as u32).unwrap());
+                ret.push('\u{3099}');
+            }
+            _ => ret.push(c),
+        }
+    }
+    ret
+}
+
+/// Replaces 旧字体 with 新字体, including those encoded as CJK
+/// compatibility ideographs.
+pub fn to_shinjitai(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            let mut c = c;
+            if ('\u{F900}'..='\u{FAFF}').contains(&c) {
+                decompose_canonical(c, |d| c = d);
+            }
+            match KYUJITAI.binary_search_by_key(&c, |&(old, _)| old) {
+                Ok(i) => KYUJITAI[i].1,
+                Err(_) => c,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conversion(width: Width, kana: Kana) -> Conversion {
+        Conversion {
+            alphanumerics: width,
+            symbols: width,
+            spaces: width,
+            katakana: width,
+            kana,
+            shinjitai: false,
+        }
+    }
+
+    #[test]
+    fn converts_everything_to_fullwidth() {
+        assert_eq!(
+            conversion(Width::Full, Kana::Keep).apply("ｶﾞｷﾟｳﾞﾊﾟ ABC1!｡ｰﾞ"),
+            "ガキ゜ヴパ　ＡＢＣ１！。ー゛"
+        );
+    }
+
+    #[test]
+    fn converts_everything_to_halfwidth() {
+        assert_eq!(
+            conversion(Width::Half, Kana::Keep).apply("ガパヴ　ＡＢＣ１！。"),
+            "ｶﾞﾊﾟｳﾞ ABC1!｡"
+        );
+        assert_eq!(halfwidth_katakana("ガパヴ"), "ｶﾞﾊﾟｳﾞ");
+        assert_eq!(fullwidth_katakana("ｶﾞﾊﾟｳﾞ"), "ガパヴ");
+    }
+
+    #[test]
+    fn converts_between_hiragana_and_katakana() {
+        assert_eq!(to_hiragana("カタカナ"), "かたかな");
+        assert_eq!(to_katakana("ひらがな"), "ヒラガナ");
+        assert_eq!(to_hiragana("ｶﾞｷﾞ"), "がぎ");
+        assert_eq!(to_hiragana("ヷ"), "わ\u{3099}");
+        assert_eq!(
+            conversion(Width::Half, Kana::Katakana).apply("ひらがな"),
+            "ﾋﾗｶﾞﾅ"
+        );
+    }
+
+    #[test]
+    fn converts_kyujitai_to_shinjitai() {
+        assert_eq!(to_shinjitai("舊字體の國學\u{FA31}"), "旧字体の国学僧");
+        let conversion = Conversion {
+            shinjitai: true,
+            ..conversion(Width::Keep, Kana::Keep)
+        };
+        assert_eq!(conversion.apply("國"), "国");
+    }
+}
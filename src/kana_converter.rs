@@ -0,0 +1,175 @@
+use crate::japanese::{Conversion, Kana, Width};
+use crate::util::horizontal_field;
+use yew::prelude::*;
+
+const WIDTHS: &[(Width, &str)] = &[
+    (Width::Keep, "そのまま"),
+    (Width::Full, "全角"),
+    (Width::Half, "半角"),
+];
+
+const KANAS: &[(Kana, &str)] = &[
+    (Kana::Keep, "そのまま"),
+    (Kana::Hiragana, "ひらがな"),
+    (Kana::Katakana, "カタカナ"),
+];
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    input: String,
+    #[prop_or(Width::Keep)]
+    alphanumerics: Width,
+    #[prop_or(Width::Keep)]
+    symbols: Width,
+    #[prop_or(Width::Keep)]
+    spaces: Width,
+    #[prop_or(Width::Keep)]
+    katakana: Width,
+    #[prop_or(Kana::Keep)]
+    kana: Kana,
+    #[prop_or_default]
+    shinjitai: bool,
+}
+
+pub enum Msg {
+    Input(String),
+    Alphanumerics(Width),
+    Symbols(Width),
+    Spaces(Width),
+    Katakana(Width),
+    Kana(Kana),
+    Shinjitai(bool),
+    All(Width),
+}
+
+impl Model {
+    fn width_field(&self, label: &str, value: Width, msg: fn(Width) -> Msg) -> Html {
+        horizontal_field(
+            label,
+            html! {
+                <div class="select">
+                    <select onchange=self.link.callback(move |e: ChangeData| match e {
+                        ChangeData::Select(s) => msg(WIDTHS[s.selected_index().max(0) as usize].0),
+                        _ => unreachable!(),
+                    })>
+                        {
+                            for WIDTHS.iter().map(|&(width, name)| html!{
+                                <option selected=value == width>{ name }</option>
+                            })
+                        }
+                    </select>
+                </div>
+            },
+        )
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let props = &mut self.props;
+        match msg {
+            Msg::Input(txt) => props.input = txt,
+            Msg::Alphanumerics(width) => props.alphanumerics = width,
+            Msg::Symbols(width) => props.symbols = width,
+            Msg::Spaces(width) => props.spaces = width,
+            Msg::Katakana(width) => props.katakana = width,
+            Msg::Kana(kana) => props.kana = kana,
+            Msg::Shinjitai(b) => props.shinjitai = b,
+            Msg::All(width) => {
+                props.alphanumerics = width;
+                props.symbols = width;
+                props.spaces = width;
+                props.katakana = width;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let props = &self.props;
+        let shinjitai = props.shinjitai;
+        let output = Conversion {
+            alphanumerics: props.alphanumerics,
+            symbols: props.symbols,
+            spaces: props.spaces,
+            katakana: props.katakana,
+            kana: props.kana,
+            shinjitai,
+        }
+        .apply(&props.input);
+
+        html! {
+            <>
+            {
+                horizontal_field("", html!{
+                    <div class="buttons">
+                        <button class="button" onclick=self.link.callback(|_| Msg::All(Width::Full))>{"すべて全角"}</button>
+                        <button class="button" onclick=self.link.callback(|_| Msg::All(Width::Half))>{"すべて半角"}</button>
+                    </div>
+                })
+            }
+            { self.width_field("英数字", props.alphanumerics, Msg::Alphanumerics) }
+            { self.width_field("記号", props.symbols, Msg::Symbols) }
+            { self.width_field("スペース", props.spaces, Msg::Spaces) }
+            { self.width_field("カタカナ", props.katakana, Msg::Katakana) }
+            {
+                horizontal_field("かな", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Kana(KANAS[s.selected_index().max(0) as usize].0),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for KANAS.iter().map(|&(kana, name)| html!{
+                                    <option selected=props.kana == kana>{ name }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                horizontal_field("", html!{
+                    <label class="checkbox">
+                        <input type="checkbox" checked=shinjitai
+                            onchange=self.link.callback(move |_| Msg::Shinjitai(!shinjitai))/>
+                        {" 旧字体を新字体にする（國→国、學→学）"}
+                    </label>
+                })
+            }
+
+            <div class="field">
+                <label class="label">{"入力"}</label>
+                <div class="control">
+                    <textarea class="textarea" placeholder="ｶﾞｷﾞｸﾞ ＡＢＣ１２３ 舊字體"
+                        value=&props.input
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))/>
+                </div>
+            </div>
+            <div class="field">
+                <label class="label">{"出力"}</label>
+                <div class="control">
+                    <textarea class="textarea" value=output readonly=true/>
+                </div>
+            </div>
+            </>
+        }
+    }
+}
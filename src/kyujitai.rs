@@ -0,0 +1,312 @@
+//! 旧字体 to 新字体 mappings: the 常用漢字 and 人名用漢字 whose traditional
+//! forms have their own code points. Variants in the CJK Compatibility
+//! Ideographs block are left out; their canonical decompositions already
+//! give the 新字体.
+
+/// Pairs of 旧字体 and 新字体, sorted by 旧字体 for binary search.
+pub const KYUJITAI: &[(char, char)] = &[
+    ('乘', '乗'),
+    ('亂', '乱'),
+    ('亙', '亘'),
+    ('亞', '亜'),
+    ('佛', '仏'),
+    ('來', '来'),
+    ('倂', '併'),
+    ('假', '仮'),
+    ('傳', '伝'),
+    ('僞', '偽'),
+    ('價', '価'),
+    ('儉', '倹'),
+    ('兒', '児'),
+    ('兩', '両'),
+    ('剩', '剰'),
+    ('劍', '剣'),
+    ('劑', '剤'),
+    ('勞', '労'),
+    ('勳', '勲'),
+    ('勵', '励'),
+    ('勸', '勧'),
+    ('區', '区'),
+    ('卷', '巻'),
+    ('卽', '即'),
+    ('參', '参'),
+    ('單', '単'),
+    ('嚴', '厳'),
+    ('囑', '嘱'),
+    ('圈', '圏'),
+    ('國', '国'),
+    ('圍', '囲'),
+    ('圓', '円'),
+    ('圖', '図'),
+    ('團', '団'),
+    ('堯', '尭'),
+    ('增', '増'),
+    ('墮', '堕'),
+    ('壓', '圧'),
+    ('壘', '塁'),
+    ('壞', '壊'),
+    ('壤', '壌'),
+    ('壯', '壮'),
+    ('壹', '壱'),
+    ('壽', '寿'),
+    ('奧', '奥'),
+    ('奬', '奨'),
+    ('姬', '姫'),
+    ('孃', '嬢'),
+    ('學', '学'),
+    ('寢', '寝'),
+    ('實', '実'),
+    ('寫', '写'),
+    ('寬', '寛'),
+    ('寶', '宝'),
+    ('將', '将'),
+    ('專', '専'),
+    ('對', '対'),
+    ('屆', '届'),
+    ('屬', '属'),
+    ('峽', '峡'),
+    ('嶽', '岳'),
+    ('巖', '巌'),
+    ('巢', '巣'),
+    ('帶', '帯'),
+    ('廢', '廃'),
+    ('廣', '広'),
+    ('廳', '庁'),
+    ('彈', '弾'),
+    ('彌', '弥'),
+    ('徑', '径'),
+    ('從', '従'),
+    ('德', '徳'),
+    ('恆', '恒'),
+    ('惠', '恵'),
+    ('惡', '悪'),
+    ('惱', '悩'),
+    ('愼', '慎'),
+    ('慘', '惨'),
+    ('應', '応'),
+    ('懷', '懐'),
+    ('戀', '恋'),
+    ('戰', '戦'),
+    ('戲', '戯'),
+    ('戾', '戻'),
+    ('拂', '払'),
+    ('拔', '抜'),
+    ('拜', '拝'),
+    ('挾', '挟'),
+    ('插', '挿'),
+    ('揭', '掲'),
+    ('搖', '揺'),
+    ('搜', '捜'),
+    ('擇', '択'),
+    ('擊', '撃'),
+    ('擔', '担'),
+    ('據', '拠'),
+    ('擧', '挙'),
+    ('擴', '拡'),
+    ('攝', '摂'),
+    ('收', '収'),
+    ('效', '効'),
+    ('敍', '叙'),
+    ('敕', '勅'),
+    ('數', '数'),
+    ('斷', '断'),
+    ('旣', '既'),
+    ('晚', '晩'),
+    ('晝', '昼'),
+    ('曉', '暁'),
+    ('會', '会'),
+    ('條', '条'),
+    ('棧', '桟'),
+    ('榮', '栄'),
+    ('槇', '槙'),
+    ('槪', '概'),
+    ('樂', '楽'),
+    ('樓', '楼'),
+    ('樞', '枢'),
+    ('樣', '様'),
+    ('檜', '桧'),
+    ('檢', '検'),
+    ('櫻', '桜'),
+    ('權', '権'),
+    ('歐', '欧'),
+    ('歡', '歓'),
+    ('步', '歩'),
+    ('歲', '歳'),
+    ('歸', '帰'),
+    ('殘', '残'),
+    ('殼', '殻'),
+    ('毆', '殴'),
+    ('每', '毎'),
+    ('氣', '気'),
+    ('沒', '没'),
+    ('淚', '涙'),
+    ('淨', '浄'),
+    ('淺', '浅'),
+    ('渴', '渇'),
+    ('溪', '渓'),
+    ('溫', '温'),
+    ('滯', '滞'),
+    ('滿', '満'),
+    ('潛', '潜'),
+    ('澁', '渋'),
+    ('澤', '沢'),
+    ('濕', '湿'),
+    ('濟', '済'),
+    ('濱', '浜'),
+    ('瀧', '滝'),
+    ('瀨', '瀬'),
+    ('灣', '湾'),
+    ('燈', '灯'),
+    ('燒', '焼'),
+    ('營', '営'),
+    ('爐', '炉'),
+    ('爭', '争'),
+    ('爲', '為'),
+    ('犧', '犠'),
+    ('狀', '状'),
+    ('狹', '狭'),
+    ('獨', '独'),
+    ('獵', '猟'),
+    ('獸', '獣'),
+    ('獻', '献'),
+    ('瑤', '瑶'),
+    ('瓣', '弁'),
+    ('畫', '画'),
+    ('當', '当'),
+    ('疊', '畳'),
+    ('癡', '痴'),
+    ('發', '発'),
+    ('盜', '盗'),
+    ('盡', '尽'),
+    ('眞', '真'),
+    ('碎', '砕'),
+    ('祕', '秘'),
+    ('祿', '禄'),
+    ('禪', '禅'),
+    ('禮', '礼'),
+    ('稱', '称'),
+    ('稻', '稲'),
+    ('穗', '穂'),
+    ('穩', '穏'),
+    ('竊', '窃'),
+    ('竝', '並'),
+    ('粹', '粋'),
+    ('絲', '糸'),
+    ('經', '経'),
+    ('綠', '緑'),
+    ('緣', '縁'),
+    ('縣', '県'),
+    ('縱', '縦'),
+    ('總', '総'),
+    ('繩', '縄'),
+    ('繪', '絵'),
+    ('繼', '継'),
+    ('續', '続'),
+    ('缺', '欠'),
+    ('罐', '缶'),
+    ('聰', '聡'),
+    ('聲', '声'),
+    ('聽', '聴'),
+    ('肅', '粛'),
+    ('腦', '脳'),
+    ('膽', '胆'),
+    ('臟', '臓'),
+    ('臺', '台'),
+    ('與', '与'),
+    ('舊', '旧'),
+    ('舍', '舎'),
+    ('莊', '荘'),
+    ('莖', '茎'),
+    ('萬', '万'),
+    ('藏', '蔵'),
+    ('藝', '芸'),
+    ('藥', '薬'),
+    ('處', '処'),
+    ('虛', '虚'),
+    ('號', '号'),
+    ('螢', '蛍'),
+    ('蟲', '虫'),
+    ('蠟', '蝋'),
+    ('蠶', '蚕'),
+    ('蠻', '蛮'),
+    ('衞', '衛'),
+    ('裝', '装'),
+    ('覺', '覚'),
+    ('覽', '覧'),
+    ('觀', '観'),
+    ('觸', '触'),
+    ('謠', '謡'),
+    ('證', '証'),
+    ('譯', '訳'),
+    ('譽', '誉'),
+    ('讀', '読'),
+    ('變', '変'),
+    ('讓', '譲'),
+    ('豐', '豊'),
+    ('豫', '予'),
+    ('貳', '弐'),
+    ('賣', '売'),
+    ('賴', '頼'),
+    ('贊', '賛'),
+    ('踐', '践'),
+    ('輕', '軽'),
+    ('轉', '転'),
+    ('辨', '弁'),
+    ('辭', '辞'),
+    ('辯', '弁'),
+    ('遙', '遥'),
+    ('遞', '逓'),
+    ('遲', '遅'),
+    ('邊', '辺'),
+    ('鄕', '郷'),
+    ('醉', '酔'),
+    ('醫', '医'),
+    ('釀', '醸'),
+    ('釋', '釈'),
+    ('鋪', '舗'),
+    ('錄', '録'),
+    ('錢', '銭'),
+    ('鎭', '鎮'),
+    ('鐵', '鉄'),
+    ('鑄', '鋳'),
+    ('鑛', '鉱'),
+    ('關', '関'),
+    ('陷', '陥'),
+    ('隨', '随'),
+    ('險', '険'),
+    ('隱', '隠'),
+    ('隸', '隷'),
+    ('雙', '双'),
+    ('雜', '雑'),
+    ('霸', '覇'),
+    ('靈', '霊'),
+    ('靜', '静'),
+    ('顏', '顔'),
+    ('顯', '顕'),
+    ('飜', '翻'),
+    ('餘', '余'),
+    ('騷', '騒'),
+    ('驅', '駆'),
+    ('驗', '験'),
+    ('驛', '駅'),
+    ('髓', '髄'),
+    ('體', '体'),
+    ('髮', '髪'),
+    ('鬪', '闘'),
+    ('鬭', '闘'),
+    ('鷄', '鶏'),
+    ('鹽', '塩'),
+    ('麥', '麦'),
+    ('麵', '麺'),
+    ('黃', '黄'),
+    ('黑', '黒'),
+    ('默', '黙'),
+    ('點', '点'),
+    ('黨', '党'),
+    ('齊', '斉'),
+    ('齋', '斎'),
+    ('齒', '歯'),
+    ('齡', '齢'),
+    ('龍', '竜'),
+    ('龜', '亀'),
+];
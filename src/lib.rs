@@ -6,12 +6,40 @@ use yew_router::prelude::*;
 
 mod base64;
 mod base_converter;
+mod byte_values;
 mod char_counter;
+mod chmod;
+mod confusable;
+mod date_calculator;
 mod digest;
+mod escape;
+mod expr;
+mod float_inspector;
+mod frequency;
+mod holidays;
+mod html_entities;
+mod invisible;
+mod japanese;
+mod kana_converter;
+mod kyujitai;
+mod normalizer;
+mod number_theory;
+mod number_words;
+mod prime_factorization;
+mod primes;
 mod regex;
+mod sms;
 mod sudden_death;
+mod timestamp;
+mod twitter;
+mod unicode;
+mod unicode_converter;
+mod unicode_inspector;
+mod unicode_tables;
 mod url_encode;
 mod util;
+mod wareki;
+mod whitespace;
 
 static ROOT: Option<&str> = option_env!("ROOT");
 
@@ -37,6 +65,32 @@ enum AppRoute {
     CharCounter(String),
     #[to = "/{}/#/url-encode"]
     UrlEncode(String),
+    #[to = "/{}/#/bytes"]
+    ByteValues(String),
+    #[to = "/{}/#/normalize"]
+    Normalizer(String),
+    #[to = "/{}/#/unicode-convert"]
+    UnicodeConverter(String),
+    #[to = "/{}/#/unicode"]
+    UnicodeInspector(String),
+    #[to = "/{}/#/invisible"]
+    Invisible(String),
+    #[to = "/{}/#/confusable"]
+    Confusable(String),
+    #[to = "/{}/#/factorize"]
+    PrimeFactorization(String),
+    #[to = "/{}/#/number-theory"]
+    NumberTheory(String),
+    #[to = "/{}/#/chmod"]
+    Chmod(String),
+    #[to = "/{}/#/timestamp"]
+    Timestamp(String),
+    #[to = "/{}/#/wareki"]
+    Wareki(String),
+    #[to = "/{}/#/zenkaku"]
+    KanaConverter(String),
+    #[to = "/{}/#/date-calc"]
+    DateCalculator(String),
 
     #[to = "/{}/#/regex"]
     Regex(String),
@@ -100,7 +154,20 @@ impl Component for Model {
                     <li><RouterLink text="Message digest (MD5, SHA-1, SHA-2)" route=AppRoute::Digest(root())/></li>
                     <li><RouterLink text="URL encode" route=AppRoute::UrlEncode(root())/></li>
                     <li><RouterLink text="Base converter" route=AppRoute::BaseConverter(root())/></li>
+                    <li><RouterLink text="Text ↔ bytes" route=AppRoute::ByteValues(root())/></li>
                     <li><RouterLink text="Character counter" route=AppRoute::CharCounter(root())/></li>
+                    <li><RouterLink text="Whitespace normalizer" route=AppRoute::Normalizer(root())/></li>
+                    <li><RouterLink text="Unicode inspector" route=AppRoute::UnicodeInspector(root())/></li>
+                    <li><RouterLink text="Unicode normalization & escapes" route=AppRoute::UnicodeConverter(root())/></li>
+                    <li><RouterLink text="Invisible character detector" route=AppRoute::Invisible(root())/></li>
+                    <li><RouterLink text="Confusable detector" route=AppRoute::Confusable(root())/></li>
+                    <li><RouterLink text="Prime factorization" route=AppRoute::PrimeFactorization(root())/></li>
+                    <li><RouterLink text="Number theory" route=AppRoute::NumberTheory(root())/></li>
+                    <li><RouterLink text="chmod calculator" route=AppRoute::Chmod(root())/></li>
+                    <li><RouterLink text="Timestamp converter" route=AppRoute::Timestamp(root())/></li>
+                    <li><RouterLink text="Date calculator" route=AppRoute::DateCalculator(root())/></li>
+                    <li><RouterLink text="和暦変換" route=AppRoute::Wareki(root())/></li>
+                    <li><RouterLink text="全角・半角・かな変換" route=AppRoute::KanaConverter(root())/></li>
                 </ul>
 
                 <p class="menu-label">
@@ -110,14 +177,6 @@ impl Component for Model {
                     <li><RouterLink text="Regex Generator" route=AppRoute::Regex(root())/></li>
                     <li><RouterLink text="突然の死ジェネレーター" route=AppRoute::SuddenDeath(root())/></li>
                 </ul>
-
-                <p class="menu-label">
-                    {"Underconstructions"}
-                </p>
-                <ul class="menu-list">
-                    <li><a>{"ASCII converter"}</a></li>
-                    <li><a>{"Prime factorization"}</a></li>
-                </ul>
             </aside>
         };
 
@@ -130,6 +189,19 @@ impl Component for Model {
             AppRoute::SuddenDeath(_) => html! {<crate::sudden_death::Model/>},
             AppRoute::CharCounter(_) => html! {<crate::char_counter::Model/>},
             AppRoute::UrlEncode(_) => html! {<crate::url_encode::Model/>},
+            AppRoute::ByteValues(_) => html! {<crate::byte_values::Model/>},
+            AppRoute::Normalizer(_) => html! {<crate::normalizer::Model/>},
+            AppRoute::UnicodeInspector(_) => html! {<crate::unicode_inspector::Model/>},
+            AppRoute::UnicodeConverter(_) => html! {<crate::unicode_converter::Model/>},
+            AppRoute::Invisible(_) => html! {<crate::invisible::Model/>},
+            AppRoute::Confusable(_) => html! {<crate::confusable::Model/>},
+            AppRoute::PrimeFactorization(_) => html! {<crate::prime_factorization::Model/>},
+            AppRoute::NumberTheory(_) => html! {<crate::number_theory::Model/>},
+            AppRoute::Chmod(_) => html! {<crate::chmod::Model/>},
+            AppRoute::Timestamp(_) => html! {<crate::timestamp::Model/>},
+            AppRoute::Wareki(_) => html! {<crate::wareki::Model/>},
+            AppRoute::KanaConverter(_) => html! {<crate::kana_converter::Model/>},
+            AppRoute::DateCalculator(_) => html! {<crate::date_calculator::Model/>},
         };
 
         html! {
@@ -232,3 +304,25 @@ impl Component for IndexModel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use yew_router::route::Route;
+
+    fn route(path: &str) -> Option<AppRoute> {
+        AppRoute::switch(Route::<()>::new_no_state(path))
+    }
+
+    #[test]
+    fn routes_sharing_a_prefix_resolve_to_their_own_page() {
+        assert_eq!(
+            route("/static/#/unicode-convert"),
+            Some(AppRoute::UnicodeConverter("static".to_string()))
+        );
+        assert_eq!(
+            route("/static/#/unicode"),
+            Some(AppRoute::UnicodeInspector("static".to_string()))
+        );
+    }
+}
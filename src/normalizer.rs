@@ -0,0 +1,247 @@
+use crate::util::horizontal_field;
+use crate::whitespace::{
+    Bom, Diagnostics, Indent, NewLine, Normalize, BOMS, INDENTS, MAX_TAB_WIDTH, NEW_LINES,
+};
+use web_sys::FileList;
+use yew::prelude::*;
+use yew::services::reader::{FileData, ReaderService, ReaderTask};
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+    reader: Option<ReaderTask>,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    input: String,
+    #[prop_or("normalized.txt".to_string())]
+    file_name: String,
+    #[prop_or(NewLine::Lf)]
+    new_line: NewLine,
+    #[prop_or(Bom::Keep)]
+    bom: Bom,
+    #[prop_or(Indent::Keep)]
+    indent: Indent,
+    #[prop_or(4)]
+    tab_width: usize,
+    #[prop_or(true)]
+    trim_trailing: bool,
+    #[prop_or_default]
+    collapse_blank: bool,
+    #[prop_or_default]
+    replace_spaces: bool,
+}
+
+pub enum Msg {
+    Input(String),
+    Open(FileList),
+    Loaded(FileData),
+    NewLine(NewLine),
+    Bom(Bom),
+    Indent(Indent),
+    TabWidth(String),
+    TrimTrailing(bool),
+    CollapseBlank(bool),
+    ReplaceSpaces(bool),
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            reader: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(txt) => self.props.input = txt,
+            Msg::Open(files) => {
+                if let Some(file) = files.get(0) {
+                    let callback = self.link.callback(Msg::Loaded);
+                    self.reader = ReaderService::new().read_file(file, callback).ok();
+                }
+                return false;
+            }
+            Msg::Loaded(data) => {
+                self.reader = None;
+                self.props.input = String::from_utf8_lossy(&data.content).into_owned();
+                self.props.file_name = data.name;
+            }
+            Msg::NewLine(new_line) => self.props.new_line = new_line,
+            Msg::Bom(bom) => self.props.bom = bom,
+            Msg::Indent(indent) => self.props.indent = indent,
+            Msg::TabWidth(width) => {
+                if let Ok(width) = width.trim().parse::<usize>() {
+                    self.props.tab_width = width.clamp(1, MAX_TAB_WIDTH);
+                }
+            }
+            Msg::TrimTrailing(b) => self.props.trim_trailing = b,
+            Msg::CollapseBlank(b) => self.props.collapse_blank = b,
+            Msg::ReplaceSpaces(b) => self.props.replace_spaces = b,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let props = &self.props;
+        let output = Normalize {
+            new_line: props.new_line,
+            bom: props.bom,
+            indent: props.indent,
+            tab_width: props.tab_width,
+            trim_trailing: props.trim_trailing,
+            collapse_blank: props.collapse_blank,
+            replace_spaces: props.replace_spaces,
+        }
+        .apply(&props.input);
+
+        // A textarea shows every line ending as LF, so say what the output
+        // really has.
+        let before = Diagnostics::new(&props.input);
+        let after = Diagnostics::new(&output);
+        let summary = |d: &Diagnostics| {
+            format!(
+                "{} lines, {}{}, {} with trailing whitespace, {} blank",
+                d.lines,
+                d.ending().name(),
+                if d.bom { ", BOM" } else { "" },
+                d.trailing.len(),
+                d.blank.len()
+            )
+        };
+        let download = format!(
+            "data:text/plain;charset=utf-8,{}",
+            urlencoding::encode(&output)
+        );
+
+        let trim_trailing = props.trim_trailing;
+        let collapse_blank = props.collapse_blank;
+        let replace_spaces = props.replace_spaces;
+
+        html! {
+            <>
+            <h1 class="title">{"Whitespace normalizer"}</h1>
+
+            <div class="field">
+                <label class="label">{"Input"}</label>
+                <div class="control">
+                    <textarea class="textarea" value=&props.input
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))/>
+                </div>
+                <p class="help">{ summary(&before) }</p>
+            </div>
+            <div class="field">
+                <div class="file is-small">
+                    <label class="file-label">
+                        <input class="file-input" type="file" onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Files(files) => Msg::Open(files),
+                            _ => unreachable!(),
+                        })/>
+                        <span class="file-cta">
+                            <span class="file-label">{"Open a file…"}</span>
+                        </span>
+                    </label>
+                </div>
+            </div>
+
+            {
+                horizontal_field("Line endings", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::NewLine(NEW_LINES[s.selected_index().max(0) as usize]),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for NEW_LINES.iter().map(|&new_line| html!{
+                                    <option selected=props.new_line == new_line>{ new_line.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                horizontal_field("BOM", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Bom(BOMS[s.selected_index().max(0) as usize]),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for BOMS.iter().map(|&bom| html!{
+                                    <option selected=props.bom == bom>{ bom.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                horizontal_field("Indentation", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Indent(INDENTS[s.selected_index().max(0) as usize]),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for INDENTS.iter().map(|&indent| html!{
+                                    <option selected=props.indent == indent>{ indent.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                horizontal_field("Tab width", html!{
+                    <input class="input" type="number" min="1" max=MAX_TAB_WIDTH value=props.tab_width
+                        oninput=self.link.callback(|e: InputData| Msg::TabWidth(e.value))/>
+                })
+            }
+            {
+                horizontal_field("", html!{
+                    <>
+                    <label class="checkbox">
+                        <input type="checkbox" checked=trim_trailing
+                            onchange=self.link.callback(move |_| Msg::TrimTrailing(!trim_trailing))/>
+                        {" Trim trailing whitespace"}
+                    </label>
+                    <br/>
+                    <label class="checkbox">
+                        <input type="checkbox" checked=collapse_blank
+                            onchange=self.link.callback(move |_| Msg::CollapseBlank(!collapse_blank))/>
+                        {" Collapse consecutive blank lines"}
+                    </label>
+                    <br/>
+                    <label class="checkbox">
+                        <input type="checkbox" checked=replace_spaces
+                            onchange=self.link.callback(move |_| Msg::ReplaceSpaces(!replace_spaces))/>
+                        {" Replace full-width spaces (U+3000) and NBSPs with ASCII spaces"}
+                    </label>
+                    </>
+                })
+            }
+
+            <div class="field">
+                <label class="label">{"Output"}</label>
+                <div class="control">
+                    <textarea class="textarea" value=output readonly=true/>
+                </div>
+                <p class="help">{ summary(&after) }</p>
+            </div>
+            <a class="button is-info" href=download download=&props.file_name>{"Download"}</a>
+            </>
+        }
+    }
+}
@@ -0,0 +1,623 @@
+use crate::primes::{is_prime, is_proven_range, miller_rabin, MAX_BITS};
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use yew::prelude::*;
+
+/// Longer traces are cut here; a 4096-bit modpow would otherwise print
+/// thousands of lines.
+const MAX_STEPS: usize = 300;
+
+/// Neighbouring primes are only searched below this many bits, as
+/// everything is recomputed on each keystroke.
+const NEIGHBOUR_BITS: u64 = 256;
+
+const SMALL_PRIMES: &[u32] = &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Op {
+    Gcd,
+    Inverse,
+    ModPow,
+    Crt,
+    Primality,
+    Jacobi,
+}
+
+const OPS: &[Op] = &[
+    Op::Gcd,
+    Op::Inverse,
+    Op::ModPow,
+    Op::Crt,
+    Op::Primality,
+    Op::Jacobi,
+];
+
+impl Op {
+    fn name(self) -> &'static str {
+        match self {
+            Op::Gcd => "GCD / LCM",
+            Op::Inverse => "Modular inverse",
+            Op::ModPow => "Modular power",
+            Op::Crt => "CRT",
+            Op::Primality => "Primality",
+            Op::Jacobi => "Jacobi symbol",
+        }
+    }
+
+    fn args(self) -> &'static [&'static str] {
+        match self {
+            Op::Gcd => &["a", "b"],
+            Op::Inverse => &["a", "m"],
+            Op::ModPow => &["Base", "Exponent", "Modulus"],
+            Op::Crt => &[],
+            Op::Primality => &["n"],
+            Op::Jacobi => &["a", "n"],
+        }
+    }
+
+    fn run(self, args: &[BigInt], steps: &mut Steps) -> Result<String, String> {
+        match self {
+            Op::Gcd => gcd(&args[0], &args[1], steps),
+            Op::Inverse => inverse(&args[0], &args[1], steps),
+            Op::ModPow => modpow(&args[0], &args[1], &args[2], steps),
+            Op::Crt => crt(args, steps),
+            Op::Primality => primality(&args[0], steps),
+            Op::Jacobi => jacobi(&args[0], &args[1], steps).map(|j| j.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Steps(Vec<String>);
+
+impl Steps {
+    fn push(&mut self, s: String) {
+        if self.0.len() < MAX_STEPS {
+            self.0.push(s);
+        } else if self.0.len() == MAX_STEPS {
+            self.0.push("...".to_string());
+        }
+    }
+}
+
+/// Rows of the extended Euclidean algorithm on `a` and `b`, returning
+/// `(g, x, y)` with `a * x + b * y = g`.
+fn extended_gcd(a: &BigInt, b: &BigInt, steps: &mut Steps) -> (BigInt, BigInt, BigInt) {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (BigInt::one(), BigInt::zero());
+    let (mut t0, mut t1) = (BigInt::zero(), BigInt::one());
+    while !r1.is_zero() {
+        let (q, r) = r0.div_mod_floor(&r1);
+        steps.push(format!(
+            "{} = {} × {} + {}    (s, t) = ({}, {})",
+            r0, q, r1, r, s1, t1
+        ));
+        let s = &s0 - &q * &s1;
+        let t = &t0 - &q * &t1;
+        r0 = std::mem::replace(&mut r1, r);
+        s0 = std::mem::replace(&mut s1, s);
+        t0 = std::mem::replace(&mut t1, t);
+    }
+    if r0.is_negative() {
+        (-r0, -s0, -t0)
+    } else {
+        (r0, s0, t0)
+    }
+}
+
+fn gcd(a: &BigInt, b: &BigInt, steps: &mut Steps) -> Result<String, String> {
+    let (g, x, y) = extended_gcd(a, b, steps);
+    let lcm = if g.is_zero() {
+        BigInt::zero()
+    } else {
+        (a / &g * b).abs()
+    };
+    steps.push(format!("Bézout: {} × {} + {} × {} = {}", a, x, b, y, g));
+    Ok(format!("gcd = {}, lcm = {}", g, lcm))
+}
+
+fn inverse(a: &BigInt, m: &BigInt, steps: &mut Steps) -> Result<String, String> {
+    if !m.is_positive() {
+        return Err("Modulus must be positive".to_string());
+    }
+    let (g, x, _) = extended_gcd(&a.mod_floor(m), m, steps);
+    if !g.is_one() {
+        return Err(format!(
+            "{} is not invertible modulo {} (gcd = {})",
+            a, m, g
+        ));
+    }
+    let x = x.mod_floor(m);
+    steps.push(format!("{} × {} ≡ 1 (mod {})", a, x, m));
+    Ok(x.to_string())
+}
+
+fn modpow(base: &BigInt, exp: &BigInt, m: &BigInt, steps: &mut Steps) -> Result<String, String> {
+    if !m.is_positive() {
+        return Err("Modulus must be positive".to_string());
+    }
+    if exp.is_negative() {
+        return Err("Exponent must not be negative".to_string());
+    }
+    let mut b = base.mod_floor(m);
+    let mut result = BigInt::one() % m;
+    let exp = exp.magnitude();
+    steps.push(format!("Exponent in binary: {}", exp.to_str_radix(2)));
+    for bit in 0..exp.bits() {
+        if exp.bit(bit) {
+            result = &result * &b % m;
+            steps.push(format!(
+                "bit {}: 1, result × {}^(2^{}) = {}",
+                bit, base, bit, result
+            ));
+        } else {
+            steps.push(format!("bit {}: 0, result = {}", bit, result));
+        }
+        b = &b * &b % m;
+    }
+    Ok(result.to_string())
+}
+
+/// Parses congruences such as `2 mod 3`, `x ≡ 2 (mod 3)` or `2, 3`, one per
+/// line.
+fn parse_congruences(text: &str) -> Result<Vec<BigInt>, String> {
+    let mut args = vec![];
+    for line in text.lines() {
+        let line = line.trim().to_lowercase();
+        if line.is_empty() {
+            continue;
+        }
+        let line = match line.find(['≡', '=']) {
+            Some(i) => line[i..].chars().skip(1).collect(),
+            None => line,
+        };
+        let line = line.replace(['(', ')'], " ");
+        let (a, m) = match line.find("mod") {
+            Some(i) => (&line[..i], &line[i + 3..]),
+            None => match line.find(',') {
+                Some(i) => (&line[..i], &line[i + 1..]),
+                None => return Err(format!("Expected 'a mod m': {}", line.trim())),
+            },
+        };
+        args.push(crate::expr::eval(a)?);
+        args.push(crate::expr::eval(m)?);
+    }
+    if args.is_empty() {
+        return Err("Enter one congruence per line".to_string());
+    }
+    Ok(args)
+}
+
+fn crt(args: &[BigInt], steps: &mut Steps) -> Result<String, String> {
+    let mut a = BigInt::zero();
+    let mut m = BigInt::one();
+    for pair in args.chunks(2) {
+        let (b, n) = (&pair[0], &pair[1]);
+        if !n.is_positive() {
+            return Err("Moduli must be positive".to_string());
+        }
+        let b = b.mod_floor(n);
+        let g = m.gcd(n);
+        let diff = &b - &a;
+        if !(&diff % &g).is_zero() {
+            return Err(format!(
+                "No solution: x ≡ {} (mod {}) and x ≡ {} (mod {}) disagree modulo gcd = {}",
+                a, m, b, n, g
+            ));
+        }
+        // a + m * k ≡ b (mod n)  ⇔  (m / g) * k ≡ (b - a) / g (mod n / g)
+        let ng = n / &g;
+        let (_, inv, _) = extended_gcd(&(&m / &g), &ng, &mut Steps::default());
+        let k = (&diff / &g * inv).mod_floor(&ng);
+        let lcm = &m * &ng;
+        let x = (&a + &m * &k).mod_floor(&lcm);
+        steps.push(format!(
+            "x ≡ {} (mod {}), x ≡ {} (mod {}): x = {} + {} × {} ≡ {} (mod {})",
+            a, m, b, n, a, m, k, x, lcm
+        ));
+        a = x;
+        m = lcm;
+    }
+    Ok(format!("x ≡ {} (mod {})", a, m))
+}
+
+fn jacobi(a: &BigInt, n: &BigInt, steps: &mut Steps) -> Result<i32, String> {
+    if !n.is_positive() || n.is_even() {
+        return Err("n must be a positive odd integer".to_string());
+    }
+    let mut a = a.mod_floor(n);
+    let mut n = n.clone();
+    let mut t = 1;
+    steps.push(format!("Reduce a modulo n: ({} / {})", a, n));
+    while !a.is_zero() {
+        while a.is_even() {
+            a /= 2;
+            let r = (&n % 8u32).to_u32().unwrap();
+            if r == 3 || r == 5 {
+                t = -t;
+            }
+            steps.push(format!(
+                "Take out 2, (2 / n) = {} since n ≡ {} (mod 8): {}({} / {})",
+                if r == 3 || r == 5 { -1 } else { 1 },
+                r,
+                sign(t),
+                a,
+                n
+            ));
+        }
+        std::mem::swap(&mut a, &mut n);
+        let flip = (&a % 4u32) == BigInt::from(3) && (&n % 4u32) == BigInt::from(3);
+        if flip {
+            t = -t;
+        }
+        a = a.mod_floor(&n);
+        steps.push(format!(
+            "Reciprocity{}: {}({} / {})",
+            if flip { ", both ≡ 3 (mod 4)" } else { "" },
+            sign(t),
+            a,
+            n
+        ));
+    }
+    if n.is_one() {
+        Ok(t)
+    } else {
+        steps.push(format!("gcd = {} > 1", n));
+        Ok(0)
+    }
+}
+
+fn sign(t: i32) -> &'static str {
+    if t < 0 {
+        "-"
+    } else {
+        ""
+    }
+}
+
+/// The strong Lucas probable prime test with Selfridge's parameters. `n`
+/// must be odd, not a perfect square, and larger than the small primes.
+fn strong_lucas(n: &BigInt, steps: &mut Steps) -> bool {
+    let mut d = BigInt::from(5);
+    loop {
+        let j = jacobi(&d, n, &mut Steps::default()).unwrap();
+        if j == -1 {
+            break;
+        }
+        if j == 0 && d.abs() != *n {
+            steps.push(format!("Strong Lucas: {} divides n", d.abs()));
+            return false;
+        }
+        d = if d.is_positive() { -d - 2 } else { -d + 2 };
+    }
+    let p = BigInt::one();
+    let q: BigInt = (1 - &d) / 4;
+    steps.push(format!("Strong Lucas: D = {}, P = {}, Q = {}", d, p, q));
+
+    let half = |x: BigInt| {
+        let x = if x.is_odd() { x + n } else { x };
+        (x >> 1usize).mod_floor(n)
+    };
+
+    let n1: BigInt = n + 1;
+    let s = n1.magnitude().trailing_zeros().unwrap();
+    let k = &n1 >> s as usize;
+    let (mut u, mut v, mut qk) = (BigInt::one(), p.clone(), q.mod_floor(n));
+    for bit in (0..k.bits() - 1).rev() {
+        u = (&u * &v).mod_floor(n);
+        v = (&v * &v - (&qk << 1usize)).mod_floor(n);
+        qk = (&qk * &qk).mod_floor(n);
+        if k.magnitude().bit(bit) {
+            let u1 = half(&p * &u + &v);
+            v = half(&d * &u + &p * &v);
+            u = u1;
+            qk = (&qk * &q).mod_floor(n);
+        }
+    }
+    steps.push(format!("n + 1 = {} × 2^{}: U_d = {}, V_d = {}", k, s, u, v));
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+    for r in 1..s {
+        v = (&v * &v - (&qk << 1usize)).mod_floor(n);
+        qk = (&qk * &qk).mod_floor(n);
+        steps.push(format!("V_(d·2^{}) = {}", r, v));
+        if v.is_zero() {
+            return true;
+        }
+    }
+    false
+}
+
+fn primality(n: &BigInt, steps: &mut Steps) -> Result<String, String> {
+    if n.is_negative() {
+        return Err("n must not be negative".to_string());
+    }
+    let neighbours = if n.bits() > NEIGHBOUR_BITS {
+        format!(
+            "neighbouring primes are not searched above 2^{}",
+            NEIGHBOUR_BITS
+        )
+    } else {
+        format!(
+            "next prime = {}, previous prime = {}",
+            next_prime(n.magnitude()),
+            prev_prime(n.magnitude()).map_or("none".to_string(), |p| p.to_string())
+        )
+    };
+
+    let verdict = |s: &str| Ok(format!("{}; {}", s, neighbours));
+    if *n < BigInt::from(2) {
+        return verdict("neither prime nor composite");
+    }
+    for &p in SMALL_PRIMES {
+        if *n == BigInt::from(p) {
+            steps.push(format!("{} is a small prime", n));
+            return verdict("prime");
+        }
+        if (n % p).is_zero() {
+            steps.push(format!("Trial division: divisible by {}", p));
+            return verdict("composite");
+        }
+    }
+    steps.push(format!(
+        "Trial division: no factor up to {}",
+        SMALL_PRIMES.last().unwrap()
+    ));
+
+    let m = n.magnitude();
+    for &b in SMALL_PRIMES {
+        if miller_rabin(m, &BigUint::from(b)) {
+            steps.push(format!("Miller–Rabin base {}: strong probable prime", b));
+        } else {
+            steps.push(format!("Miller–Rabin base {}: composite (witness)", b));
+            return verdict("composite");
+        }
+    }
+    if is_proven_range(m) {
+        steps.push("Bases up to 41 are deterministic below 3.3 × 10^24".to_string());
+        return verdict("prime");
+    }
+
+    let root = m.sqrt();
+    if &root * &root == *m {
+        steps.push(format!("Perfect square of {}", root));
+        return verdict("composite");
+    }
+    if strong_lucas(n, steps) {
+        steps.push("Passes Baillie–PSW (no known counterexample)".to_string());
+        verdict("probable prime")
+    } else {
+        steps.push("Strong Lucas test: composite".to_string());
+        verdict("composite")
+    }
+}
+
+fn next_prime(n: &BigUint) -> BigUint {
+    let mut p = n + 1u32;
+    while !is_prime(&p) {
+        p += 1u32;
+    }
+    p
+}
+
+fn prev_prime(n: &BigUint) -> Option<BigUint> {
+    let mut p = n.clone();
+    while p > BigUint::from(2u32) {
+        p -= 1u32;
+        if is_prime(&p) {
+            return Some(p);
+        }
+    }
+    None
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or(Op::Gcd)]
+    op: Op,
+    #[prop_or_default]
+    args: [String; 3],
+    #[prop_or_default]
+    congruences: String,
+    #[prop_or_default]
+    result: Option<Result<String, String>>,
+    #[prop_or_default]
+    steps: Steps,
+}
+
+pub enum Msg {
+    Op(Op),
+    Arg(usize, String),
+    Congruences(String),
+}
+
+impl Model {
+    fn compute(&mut self) {
+        let op = self.props.op;
+        let mut steps = Steps::default();
+        let args = if op == Op::Crt {
+            if self.props.congruences.trim().is_empty() {
+                None
+            } else {
+                Some(parse_congruences(&self.props.congruences))
+            }
+        } else if self.props.args[..op.args().len()]
+            .iter()
+            .any(|a| a.trim().is_empty())
+        {
+            None
+        } else {
+            Some(
+                self.props.args[..op.args().len()]
+                    .iter()
+                    .map(|a| crate::expr::eval(a))
+                    .collect(),
+            )
+        };
+        self.props.result = args.map(|args| {
+            args.and_then(|args| {
+                if args.iter().any(|a| a.bits() > MAX_BITS) {
+                    return Err(format!("Numbers above 2^{} are not supported", MAX_BITS));
+                }
+                op.run(&args, &mut steps)
+            })
+        });
+        self.props.steps = steps;
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Op(op) => self.props.op = op,
+            Msg::Arg(i, text) => self.props.args[i] = text,
+            Msg::Congruences(text) => self.props.congruences = text,
+        }
+        self.compute();
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let op = self.props.op;
+
+        let inputs = if op == Op::Crt {
+            html! {
+                <div class="field">
+                    <label class="label">{"Congruences (one per line)"}</label>
+                    <div class="control">
+                        <textarea class="textarea" placeholder="x ≡ 2 (mod 3)\nx ≡ 3 (mod 5)\nx ≡ 2 (mod 7)"
+                            value=self.props.congruences.clone()
+                            oninput=self.link.callback(|e: InputData| Msg::Congruences(e.value))/>
+                    </div>
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                {
+                    for op.args().iter().enumerate().map(|(i, &label)| html!{
+                        <div class="field">
+                            <label class="label">{ label }</label>
+                            <div class="control">
+                                <input class="input" type="text"
+                                    value=self.props.args[i].clone()
+                                    oninput=self.link.callback(move |e: InputData| Msg::Arg(i, e.value))/>
+                            </div>
+                        </div>
+                    })
+                }
+                </>
+            }
+        };
+
+        let (result, error) = match &self.props.result {
+            Some(Ok(r)) => (r.clone(), false),
+            Some(Err(e)) => (e.clone(), true),
+            None => (String::new(), false),
+        };
+
+        html! {
+            <>
+            <div class="tabs is-boxed">
+                <ul>
+                {
+                    for OPS.iter().map(|&o| html!{
+                        <li class=if o == op {"is-active"} else {""}>
+                            <a onclick=self.link.callback(move |_| Msg::Op(o))>{ o.name() }</a>
+                        </li>
+                    })
+                }
+                </ul>
+            </div>
+
+            { inputs }
+
+            <div class="field">
+                <label class="label">{"Result"}</label>
+                <div class="control">
+                    <input class=if error {"input is-danger"} else {"input"} type="text" value=result readonly=true/>
+                </div>
+            </div>
+            <div class="field">
+                <label class="label">{"Steps"}</label>
+                <div class="control">
+                    <textarea class="textarea is-family-monospace" rows=12 value=self.props.steps.0.join("\n") readonly=true/>
+                </div>
+            </div>
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int(n: i64) -> BigInt {
+        BigInt::from(n)
+    }
+
+    #[test]
+    fn primality_reports_neighbours_of_small_numbers() {
+        let mut steps = Steps::default();
+        assert_eq!(
+            primality(&int(97), &mut steps).unwrap(),
+            "prime; next prime = 101, previous prime = 89"
+        );
+        assert_eq!(
+            primality(&int(91), &mut steps).unwrap(),
+            "composite; next prime = 97, previous prime = 89"
+        );
+    }
+
+    #[test]
+    fn primality_skips_the_neighbour_search_for_large_numbers() {
+        let n = (BigInt::one() << 300usize) + 1;
+        let result = primality(&n, &mut Steps::default()).unwrap();
+        assert!(result.ends_with("not searched above 2^256"), "{}", result);
+    }
+
+    #[test]
+    fn jacobi_symbol() {
+        let mut steps = Steps::default();
+        assert_eq!(jacobi(&int(1001), &int(9907), &mut steps), Ok(-1));
+        assert_eq!(jacobi(&int(2), &int(7), &mut steps), Ok(1));
+        assert!(jacobi(&int(1), &int(8), &mut steps).is_err());
+    }
+
+    #[test]
+    fn crt_combines_and_detects_conflicts() {
+        let mut steps = Steps::default();
+        let args = parse_congruences("x ≡ 2 (mod 3)\nx ≡ 3 (mod 5)\nx = 2 mod 7").unwrap();
+        assert_eq!(crt(&args, &mut steps).unwrap(), "x ≡ 23 (mod 105)");
+        assert!(crt(&[int(1), int(4), int(2), int(6)], &mut steps).is_err());
+    }
+
+    #[test]
+    fn inverse_and_modpow() {
+        let mut steps = Steps::default();
+        assert_eq!(inverse(&int(3), &int(7), &mut steps).unwrap(), "5");
+        assert_eq!(
+            modpow(&int(4), &int(13), &int(497), &mut steps).unwrap(),
+            "445"
+        );
+    }
+}
@@ -0,0 +1,636 @@
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum NumberWords {
+    Roman,
+    Kanji,
+    FormalKanji,
+    English,
+    EnglishOrdinal,
+}
+
+pub const NUMBER_WORDS: &[NumberWords] = &[
+    NumberWords::Roman,
+    NumberWords::Kanji,
+    NumberWords::FormalKanji,
+    NumberWords::English,
+    NumberWords::EnglishOrdinal,
+];
+
+const ROMAN: &[(u32, &str)] = &[
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+const KANJI_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+/// 大字 as used on invoices and in registrations: only the digits that are
+/// easily altered are replaced.
+const FORMAL_DIGITS: [char; 10] = ['零', '壱', '弐', '参', '四', '五', '六', '七', '八', '九'];
+
+/// 十, 百 and 千, indexed by the power of ten.
+const KANJI_UNITS: [char; 4] = ['\0', '十', '百', '千'];
+const FORMAL_UNITS: [char; 4] = ['\0', '拾', '百', '千'];
+
+/// Units for each group of four digits, starting from 10^4.
+const KANJI_GROUPS: [char; 12] = [
+    '万', '億', '兆', '京', '垓', '𥝱', '穣', '溝', '澗', '正', '載', '極',
+];
+
+const ONES: &[&str] = &[
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: &[&str] = &[
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Short scale names for each group of three digits, starting from 10^3.
+const SCALES: &[&str] = &[
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+];
+
+/// Ordinals that are not formed by simply appending "th".
+const IRREGULAR_ORDINALS: &[(&str, &str)] = &[
+    ("one", "first"),
+    ("two", "second"),
+    ("three", "third"),
+    ("five", "fifth"),
+    ("eight", "eighth"),
+    ("nine", "ninth"),
+    ("twelve", "twelfth"),
+];
+
+impl NumberWords {
+    pub fn name(self) -> &'static str {
+        match self {
+            NumberWords::Roman => "Roman numerals",
+            NumberWords::Kanji => "Kanji",
+            NumberWords::FormalKanji => "Kanji (大字)",
+            NumberWords::English => "English",
+            NumberWords::EnglishOrdinal => "English ordinal",
+        }
+    }
+
+    pub fn placeholder(self) -> &'static str {
+        match self {
+            NumberWords::Roman => "MMXXIV",
+            NumberWords::Kanji => "三千五百二十一",
+            NumberWords::FormalKanji => "参千五百弐拾壱",
+            NumberWords::English => "forty-two",
+            NumberWords::EnglishOrdinal => "forty-second",
+        }
+    }
+
+    /// Describes the values that can be written, for when `encode` fails.
+    pub fn range(self) -> &'static str {
+        match self {
+            NumberWords::Roman => "Roman numerals only cover 1 to 3999",
+            NumberWords::Kanji | NumberWords::FormalKanji => {
+                "Kanji numerals only go up to 極 (10^48)"
+            }
+            NumberWords::English | NumberWords::EnglishOrdinal => {
+                "English words only go up to decillions (10^33)"
+            }
+        }
+    }
+
+    pub fn encode(self, value: &BigUint) -> Option<String> {
+        match self {
+            NumberWords::Roman => to_roman(value.to_u32()?),
+            NumberWords::Kanji => to_kanji(value, false),
+            NumberWords::FormalKanji => to_kanji(value, true),
+            NumberWords::English => to_english(value),
+            NumberWords::EnglishOrdinal => to_english(value).map(|words| to_ordinal(&words)),
+        }
+    }
+
+    pub fn decode(self, text: &str) -> Option<BigUint> {
+        let text = text.trim();
+        match self {
+            NumberWords::Roman => from_roman(text).map(BigUint::from),
+            NumberWords::Kanji | NumberWords::FormalKanji => from_kanji(text),
+            NumberWords::English | NumberWords::EnglishOrdinal => from_english(text),
+        }
+    }
+}
+
+fn to_roman(mut value: u32) -> Option<String> {
+    if value == 0 || value > 3999 {
+        return None;
+    }
+    let mut roman = String::new();
+    for &(n, symbol) in ROMAN {
+        while value >= n {
+            roman.push_str(symbol);
+            value -= n;
+        }
+    }
+    Some(roman)
+}
+
+/// Parses a Roman numeral, accepting only the canonical subtractive form so
+/// that `IIII` or `IC` are rejected rather than silently read.
+fn from_roman(text: &str) -> Option<u32> {
+    let text = text.to_ascii_uppercase();
+    let values = text
+        .chars()
+        .map(|c| match c {
+            'I' => Some(1),
+            'V' => Some(5),
+            'X' => Some(10),
+            'L' => Some(50),
+            'C' => Some(100),
+            'D' => Some(500),
+            'M' => Some(1000),
+            _ => None,
+        })
+        .collect::<Option<Vec<u32>>>()?;
+
+    let mut value = 0i64;
+    for (i, &v) in values.iter().enumerate() {
+        if values.get(i + 1).is_some_and(|&next| next > v) {
+            value -= v as i64;
+        } else {
+            value += v as i64;
+        }
+    }
+    let value = value.to_u32()?;
+    if to_roman(value)? == text {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Writes a number below 10^4 with 十, 百 and 千. Plain kanji drop the 一 in
+/// front of a unit (十, not 一十), 大字 keep it (壱拾).
+fn kanji_group(mut value: u32, formal: bool) -> String {
+    let (digits, units) = if formal {
+        (FORMAL_DIGITS, FORMAL_UNITS)
+    } else {
+        (KANJI_DIGITS, KANJI_UNITS)
+    };
+    let mut s = String::new();
+    for power in (0..4).rev() {
+        let scale = 10u32.pow(power);
+        let digit = value / scale;
+        value %= scale;
+        if digit == 0 {
+            continue;
+        }
+        if power == 0 || digit != 1 || formal {
+            s.push(digits[digit as usize]);
+        }
+        if power > 0 {
+            s.push(units[power as usize]);
+        }
+    }
+    s
+}
+
+fn to_kanji(value: &BigUint, formal: bool) -> Option<String> {
+    if value.is_zero() {
+        return Some(if formal { "零" } else { "〇" }.to_string());
+    }
+
+    let mut groups = vec![];
+    let mut value = value.clone();
+    while !value.is_zero() {
+        groups.push((&value % 10_000u32).to_u32().unwrap());
+        value /= 10_000u32;
+    }
+    if groups.len() > KANJI_GROUPS.len() + 1 {
+        return None;
+    }
+
+    let mut s = String::new();
+    for (i, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        s.push_str(&kanji_group(group, formal));
+        if i > 0 {
+            s.push(match (formal, KANJI_GROUPS[i - 1]) {
+                (true, '万') => '萬',
+                (_, unit) => unit,
+            });
+        }
+    }
+    Some(s)
+}
+
+fn kanji_digit(c: char) -> Option<u32> {
+    match c {
+        '〇' | '零' => Some(0),
+        '一' | '壱' | '壹' => Some(1),
+        '二' | '弐' | '貳' => Some(2),
+        '三' | '参' | '參' => Some(3),
+        '四' | '肆' => Some(4),
+        '五' | '伍' => Some(5),
+        '六' | '陸' => Some(6),
+        '七' | '漆' | '柒' => Some(7),
+        '八' | '捌' => Some(8),
+        '九' | '玖' => Some(9),
+        '０'..='９' => Some(c as u32 - '０' as u32),
+        _ => c.to_digit(10),
+    }
+}
+
+fn kanji_unit(c: char) -> Option<u32> {
+    match c {
+        '十' | '拾' => Some(1),
+        '百' | '佰' | '陌' => Some(2),
+        '千' | '阡' | '仟' => Some(3),
+        _ => None,
+    }
+}
+
+fn kanji_group_unit(c: char) -> Option<usize> {
+    if c == '萬' {
+        return Some(1);
+    }
+    KANJI_GROUPS.iter().position(|&g| g == c).map(|i| i + 1)
+}
+
+/// Parses kanji numerals such as `三千五百二十一`, `壱萬弐千円`, `3万5千` or
+/// the positional `二〇二四`.
+pub fn from_kanji(text: &str) -> Option<BigUint> {
+    let text: String = text
+        .trim_start_matches('金')
+        .trim_end_matches(['円', '也'])
+        .chars()
+        .filter(|c| !matches!(c, ',' | '，' | '、' | ' ' | '　'))
+        .collect();
+    if text.is_empty() {
+        return None;
+    }
+
+    // Without any unit the digits are positional, as in years.
+    if !text
+        .chars()
+        .any(|c| kanji_unit(c).is_some() || kanji_group_unit(c).is_some())
+    {
+        let digits = text
+            .chars()
+            .map(|c| kanji_digit(c).map(|d| char::from(b'0' + d as u8)))
+            .collect::<Option<String>>()?;
+        return BigUint::parse_bytes(digits.as_bytes(), 10);
+    }
+
+    let mut total = BigUint::zero();
+    // The part below the next group unit, and the digits not yet followed
+    // by a unit.
+    let mut section = 0u32;
+    let mut digits: Option<u32> = None;
+    let mut last_unit = 4;
+    let mut last_group = KANJI_GROUPS.len() + 1;
+
+    for c in text.chars() {
+        if let Some(d) = kanji_digit(c) {
+            digits = Some(digits.unwrap_or(0).checked_mul(10)?.checked_add(d)?);
+        } else if let Some(unit) = kanji_unit(c) {
+            if unit >= last_unit {
+                return None;
+            }
+            last_unit = unit;
+            section += digits.take().unwrap_or(1).checked_mul(10u32.pow(unit))?;
+        } else if let Some(group) = kanji_group_unit(c) {
+            if group >= last_group {
+                return None;
+            }
+            last_group = group;
+            last_unit = 4;
+            let value = section + digits.take().unwrap_or(0);
+            if value == 0 {
+                return None;
+            }
+            total += BigUint::from(value) * BigUint::from(10_000u32).pow(group as u32);
+            section = 0;
+        } else {
+            return None;
+        }
+    }
+
+    Some(total + section + digits.unwrap_or(0))
+}
+
+/// Writes a number below 1000 in words, e.g. "five hundred twenty-one".
+fn english_group(value: usize) -> String {
+    let mut words = vec![];
+    if value >= 100 {
+        words.push(format!("{} hundred", ONES[value / 100]));
+    }
+    let rest = value % 100;
+    if rest >= 20 {
+        if rest.is_multiple_of(10) {
+            words.push(TENS[rest / 10].to_string());
+        } else {
+            words.push(format!("{}-{}", TENS[rest / 10], ONES[rest % 10]));
+        }
+    } else if rest > 0 {
+        words.push(ONES[rest].to_string());
+    }
+    words.join(" ")
+}
+
+fn to_english(value: &BigUint) -> Option<String> {
+    if value.is_zero() {
+        return Some(ONES[0].to_string());
+    }
+
+    let mut groups = vec![];
+    let mut value = value.clone();
+    while !value.is_zero() {
+        groups.push((&value % 1000u32).to_usize().unwrap());
+        value /= 1000u32;
+    }
+    if groups.len() > SCALES.len() + 1 {
+        return None;
+    }
+
+    let mut words = vec![];
+    for (i, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        words.push(english_group(group));
+        if i > 0 {
+            words.push(SCALES[i - 1].to_string());
+        }
+    }
+    Some(words.join(" "))
+}
+
+/// Turns cardinal words into ordinal ones by changing the last word, so
+/// that "twenty-one" becomes "twenty-first".
+fn to_ordinal(words: &str) -> String {
+    let split = words.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = words.split_at(split);
+    let last = match IRREGULAR_ORDINALS
+        .iter()
+        .find(|(cardinal, _)| *cardinal == last)
+    {
+        Some((_, ordinal)) => ordinal.to_string(),
+        None => match last.strip_suffix('y') {
+            Some(stem) => format!("{}ieth", stem),
+            None => format!("{}th", last),
+        },
+    };
+    format!("{}{}", head, last)
+}
+
+fn cardinal_word(word: &str) -> String {
+    if let Some((cardinal, _)) = IRREGULAR_ORDINALS.iter().find(|(_, o)| *o == word) {
+        return cardinal.to_string();
+    }
+    if let Some(stem) = word.strip_suffix("ieth") {
+        return format!("{}y", stem);
+    }
+    word.strip_suffix("th").unwrap_or(word).to_string()
+}
+
+/// Parses English cardinal or ordinal words such as "three thousand five
+/// hundred and twenty-first".
+fn from_english(text: &str) -> Option<BigUint> {
+    let text = text.to_lowercase();
+    let words: Vec<String> = text
+        .split(|c: char| c.is_whitespace() || c == '-' || c == ',')
+        .filter(|w| !w.is_empty() && *w != "and")
+        .map(cardinal_word)
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let mut total = BigUint::zero();
+    let mut group = 0usize;
+    let mut last_scale = SCALES.len();
+
+    for (i, word) in words.iter().enumerate() {
+        if let Some(n) = ONES.iter().position(|w| w == word) {
+            if !group.is_multiple_of(100) && (n >= 10 || !group.is_multiple_of(10)) {
+                return None;
+            }
+            group += n;
+        } else if let Some(n) = TENS.iter().position(|w| !w.is_empty() && w == word) {
+            if !group.is_multiple_of(100) {
+                return None;
+            }
+            group += n * 10;
+        } else if word == "a" && i + 1 < words.len() {
+            group += 1;
+        } else if word == "hundred" {
+            if group >= 10 {
+                return None;
+            }
+            group = group.max(1) * 100;
+        } else if let Some(scale) = SCALES.iter().position(|w| w == word) {
+            if scale >= last_scale || group == 0 {
+                return None;
+            }
+            last_scale = scale;
+            total += BigUint::from(group) * BigUint::from(1000u32).pow(scale as u32 + 1);
+            group = 0;
+        } else {
+            return None;
+        }
+    }
+
+    Some(total + group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(words: NumberWords, value: u64) -> Option<String> {
+        words.encode(&BigUint::from(value))
+    }
+
+    fn decode(words: NumberWords, text: &str) -> Option<u64> {
+        words.decode(text).and_then(|n| n.to_u64())
+    }
+
+    fn pow10(exp: u32) -> BigUint {
+        BigUint::from(10u32).pow(exp)
+    }
+
+    #[test]
+    fn roman_round_trips_and_boundaries() {
+        for value in 1..=3999 {
+            let roman = encode(NumberWords::Roman, value).unwrap();
+            assert_eq!(decode(NumberWords::Roman, &roman), Some(value));
+        }
+        assert_eq!(encode(NumberWords::Roman, 2024).as_deref(), Some("MMXXIV"));
+        assert_eq!(
+            encode(NumberWords::Roman, 3999).as_deref(),
+            Some("MMMCMXCIX")
+        );
+        assert_eq!(encode(NumberWords::Roman, 4000), None);
+        assert_eq!(encode(NumberWords::Roman, 0), None);
+        assert_eq!(decode(NumberWords::Roman, "mmxxiv"), Some(2024));
+        for text in &["MMMM", "IIII", "IC", "VX", "", "-I", "N"] {
+            assert_eq!(decode(NumberWords::Roman, text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn kanji_round_trips() {
+        for &value in &[0, 1, 10, 11, 21, 100, 1000, 3521, 10_000, 110_000, 12_000] {
+            for &words in &[NumberWords::Kanji, NumberWords::FormalKanji] {
+                let kanji = encode(words, value).unwrap();
+                assert_eq!(decode(words, &kanji), Some(value), "{}", kanji);
+            }
+        }
+        for value in [pow10(52) - 1u32, pow10(48), pow10(12) + 3u32] {
+            let kanji = NumberWords::Kanji.encode(&value).unwrap();
+            assert_eq!(NumberWords::Kanji.decode(&kanji), Some(value));
+        }
+        assert_eq!(NumberWords::Kanji.encode(&pow10(52)), None);
+    }
+
+    #[test]
+    fn kanji_units() {
+        let kanji = |value| encode(NumberWords::Kanji, value).unwrap();
+        let formal = |value| encode(NumberWords::FormalKanji, value).unwrap();
+        assert_eq!(kanji(0), "〇");
+        assert_eq!(kanji(10), "十");
+        assert_eq!(kanji(3521), "三千五百二十一");
+        assert_eq!(kanji(110_000), "十一万");
+        assert_eq!(kanji(100_000_000), "一億");
+        assert_eq!(kanji(1_0002_0000_0003), "一兆二億三");
+        assert_eq!(formal(0), "零");
+        assert_eq!(formal(10), "壱拾");
+        assert_eq!(formal(3521), "参千五百弐拾壱");
+        assert_eq!(formal(12_000), "壱萬弐千");
+        assert_eq!(formal(2_0000_0000), "弐億");
+
+        assert_eq!(decode(NumberWords::Kanji, "二〇二四"), Some(2024));
+        assert_eq!(decode(NumberWords::Kanji, "3万5千"), Some(35_000));
+        assert_eq!(decode(NumberWords::Kanji, "金壱萬弐千円也"), Some(12_000));
+        assert_eq!(decode(NumberWords::Kanji, "五億三千万"), Some(530_000_000));
+        assert_eq!(
+            decode(NumberWords::Kanji, "一兆二億"),
+            Some(1_0002_0000_0000)
+        );
+        for text in &[
+            "",
+            "千千",
+            "十百",
+            "万億",
+            "兆",
+            "一兆一兆",
+            "マイナス五",
+            "-5",
+        ] {
+            assert_eq!(decode(NumberWords::Kanji, text), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn english_round_trips() {
+        for value in (0..=1000).chain([1001, 1_000_000, 1_002_003, 123_456_789]) {
+            let words = encode(NumberWords::English, value).unwrap();
+            assert_eq!(
+                decode(NumberWords::English, &words),
+                Some(value),
+                "{}",
+                words
+            );
+            let ordinal = encode(NumberWords::EnglishOrdinal, value).unwrap();
+            assert_eq!(
+                decode(NumberWords::EnglishOrdinal, &ordinal),
+                Some(value),
+                "{}",
+                ordinal
+            );
+        }
+        let max = pow10(36) - 1u32;
+        let words = NumberWords::English.encode(&max).unwrap();
+        assert!(words.starts_with("nine hundred ninety-nine decillion"));
+        assert_eq!(NumberWords::English.decode(&words), Some(max));
+        assert_eq!(NumberWords::English.encode(&pow10(36)), None);
+    }
+
+    #[test]
+    fn english_words() {
+        let english = |value| encode(NumberWords::English, value).unwrap();
+        let ordinal = |value| encode(NumberWords::EnglishOrdinal, value).unwrap();
+        assert_eq!(english(0), "zero");
+        assert_eq!(english(42), "forty-two");
+        assert_eq!(english(1_002_003), "one million two thousand three");
+        assert_eq!(ordinal(0), "zeroth");
+        assert_eq!(ordinal(12), "twelfth");
+        assert_eq!(ordinal(20), "twentieth");
+        assert_eq!(ordinal(21), "twenty-first");
+        assert_eq!(ordinal(101), "one hundred first");
+
+        assert_eq!(decode(NumberWords::English, "a hundred"), Some(100));
+        assert_eq!(
+            decode(NumberWords::English, "One Hundred and Five"),
+            Some(105)
+        );
+        assert_eq!(
+            decode(
+                NumberWords::English,
+                "three thousand, five hundred and twenty-first"
+            ),
+            Some(3521)
+        );
+        for text in &[
+            "",
+            "a",
+            "thousand",
+            "minus five",
+            "-5",
+            "twenty twenty",
+            "five twelve",
+            "one thousand one million",
+            "ten hundred",
+        ] {
+            assert_eq!(decode(NumberWords::English, text), None, "{}", text);
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use crate::primes::{divisor_functions, is_proven_range, Factorizer, MAX_BITS};
+use crate::util::horizontal_field;
+use num_bigint::{BigUint, Sign};
+use num_traits::Zero;
+use std::time::Duration;
+use yew::prelude::*;
+use yew::services::timeout::{TimeoutService, TimeoutTask};
+
+/// Work done per tick, in modular multiplications. Small enough that the
+/// page stays responsive between ticks.
+const STEP_BUDGET: u64 = 2000;
+
+/// Evaluates the input as an expression and checks that it can be factored.
+fn parse_input(text: &str) -> Result<BigUint, String> {
+    let n = crate::expr::eval(text)?;
+    if n.bits() > MAX_BITS {
+        Err(format!("Numbers above 2^{} are not supported", MAX_BITS))
+    } else if n.is_zero() {
+        Err("0 has no factorization".to_string())
+    } else if n.sign() == Sign::Minus {
+        Err("Enter a positive integer".to_string())
+    } else {
+        Ok(n.magnitude().clone())
+    }
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+    factorizer: Option<Factorizer>,
+    task: Option<TimeoutTask>,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    input: String,
+    #[prop_or_default]
+    error: Option<String>,
+    #[prop_or_default]
+    cancelled: bool,
+}
+
+pub enum Msg {
+    Input(String),
+    Factorize,
+    Step,
+    Cancel,
+}
+
+impl Model {
+    fn schedule(&mut self) {
+        self.task = Some(TimeoutService::spawn(
+            Duration::from_millis(0),
+            self.link.callback(|_| Msg::Step),
+        ));
+    }
+
+    fn view_result(&self, factorizer: &Factorizer) -> Html {
+        let factors = factorizer.factors();
+        let mut terms: Vec<String> = factors
+            .iter()
+            .map(|(p, &e)| {
+                if e == 1 {
+                    p.to_string()
+                } else {
+                    format!("{}^{}", p, e)
+                }
+            })
+            .collect();
+        terms.extend(factorizer.pending().map(|c| format!("({})", c)));
+        let product = if terms.is_empty() {
+            "1".to_string()
+        } else {
+            terms.join(" × ")
+        };
+
+        let status = if factorizer.is_done() {
+            "Done".to_string()
+        } else if self.props.cancelled {
+            "Cancelled; parts not known to be prime are shown in parentheses".to_string()
+        } else {
+            factorizer.status()
+        };
+
+        let input = |s: String| {
+            html! {
+                <input class="input" type="text" value=s readonly=true/>
+            }
+        };
+
+        html! {
+            <>
+            { horizontal_field("Status", input(status)) }
+            { horizontal_field("Factorization", html!{
+                <textarea class="textarea" value=product readonly=true/>
+            }) }
+
+            <table class="table is-fullwidth">
+                <thead>
+                    <tr><th>{"Prime"}</th><th>{"Exponent"}</th><th>{"Proof"}</th></tr>
+                </thead>
+                <tbody>
+                {
+                    for factors.iter().map(|(p, e)| html!{
+                        <tr>
+                            <td style="word-break: break-all;">{ p }</td>
+                            <td>{ e }</td>
+                            <td>{ if is_proven_range(p) { "Prime" } else { "Probable prime" } }</td>
+                        </tr>
+                    })
+                }
+                </tbody>
+            </table>
+
+            {
+                if factorizer.is_done() {
+                    let (count, sum, totient) = divisor_functions(factors);
+                    html! {
+                        <>
+                        { horizontal_field("Divisors", input(count.to_string())) }
+                        { horizontal_field("Sum of divisors", input(sum.to_string())) }
+                        { horizontal_field("Totient", input(totient.to_string())) }
+                        </>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+            </>
+        }
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            factorizer: None,
+            task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(text) => {
+                self.props.input = text;
+                self.props.error = None;
+            }
+            Msg::Factorize => {
+                self.task = None;
+                self.factorizer = None;
+                self.props.cancelled = false;
+                match parse_input(&self.props.input) {
+                    Ok(n) => {
+                        self.factorizer = Some(Factorizer::new(&n));
+                        self.schedule();
+                    }
+                    Err(e) => self.props.error = Some(e),
+                }
+            }
+            Msg::Step => {
+                if let Some(factorizer) = &mut self.factorizer {
+                    factorizer.step(STEP_BUDGET);
+                    if factorizer.is_done() {
+                        self.task = None;
+                    } else {
+                        self.schedule();
+                    }
+                }
+            }
+            Msg::Cancel => {
+                self.task = None;
+                self.props.cancelled = true;
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let mut classes = vec!["input"];
+        if self.props.error.is_some() {
+            classes.push("is-danger");
+        }
+        let running = self.task.is_some();
+
+        html! {
+            <>
+            <div class="field has-addons">
+                <div class="control is-expanded">
+                    <input class=classes type="text" placeholder="2**128 + 1"
+                        value=self.props.input.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))
+                        onkeypress=self.link.batch_callback(|e: KeyboardEvent| {
+                            if e.key() == "Enter" { vec![Msg::Factorize] } else { vec![] }
+                        })/>
+                </div>
+                <div class="control">
+                    <button class="button is-info" disabled=running
+                        onclick=self.link.callback(|_| Msg::Factorize)>{"Factorize"}</button>
+                </div>
+                <div class="control">
+                    <button class="button" disabled=!running
+                        onclick=self.link.callback(|_| Msg::Cancel)>{"Cancel"}</button>
+                </div>
+            </div>
+            {
+                if let Some(e) = &self.props.error {
+                    html! { <p class="help is-danger">{ e }</p> }
+                } else {
+                    html! {}
+                }
+            }
+
+            { self.factorizer.as_ref().map_or(html!{}, |f| self.view_result(f)) }
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_input_accepts_positive_expressions() {
+        assert_eq!(parse_input("2**128 + 1").map(|n| n.bits()), Ok(129));
+        assert_eq!(parse_input("2**2048 - 1").map(|n| n.bits()), Ok(2048));
+        assert_eq!(
+            parse_input("2**2048"),
+            Err("Numbers above 2^2048 are not supported".to_string())
+        );
+        assert_eq!(parse_input("0"), Err("0 has no factorization".to_string()));
+        assert_eq!(
+            parse_input("-6"),
+            Err("Enter a positive integer".to_string())
+        );
+        assert!(parse_input("1 +").is_err());
+    }
+}
@@ -0,0 +1,804 @@
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::cmp::min;
+use std::collections::BTreeMap;
+
+/// Largest input in bits. A single Miller–Rabin round is the smallest unit
+/// of work a step can do; at this size it takes about 4 ms natively, and
+/// doubling the size makes it eight times slower.
+pub const MAX_BITS: u64 = 2048;
+
+/// Primes below this are found by trial division before anything else.
+const TRIAL_LIMIT: u32 = 1 << 16;
+
+/// Miller–Rabin with these bases is exact below 3.3 * 10^24.
+const MR_BASES: &[u32] = &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Extra random bases used above that bound, making the test probabilistic
+/// with an error rate below 4^-20.
+const MR_EXTRA_ROUNDS: usize = 20;
+
+/// Iterations of Pollard's rho before switching to ECM.
+const RHO_LIMIT: u64 = 1 << 20;
+
+/// Rho iterations between gcd computations.
+const RHO_BATCH: u64 = 128;
+
+/// ECM stage 1 bounds and how many curves to try at each, following the
+/// usual table for 15, 20, 25, 30 and 35 digit factors.
+const ECM_SCHEDULE: &[(u64, usize)] = &[
+    (2_000, 25),
+    (11_000, 90),
+    (50_000, 300),
+    (250_000, 700),
+    (1_000_000, usize::MAX),
+];
+
+/// Primes below `limit`, by the sieve of Eratosthenes.
+pub fn primes_below(limit: u64) -> Vec<u64> {
+    let limit = limit as usize;
+    let mut sieve = vec![true; limit];
+    let mut primes = vec![];
+    for i in 2..limit {
+        if sieve[i] {
+            primes.push(i as u64);
+            for j in (i * i..limit).step_by(i) {
+                sieve[j] = false;
+            }
+        }
+    }
+    primes
+}
+
+/// A small xorshift generator; the algorithms only need varied parameters,
+/// not unpredictable ones.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A uniformly distributed value in `[lo, hi)`.
+    pub fn below(&mut self, lo: &BigUint, hi: &BigUint) -> BigUint {
+        let range = hi - lo;
+        let words = (range.bits() / 32 + 2) as usize;
+        let digits: Vec<u32> = (0..words).map(|_| self.next_u64() as u32).collect();
+        lo + BigUint::new(digits) % range
+    }
+}
+
+/// One round of Miller–Rabin: whether `n` is a strong probable prime to
+/// `base`. `n` must be odd and greater than `base`.
+pub fn miller_rabin(n: &BigUint, base: &BigUint) -> bool {
+    let n1: BigUint = n - 1u32;
+    let s = n1.trailing_zeros().unwrap();
+    let d = &n1 >> s as usize;
+    let mut x = base.modpow(&d, n);
+    if x.is_one() || x == n1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = &x * &x % n;
+        if x == n1 {
+            return true;
+        }
+        if x.is_one() {
+            return false;
+        }
+    }
+    false
+}
+
+/// Whether `n` is prime. The answer is exact below 3.3 * 10^24 and a
+/// probable prime test above.
+pub fn is_prime(n: &BigUint) -> bool {
+    PrimalityTest::new(n.clone()).run(u64::MAX).unwrap()
+}
+
+/// The test behind `is_prime`, resumable so that the Miller–Rabin rounds
+/// can be spread over several ticks.
+struct PrimalityTest {
+    n: BigUint,
+    round: usize,
+    rng: Rng,
+}
+
+impl PrimalityTest {
+    fn new(n: BigUint) -> Self {
+        let rng = Rng::new(n.to_u64_digits().first().copied().unwrap_or(0));
+        Self { n, round: 0, rng }
+    }
+
+    fn rounds(&self) -> usize {
+        if is_proven_range(&self.n) {
+            MR_BASES.len()
+        } else {
+            MR_BASES.len() + MR_EXTRA_ROUNDS
+        }
+    }
+
+    /// Runs rounds worth about `budget` modular multiplications, at least
+    /// one. Returns whether `n` is prime once that is known.
+    fn run(&mut self, mut budget: u64) -> Option<bool> {
+        let n = &self.n;
+        if self.round == 0 {
+            if let Some(n) = n.to_u32() {
+                if n < 2 {
+                    return Some(false);
+                }
+                if MR_BASES.contains(&n) {
+                    return Some(true);
+                }
+            }
+            if MR_BASES.iter().any(|&p| (n % p).is_zero()) {
+                return Some(false);
+            }
+        }
+        let rounds = self.rounds();
+        while self.round < rounds {
+            let base = match MR_BASES.get(self.round) {
+                Some(&b) => BigUint::from(b),
+                None => self.rng.below(&BigUint::from(2u32), &(n - 1u32)),
+            };
+            self.round += 1;
+            if !miller_rabin(n, &base) {
+                return Some(false);
+            }
+            budget = budget.saturating_sub(n.bits());
+            if budget == 0 && self.round < rounds {
+                return None;
+            }
+        }
+        Some(true)
+    }
+}
+
+/// Whether `is_prime` gives a proven answer for `n`.
+pub fn is_proven_range(n: &BigUint) -> bool {
+    *n < BigUint::parse_bytes(b"3317044064679887385961981", 10).unwrap()
+}
+
+/// The inverse of `a` modulo `n`, or the non-trivial gcd that prevents it.
+pub fn mod_inverse(a: &BigUint, n: &BigUint) -> Result<BigUint, BigUint> {
+    let n = BigInt::from(n.clone());
+    let e = BigInt::from(a.clone()).extended_gcd(&n);
+    if e.gcd.is_one() {
+        Ok(e.x.mod_floor(&n).to_biguint().unwrap())
+    } else {
+        Err(e.gcd.abs().to_biguint().unwrap())
+    }
+}
+
+/// Pollard's rho with Brent's cycle detection, resumable so that the work
+/// can be spread over several ticks.
+struct Rho {
+    c: BigUint,
+    x: BigUint,
+    y: BigUint,
+    ys: BigUint,
+    q: BigUint,
+    r: u64,
+    k: u64,
+    advance: u64,
+    iterations: u64,
+}
+
+impl Rho {
+    fn new(rng: &mut Rng, n: &BigUint) -> Self {
+        let (lo, hi) = (BigUint::one(), n - 1u32);
+        let y = rng.below(&lo, &hi);
+        Self {
+            c: rng.below(&lo, &hi),
+            x: y.clone(),
+            ys: y.clone(),
+            y,
+            q: BigUint::one(),
+            r: 0,
+            k: 0,
+            advance: 0,
+            iterations: 0,
+        }
+    }
+
+    fn f(&self, y: &BigUint, n: &BigUint) -> BigUint {
+        (y * y + &self.c) % n
+    }
+
+    /// Runs about `budget` iterations. Returns the gcd found, which is `n`
+    /// itself if this choice of constant failed.
+    fn run(&mut self, n: &BigUint, mut budget: u64) -> Option<BigUint> {
+        while budget > 0 {
+            if self.advance > 0 {
+                self.y = self.f(&self.y, n);
+                self.advance -= 1;
+                self.iterations += 1;
+                budget -= 1;
+            } else if self.k >= self.r {
+                self.r = if self.r == 0 { 1 } else { self.r * 2 };
+                self.x = self.y.clone();
+                self.advance = self.r;
+                self.k = 0;
+            } else {
+                self.ys = self.y.clone();
+                let steps = min(RHO_BATCH, self.r - self.k);
+                for _ in 0..steps {
+                    self.y = self.f(&self.y, n);
+                    self.q = &self.q * abs_diff(&self.x, &self.y) % n;
+                }
+                self.k += steps;
+                self.iterations += steps;
+                budget = budget.saturating_sub(steps);
+
+                let g = self.q.gcd(n);
+                if g.is_one() {
+                    continue;
+                }
+                if g != *n {
+                    return Some(g);
+                }
+                // The batch overshot; redo it one step at a time.
+                loop {
+                    self.ys = self.f(&self.ys, n);
+                    let g = abs_diff(&self.x, &self.ys).gcd(n);
+                    if !g.is_one() {
+                        return Some(g);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+fn abs_diff(a: &BigUint, b: &BigUint) -> BigUint {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// A point on a Montgomery curve in projective `(X : Z)` coordinates.
+type Point = (BigUint, BigUint);
+
+/// Lenstra's elliptic curve method on Montgomery curves with Suyama's
+/// parametrization: stage 1 up to `b1`, then the standard continuation up
+/// to `b2` with baby and giant steps.
+struct Ecm {
+    b1: u64,
+    b2: u64,
+    a24: BigUint,
+    q: Point,
+    prime: usize,
+    stage2: Option<Stage2>,
+}
+
+struct Stage2 {
+    /// `j * Q` for `j` below `ECM_WHEEL / 2` and coprime to it.
+    baby: Vec<Point>,
+    /// `ECM_WHEEL * Q`.
+    wheel: Point,
+    /// `m * ECM_WHEEL * Q` and the previous giant step.
+    giant: Point,
+    prev: Point,
+    m: u64,
+    acc: BigUint,
+}
+
+/// Modular multiplications per bit of a Montgomery ladder: one doubling
+/// and one differential addition.
+const LADDER_COST: u64 = 11;
+
+const ECM_WHEEL: u64 = 210;
+
+impl Ecm {
+    /// Sets up a random curve, or returns a factor if one shows up while
+    /// doing so.
+    fn new(rng: &mut Rng, n: &BigUint, b1: u64) -> Result<Self, BigUint> {
+        let sigma = rng.below(&BigUint::from(6u32), &BigUint::from(u32::MAX));
+        let u = (&sigma * &sigma + n - 5u32) % n;
+        let v = (&sigma * 4u32) % n;
+        let x = u.modpow(&BigUint::from(3u32), n);
+        let z = v.modpow(&BigUint::from(3u32), n);
+        let vu = (&v + n - &u) % n;
+        let num = vu.modpow(&BigUint::from(3u32), n) * ((&u * 3u32 + &v) % n) % n;
+        let den = (&x * &v * 16u32) % n;
+        let inv = mod_inverse(&den, n)?;
+        Ok(Self {
+            b1,
+            b2: b1 * 100,
+            a24: num * inv % n,
+            q: (x, z),
+            prime: 0,
+            stage2: None,
+        })
+    }
+
+    fn double(&self, (x, z): &Point, n: &BigUint) -> Point {
+        let s = (x + z) % n;
+        let d = (x + n - z) % n;
+        let t1 = &s * &s % n;
+        let t2 = &d * &d % n;
+        let t3 = (&t1 + n - &t2) % n;
+        let x2 = &t1 * &t2 % n;
+        let z2 = &t3 * ((&t2 + &self.a24 * &t3) % n) % n;
+        (x2, z2)
+    }
+
+    /// `P + Q` given `P - Q`.
+    fn add((xp, zp): &Point, (xq, zq): &Point, (xd, zd): &Point, n: &BigUint) -> Point {
+        let u = (xp + n - zp) * (xq + zq) % n;
+        let v = (xp + zp) * (xq + n - zq) % n;
+        let s = (&u + &v) % n;
+        let d = (&u + n - &v) % n;
+        (zd * (&s * &s % n) % n, xd * (&d * &d % n) % n)
+    }
+
+    /// `k * P` by the Montgomery ladder; `k` must be positive.
+    fn multiply(&self, k: u64, p: &Point, n: &BigUint) -> Point {
+        let mut r0 = p.clone();
+        let mut r1 = self.double(p, n);
+        for bit in (0..63 - k.leading_zeros()).rev() {
+            if k >> bit & 1 == 1 {
+                r0 = Self::add(&r1, &r0, p, n);
+                r1 = self.double(&r1, n);
+            } else {
+                r1 = Self::add(&r0, &r1, p, n);
+                r0 = self.double(&r0, n);
+            }
+        }
+        r0
+    }
+
+    fn start_stage2(&self, n: &BigUint) -> Stage2 {
+        let q = &self.q;
+        let q2 = self.double(q, n);
+        let mut baby = vec![];
+        let (mut prev, mut cur) = (q.clone(), q.clone());
+        for j in (1..ECM_WHEEL / 2).step_by(2) {
+            if j.gcd(&ECM_WHEEL) == 1 {
+                baby.push(cur.clone());
+            }
+            let next = Self::add(&cur, &q2, &prev, n);
+            prev = cur;
+            cur = next;
+        }
+
+        let wheel = self.multiply(ECM_WHEEL, q, n);
+        let m = (self.b1 / ECM_WHEEL).max(2);
+        Stage2 {
+            baby,
+            giant: self.multiply(m, &wheel, n),
+            prev: self.multiply(m - 1, &wheel, n),
+            wheel,
+            m,
+            acc: BigUint::one(),
+        }
+    }
+
+    /// Does about `budget` modular multiplications of work, and at least
+    /// one prime or giant step. Returns the gcd found, `n` if the curve
+    /// failed, or `Some(1)` once both stages are exhausted.
+    fn run(&mut self, n: &BigUint, primes: &[u64], budget: u64) -> Option<BigUint> {
+        let mut spent = 0;
+        if let Some(stage2) = &mut self.stage2 {
+            let last = self.b2 / ECM_WHEEL + 1;
+            while stage2.m < last && spent < budget {
+                // Every prime in the range is m * ECM_WHEEL ± j for some
+                // baby step j, and their x-coordinates agree modulo p exactly
+                // when (m * ECM_WHEEL ± j) * Q is the identity modulo p.
+                let (xg, zg) = &stage2.giant;
+                for (xj, zj) in &stage2.baby {
+                    let d = (xg * zj % n + n - xj * zg % n) % n;
+                    stage2.acc = &stage2.acc * d % n;
+                }
+                let next = Self::add(&stage2.giant, &stage2.wheel, &stage2.prev, n);
+                stage2.prev = std::mem::replace(&mut stage2.giant, next);
+                stage2.m += 1;
+                spent += 3 * stage2.baby.len() as u64 + 6;
+            }
+
+            let g = stage2.acc.gcd(n);
+            return if !g.is_one() || stage2.m == last {
+                Some(g)
+            } else {
+                None
+            };
+        }
+
+        while self.prime < primes.len() && spent < budget {
+            let p = primes[self.prime];
+            let mut q = p;
+            while q <= self.b1 / p {
+                q *= p;
+            }
+            self.q = self.multiply(q, &self.q, n);
+            self.prime += 1;
+            spent += LADDER_COST * (64 - q.leading_zeros()) as u64;
+        }
+
+        let g = self.q.1.gcd(n);
+        if !g.is_one() {
+            return Some(g);
+        }
+        if self.prime == primes.len() {
+            self.stage2 = Some(self.start_stage2(n));
+        }
+        None
+    }
+}
+
+enum Method {
+    Rho(Rho),
+    Ecm(Ecm),
+}
+
+/// An incremental factorization. Call `step` until `is_done`.
+pub struct Factorizer {
+    factors: BTreeMap<BigUint, u32>,
+    /// Parts not tested for primality yet, and the test running on the
+    /// last one.
+    unchecked: Vec<BigUint>,
+    test: Option<PrimalityTest>,
+    /// Composite parts not split yet.
+    pending: Vec<BigUint>,
+    method: Option<Method>,
+    curves: usize,
+    rho_iterations: u64,
+    ecm_primes: Vec<u64>,
+    rng: Rng,
+}
+
+impl Factorizer {
+    /// Starts factoring `n`, which must be positive and at most `MAX_BITS`
+    /// bits. Trial division is done here; primality tests and the rest are
+    /// left for `step`.
+    pub fn new(n: &BigUint) -> Self {
+        let mut ret = Self {
+            factors: BTreeMap::new(),
+            unchecked: vec![],
+            test: None,
+            pending: vec![],
+            method: None,
+            curves: 0,
+            rho_iterations: 0,
+            ecm_primes: vec![],
+            rng: Rng::new(0x9e37_79b9_7f4a_7c15),
+        };
+
+        let mut n = n.clone();
+        for p in primes_below(TRIAL_LIMIT as u64) {
+            if BigUint::from(p * p) > n {
+                break;
+            }
+            while (&n % p).is_zero() {
+                *ret.factors.entry(BigUint::from(p)).or_default() += 1;
+                n /= p;
+            }
+        }
+        ret.push(n);
+        ret
+    }
+
+    fn push(&mut self, n: BigUint) {
+        if !n.is_one() {
+            self.unchecked.push(n);
+        }
+    }
+
+    fn push_composite(&mut self, n: BigUint) {
+        // Trial division already removed every prime below 2^16, so a root
+        // must be at least that large.
+        for k in (2..=(n.bits() / 16) as u32).rev() {
+            let root = n.nth_root(k);
+            if root.pow(k) == n {
+                for _ in 0..k {
+                    self.push(root.clone());
+                }
+                return;
+            }
+        }
+        self.pending.push(n);
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.unchecked.is_empty() && self.pending.is_empty()
+    }
+
+    /// The prime factors found so far with their multiplicities.
+    pub fn factors(&self) -> &BTreeMap<BigUint, u32> {
+        &self.factors
+    }
+
+    /// The parts not known to be prime yet.
+    pub fn pending(&self) -> impl Iterator<Item = &BigUint> {
+        self.pending.iter().chain(&self.unchecked)
+    }
+
+    /// Does roughly `budget` modular multiplications of work.
+    pub fn step(&mut self, budget: u64) {
+        if let Some(n) = self.unchecked.last() {
+            let test = self
+                .test
+                .get_or_insert_with(|| PrimalityTest::new(n.clone()));
+            if let Some(prime) = test.run(budget) {
+                self.test = None;
+                let n = self.unchecked.pop().unwrap();
+                if prime {
+                    *self.factors.entry(n).or_default() += 1;
+                } else {
+                    self.push_composite(n);
+                }
+            }
+            return;
+        }
+
+        let n = match self.pending.last() {
+            Some(n) => n.clone(),
+            None => return,
+        };
+
+        let found = match &mut self.method {
+            None => {
+                self.method = Some(if self.rho_iterations < RHO_LIMIT {
+                    Method::Rho(Rho::new(&mut self.rng, &n))
+                } else {
+                    let b1 = self.ecm_b1();
+                    if self.ecm_primes.last().is_none_or(|&p| p * 2 < b1) {
+                        self.ecm_primes = primes_below(b1 + 1);
+                    }
+                    self.curves += 1;
+                    match Ecm::new(&mut self.rng, &n, b1) {
+                        Ok(ecm) => Method::Ecm(ecm),
+                        Err(g) => {
+                            self.split(&n, g);
+                            return;
+                        }
+                    }
+                });
+                return;
+            }
+            Some(Method::Rho(rho)) => {
+                let before = rho.iterations;
+                let found = rho.run(&n, budget);
+                self.rho_iterations += rho.iterations - before;
+                found
+            }
+            Some(Method::Ecm(ecm)) => {
+                let primes = &self.ecm_primes;
+                let primes = &primes[..primes.partition_point(|&p| p <= ecm.b1)];
+                ecm.run(&n, primes, budget)
+            }
+        };
+
+        match found {
+            Some(g) => self.split(&n, g),
+            None => {
+                if self.rho_iterations >= RHO_LIMIT {
+                    if let Some(Method::Rho(_)) = self.method {
+                        self.method = None;
+                    }
+                }
+            }
+        }
+    }
+
+    fn split(&mut self, n: &BigUint, g: BigUint) {
+        self.method = None;
+        if g.is_one() || g == *n {
+            return;
+        }
+        self.pending.pop();
+        self.rho_iterations = 0;
+        self.curves = 0;
+        let cofactor = n / &g;
+        self.push(g);
+        self.push(cofactor);
+    }
+
+    fn ecm_b1(&self) -> u64 {
+        let mut curves = self.curves;
+        for &(b1, count) in ECM_SCHEDULE {
+            if curves < count {
+                return b1;
+            }
+            curves -= count;
+        }
+        unreachable!()
+    }
+
+    /// What is being tried on which number, for showing progress.
+    pub fn status(&self) -> String {
+        if let Some(n) = self.unchecked.last() {
+            let (round, rounds) = match &self.test {
+                Some(test) => (test.round, test.rounds()),
+                None => (0, 0),
+            };
+            return format!(
+                "Testing a {}-digit number for primality: round {}/{}",
+                n.to_str_radix(10).len(),
+                round,
+                rounds
+            );
+        }
+        let n = match self.pending.last() {
+            Some(n) => n,
+            None => return "Done".to_string(),
+        };
+        let digits = n.to_str_radix(10).len();
+        match &self.method {
+            Some(Method::Ecm(ecm)) => match &ecm.stage2 {
+                None => format!(
+                    "ECM on a {}-digit number: curve {}, B1 = {}, stage 1 {}/{} primes",
+                    digits,
+                    self.curves,
+                    ecm.b1,
+                    ecm.prime,
+                    self.ecm_primes.partition_point(|&p| p <= ecm.b1)
+                ),
+                Some(stage2) => format!(
+                    "ECM on a {}-digit number: curve {}, B2 = {}, stage 2 {}/{}",
+                    digits,
+                    self.curves,
+                    ecm.b2,
+                    stage2.m * ECM_WHEEL,
+                    ecm.b2
+                ),
+            },
+            _ => format!(
+                "Pollard's rho (Brent) on a {}-digit number: {} iterations",
+                digits, self.rho_iterations
+            ),
+        }
+    }
+}
+
+/// The number of divisors, the sum of divisors and Euler's totient of the
+/// number with the given factorization.
+pub fn divisor_functions(factors: &BTreeMap<BigUint, u32>) -> (BigUint, BigUint, BigUint) {
+    let mut count = BigUint::one();
+    let mut sum = BigUint::one();
+    let mut totient = BigUint::one();
+    for (p, &e) in factors {
+        count *= e + 1;
+        sum *= (p.pow(e + 1) - 1u32) / (p - 1u32);
+        totient *= p.pow(e - 1) * (p - 1u32);
+    }
+    (count, sum, totient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigUint {
+        BigUint::parse_bytes(s.as_bytes(), 10).unwrap()
+    }
+
+    fn factorize(n: &BigUint) -> Vec<(String, u32)> {
+        let mut factorizer = Factorizer::new(n);
+        while !factorizer.is_done() {
+            factorizer.step(2000);
+        }
+        factorizer
+            .factors()
+            .iter()
+            .map(|(p, &e)| (p.to_string(), e))
+            .collect()
+    }
+
+    fn pairs(factors: &[(&str, u32)]) -> Vec<(String, u32)> {
+        factors.iter().map(|&(p, e)| (p.to_string(), e)).collect()
+    }
+
+    #[test]
+    fn is_prime_rejects_pseudoprimes() {
+        // A Carmichael number, and strong pseudoprimes to the first 1, 4, 9
+        // and 12 prime bases.
+        for n in &[
+            "561",
+            "2047",
+            "3215031751",
+            "3825123056546413051",
+            "318665857834031151167461",
+        ] {
+            assert!(!is_prime(&big(n)), "{}", n);
+        }
+        for n in &["0", "1", "4", "1000000"] {
+            assert!(!is_prime(&big(n)), "{}", n);
+        }
+    }
+
+    #[test]
+    fn is_prime_accepts_primes() {
+        for n in &["2", "3", "41", "43", "65537", "2305843009213693951"] {
+            assert!(is_prime(&big(n)), "{}", n);
+        }
+        // 2^127 - 1, beyond the proven range.
+        let m127 = (BigUint::one() << 127usize) - 1u32;
+        assert!(!is_proven_range(&m127));
+        assert!(is_prime(&m127));
+        assert!(!is_prime(&(m127 + 2u32)));
+    }
+
+    #[test]
+    fn factorizes_semiprimes_and_prime_powers() {
+        assert_eq!(factorize(&big("1")), vec![]);
+        assert_eq!(
+            factorize(&big("360")),
+            pairs(&[("2", 3), ("3", 2), ("5", 1)])
+        );
+        assert_eq!(
+            factorize(&big("1000036000099")),
+            pairs(&[("1000003", 1), ("1000033", 1)])
+        );
+        // 2^64 + 1
+        assert_eq!(
+            factorize(&big("18446744073709551617")),
+            pairs(&[("274177", 1), ("67280421310721", 1)])
+        );
+        // 65537^3 and 3^40: a perfect power above the trial division limit,
+        // and one below it.
+        assert_eq!(factorize(&big("281487861809153")), pairs(&[("65537", 3)]));
+        assert_eq!(factorize(&BigUint::from(3u32).pow(40)), pairs(&[("3", 40)]));
+    }
+
+    #[test]
+    fn primality_tests_are_spread_over_steps() {
+        // 2^521 - 1 needs 33 rounds of 521 multiplications each.
+        let m521 = (BigUint::one() << 521usize) - 1u32;
+        let mut factorizer = Factorizer::new(&m521);
+        let mut steps = 0;
+        while !factorizer.is_done() {
+            factorizer.step(500);
+            steps += 1;
+        }
+        assert_eq!(steps, 33);
+        assert_eq!(factorizer.factors().get(&m521), Some(&1));
+    }
+
+    #[test]
+    fn ecm_finds_a_factor() {
+        let n = big("1000036000099");
+        let primes = primes_below(2001);
+        let mut rng = Rng::new(1);
+        let found = (0..50).find_map(|_| {
+            let mut ecm = match Ecm::new(&mut rng, &n, 2000) {
+                Ok(ecm) => ecm,
+                Err(g) => return Some(g),
+            };
+            loop {
+                if let Some(g) = ecm.run(&n, &primes, 2000) {
+                    return if g.is_one() || g == n { None } else { Some(g) };
+                }
+            }
+        });
+        let g = found.unwrap();
+        assert!(g == big("1000003") || g == big("1000033"));
+    }
+
+    #[test]
+    fn divisor_function_values() {
+        let factors = |pairs: &[(u32, u32)]| -> BTreeMap<BigUint, u32> {
+            pairs.iter().map(|&(p, e)| (BigUint::from(p), e)).collect()
+        };
+        let values = |pairs: &[(u32, u32)]| {
+            let (count, sum, totient) = divisor_functions(&factors(pairs));
+            (count.to_string(), sum.to_string(), totient.to_string())
+        };
+        let strings = |a: &str, b: &str, c: &str| (a.to_string(), b.to_string(), c.to_string());
+        assert_eq!(values(&[]), strings("1", "1", "1"));
+        assert_eq!(values(&[(2, 2), (3, 1)]), strings("6", "28", "4"));
+        assert_eq!(values(&[(7, 1)]), strings("2", "8", "6"));
+        assert_eq!(values(&[(2, 10)]), strings("11", "2047", "512"));
+    }
+}
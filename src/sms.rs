@@ -0,0 +1,162 @@
+/// The GSM 03.38 default alphabet in code order, with the escape code 0x1B
+/// left out since it only introduces the extension table.
+const BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+                     ¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+
+/// Characters of the extension table, which take an escape septet and so
+/// cost 2 septets each.
+const EXTENSION: &str = "\u{0C}^{}\\[~]|€";
+
+/// Capacity of a single message and of each part of a concatenated one,
+/// whose user data header takes 6 bytes (7 septets or 3 UCS-2 units).
+const GSM_SINGLE: usize = 160;
+const GSM_PART: usize = 153;
+const UCS2_SINGLE: usize = 70;
+const UCS2_PART: usize = 67;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Encoding {
+    Gsm7,
+    Ucs2,
+}
+
+impl Encoding {
+    pub fn name(self) -> &'static str {
+        match self {
+            Encoding::Gsm7 => "GSM-7",
+            Encoding::Ucs2 => "UCS-2",
+        }
+    }
+
+    fn unit(self) -> &'static str {
+        match self {
+            Encoding::Gsm7 => "septets",
+            Encoding::Ucs2 => "UTF-16 units",
+        }
+    }
+
+    fn capacity(self, parts: usize) -> usize {
+        match (self, parts) {
+            (Encoding::Gsm7, 1) => GSM_SINGLE,
+            (Encoding::Gsm7, _) => GSM_PART,
+            (Encoding::Ucs2, 1) => UCS2_SINGLE,
+            (Encoding::Ucs2, _) => UCS2_PART,
+        }
+    }
+}
+
+/// Number of septets `c` takes in GSM-7, or `None` if it is not in the
+/// alphabet.
+pub fn gsm_septets(c: char) -> Option<usize> {
+    if BASIC.contains(c) {
+        Some(1)
+    } else if EXTENSION.contains(c) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+#[derive(Clone)]
+pub struct Segments {
+    pub encoding: Encoding,
+    /// Total septets or UTF-16 units.
+    pub units: usize,
+    pub segments: usize,
+    /// Units left in the last segment.
+    pub remaining: usize,
+}
+
+impl Segments {
+    pub fn unit(&self) -> &'static str {
+        self.encoding.unit()
+    }
+}
+
+/// Splits `text` into SMS segments. GSM-7 is used when every character is
+/// in the alphabet, UCS-2 otherwise. An escaped GSM-7 character or a
+/// surrogate pair is never split across two parts, so a part may end with
+/// a unit unused.
+pub fn segments(text: &str) -> Segments {
+    let encoding = if text.chars().all(|c| gsm_septets(c).is_some()) {
+        Encoding::Gsm7
+    } else {
+        Encoding::Ucs2
+    };
+    let sizes: Vec<usize> = text
+        .chars()
+        .map(|c| match encoding {
+            Encoding::Gsm7 => gsm_septets(c).unwrap(),
+            Encoding::Ucs2 => c.len_utf16(),
+        })
+        .collect();
+    let units = sizes.iter().sum();
+
+    if units <= encoding.capacity(1) {
+        return Segments {
+            encoding,
+            units,
+            segments: if units == 0 { 0 } else { 1 },
+            remaining: encoding.capacity(1) - units,
+        };
+    }
+
+    let part = encoding.capacity(2);
+    let mut segments = 1;
+    let mut used = 0;
+    for size in sizes {
+        if used + size > part {
+            segments += 1;
+            used = 0;
+        }
+        used += size;
+    }
+    Segments {
+        encoding,
+        units,
+        segments,
+        remaining: part - used,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(text: &str) -> (&'static str, usize, usize, usize) {
+        let s = segments(text);
+        (s.encoding.name(), s.units, s.segments, s.remaining)
+    }
+
+    #[test]
+    fn gsm_alphabet() {
+        assert_eq!(gsm_septets('a'), Some(1));
+        assert_eq!(gsm_septets('é'), Some(1));
+        assert_eq!(gsm_septets('€'), Some(2));
+        assert_eq!(gsm_septets('{'), Some(2));
+        assert_eq!(gsm_septets('あ'), None);
+    }
+
+    #[test]
+    fn gsm7_segments() {
+        assert_eq!(summary(""), ("GSM-7", 0, 0, 160));
+        assert_eq!(summary(&"a".repeat(160)), ("GSM-7", 160, 1, 0));
+        assert_eq!(summary(&"a".repeat(161)), ("GSM-7", 161, 2, 145));
+        assert_eq!(summary("€"), ("GSM-7", 2, 1, 158));
+    }
+
+    #[test]
+    fn ucs2_segments() {
+        assert_eq!(summary("😀"), ("UCS-2", 2, 1, 68));
+        assert_eq!(summary(&"あ".repeat(70)), ("UCS-2", 70, 1, 0));
+        assert_eq!(summary(&"あ".repeat(71)), ("UCS-2", 71, 2, 63));
+    }
+
+    #[test]
+    fn escapes_and_surrogate_pairs_are_not_split() {
+        let text = format!("{}€{}", "a".repeat(152), "a".repeat(10));
+        assert_eq!(summary(&text), ("GSM-7", 164, 2, 141));
+        let text = format!("{}😀{}", "あ".repeat(66), "あ".repeat(10));
+        assert_eq!(summary(&text), ("UCS-2", 78, 2, 55));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::japanese::is_fullwidth;
+use crate::twitter::{tweet_length, MAX_WEIGHTED_LENGTH};
 use std::cmp::max;
 use url::Url;
 use web_sys::HtmlTextAreaElement;
@@ -6,10 +8,10 @@ use yew::prelude::*;
 const DEFAULT_INPUT: &str = "突然の死";
 
 fn char_width(c: char) -> usize {
-    if c.is_ascii() {
-        1
-    } else {
+    if is_fullwidth(c) {
         2
+    } else {
+        1
     }
 }
 
@@ -76,10 +78,12 @@ pub struct Model {
     link: ComponentLink<Self>,
     input: NodeRef,
     output: NodeRef,
+    length: usize,
 }
 
 pub enum Msg {
     Input,
+    Output,
     Tweet,
 }
 
@@ -92,6 +96,7 @@ impl Component for Model {
             link,
             input: NodeRef::default(),
             output: NodeRef::default(),
+            length: tweet_length(&sudden_death(DEFAULT_INPUT)).length,
         }
     }
 
@@ -102,13 +107,26 @@ impl Component for Model {
         match msg {
             Msg::Input => {
                 output.set_value(&sudden_death(&input.value()));
+                self.length = tweet_length(&output.value()).length;
+            }
+            Msg::Output => {
+                self.length = tweet_length(&output.value()).length;
             }
             Msg::Tweet => {
+                let window = web_sys::window().unwrap();
+                if self.length > MAX_WEIGHTED_LENGTH {
+                    let message = format!(
+                        "{}文字の上限を超えています（{}文字）。このままツイートしますか？",
+                        MAX_WEIGHTED_LENGTH, self.length
+                    );
+                    if !window.confirm_with_message(&message).unwrap_or(false) {
+                        return false;
+                    }
+                }
                 let text = output.value();
                 let mut url = Url::parse("https://twitter.com/intent/tweet").unwrap();
                 url.query_pairs_mut().append_pair("text", &text);
-                web_sys::window()
-                    .unwrap()
+                window
                     .open_with_url_and_target(url.as_str(), "_blank")
                     .unwrap();
             }
@@ -122,6 +140,11 @@ impl Component for Model {
 
     fn view(&self) -> yew::Html {
         let default_output = sudden_death(DEFAULT_INPUT);
+        let (output_classes, help_classes) = if self.length > MAX_WEIGHTED_LENGTH {
+            ("textarea is-danger", "help is-danger")
+        } else {
+            ("textarea", "help")
+        };
 
         html! {
             <>
@@ -138,10 +161,14 @@ impl Component for Model {
             <div class="field">
                 <label class="label">{"生成テキスト"}</label>
                 <div class="control">
-                    <textarea class="textarea" ref=self.output.clone()>
+                    <textarea class=output_classes
+                        oninput=self.link.callback(|_| Msg::Output) ref=self.output.clone()>
                         {default_output}
                     </textarea>
                 </div>
+                <p class=help_classes>
+                    { format!("{} / {}文字", self.length, MAX_WEIGHTED_LENGTH) }
+                </p>
             </div>
 
             <button class="button is-info" onclick=self.link.callback(|_| Msg::Tweet)>{"ツイート"}</button>
@@ -150,3 +177,16 @@ impl Component for Model {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ambiguous_is_wide_and_halfwidth_katakana_is_narrow() {
+        assert_eq!(char_width('○'), 2);
+        assert_eq!(char_width('ｶ'), 1);
+        assert_eq!(sudden_death("○○"), "＿人人人人＿\n＞　○○　＜\n￣Y^Y^Y^￣\n");
+        assert_eq!(sudden_death("ｶｶ"), "＿人人人＿\n＞　ｶｶ　＜\n￣Y^Y^￣\n");
+    }
+}
@@ -0,0 +1,518 @@
+use crate::util::horizontal_field;
+use chrono::{
+    DateTime, Datelike, Local, LocalResult, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc,
+};
+use chrono_tz::{Tz, TZ_VARIANTS};
+use std::convert::TryFrom;
+use std::time::Duration;
+use yew::prelude::*;
+use yew::services::interval::{IntervalService, IntervalTask};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// Seconds from the FILETIME epoch (1601-01-01) to the Unix epoch.
+const FILETIME_EPOCH_OFFSET: i128 = 11_644_473_600;
+
+/// Formats tried, in order, for timestamps that carry no UTC offset.
+const NAIVE_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+    "%Y/%m/%d %H:%M:%S%.f",
+    "%Y/%m/%d %H:%M",
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Unit {
+    Auto,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+    FileTime,
+}
+
+const UNITS: &[Unit] = &[
+    Unit::Auto,
+    Unit::Seconds,
+    Unit::Milliseconds,
+    Unit::Microseconds,
+    Unit::Nanoseconds,
+    Unit::FileTime,
+];
+
+impl Unit {
+    fn name(self) -> &'static str {
+        match self {
+            Unit::Auto => "Auto",
+            Unit::Seconds => "Seconds",
+            Unit::Milliseconds => "Milliseconds",
+            Unit::Microseconds => "Microseconds",
+            Unit::Nanoseconds => "Nanoseconds",
+            Unit::FileTime => "FILETIME",
+        }
+    }
+
+    /// Nanoseconds per tick.
+    fn scale(self) -> i128 {
+        match self {
+            Unit::Auto | Unit::Seconds => NANOS_PER_SEC,
+            Unit::Milliseconds => 1_000_000,
+            Unit::Microseconds => 1_000,
+            Unit::Nanoseconds => 1,
+            Unit::FileTime => 100,
+        }
+    }
+
+    /// Guesses the unit of a Unix timestamp from its magnitude: seconds
+    /// cover dates up to the year 5138, and each smaller unit takes over
+    /// where the previous one would be that far out.
+    fn detect(int: i128) -> Unit {
+        match int.abs() {
+            n if n < 100_000_000_000 => Unit::Seconds,
+            n if n < 100_000_000_000_000 => Unit::Milliseconds,
+            n if n < 100_000_000_000_000_000 => Unit::Microseconds,
+            _ => Unit::Nanoseconds,
+        }
+    }
+}
+
+/// Parses a number such as `1700000000`, `-1.5` or `1_700_000_000_123` in
+/// `unit`, returning nanoseconds since the Unix epoch and the unit used.
+fn parse_number(text: &str, unit: Unit) -> Option<(i128, Unit)> {
+    let text = text.replace(['_', ','], "");
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, &text[..]),
+    };
+    let (int, frac) = match digits.find('.') {
+        Some(i) => (&digits[..i], &digits[i + 1..]),
+        None => (digits, ""),
+    };
+    if int.is_empty() || !(int.chars().chain(frac.chars())).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let int: i128 = int.parse().ok()?;
+    let unit = if unit == Unit::Auto {
+        Unit::detect(int)
+    } else {
+        unit
+    };
+    let scale = unit.scale();
+    let mut frac_nanos = 0;
+    let mut place = scale;
+    for c in frac.chars() {
+        place /= 10;
+        frac_nanos += (c as i128 - '0' as i128) * place;
+    }
+    let mut nanos = int.checked_mul(scale)?.checked_add(frac_nanos)?;
+    if negative {
+        nanos = -nanos;
+    }
+    if unit == Unit::FileTime {
+        nanos -= FILETIME_EPOCH_OFFSET * NANOS_PER_SEC;
+    }
+    Some((nanos, unit))
+}
+
+fn from_nanos(nanos: i128) -> Option<DateTime<Utc>> {
+    let secs = nanos.div_euclid(NANOS_PER_SEC);
+    let subsec = nanos.rem_euclid(NANOS_PER_SEC) as u32;
+    match Utc.timestamp_opt(i64::try_from(secs).ok()?, subsec) {
+        LocalResult::Single(dt) => Some(dt),
+        _ => None,
+    }
+}
+
+fn to_nanos(dt: &DateTime<Utc>) -> i128 {
+    dt.timestamp() as i128 * NANOS_PER_SEC + dt.timestamp_subsec_nanos() as i128
+}
+
+/// Parses any of the supported inputs. Timestamps without an offset are
+/// read in `zone`. Also returns the unit a number was read in.
+fn parse_instant(
+    text: &str,
+    unit: Unit,
+    zone: Tz,
+) -> Result<(DateTime<Utc>, Option<Unit>), String> {
+    let text = text.trim();
+
+    if let Some((nanos, unit)) = parse_number(text, unit) {
+        return from_nanos(nanos)
+            .map(|dt| (dt, Some(unit)))
+            .ok_or_else(|| "timestamp is out of range".to_string());
+    }
+
+    // ISO 8601 also allows a space between the date and the time.
+    let iso = if text.len() > 10 && text.as_bytes()[10] == b' ' {
+        format!("{}T{}", &text[..10], &text[11..])
+    } else {
+        text.to_string()
+    };
+    if let Ok(dt) = DateTime::parse_from_rfc3339(&iso) {
+        return Ok((dt.with_timezone(&Utc), None));
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc2822(text) {
+        return Ok((dt.with_timezone(&Utc), None));
+    }
+
+    let naive = NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            ["%Y-%m-%d", "%Y/%m/%d"]
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .ok_or_else(|| "unrecognized timestamp".to_string())?;
+    match zone.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => Ok((dt.with_timezone(&Utc), None)),
+        // In a backward transition, take the earlier of the two instants.
+        LocalResult::Ambiguous(dt, _) => Ok((dt.with_timezone(&Utc), None)),
+        LocalResult::None => Err(format!("{} does not exist in {}", naive, zone)),
+    }
+}
+
+/// RFC 2822 only has four-digit years, so other years are shown as `-`.
+fn format_rfc2822<T: TimeZone>(dt: &DateTime<T>) -> String
+where
+    T::Offset: std::fmt::Display,
+{
+    if (0..=9999).contains(&dt.year()) {
+        dt.to_rfc2822()
+    } else {
+        "-".to_string()
+    }
+}
+
+/// Formats `nanos` in units of `scale` nanoseconds, with a fractional part
+/// only when needed.
+fn format_scaled(nanos: i128, scale: i128) -> String {
+    let sign = if nanos < 0 { "-" } else { "" };
+    let int = nanos.abs() / scale;
+    let frac = nanos.abs() % scale;
+    if frac == 0 {
+        return format!("{}{}", sign, int);
+    }
+    let width = scale.to_string().len() - 1;
+    let frac = format!("{:0width$}", frac, width = width);
+    format!("{}{}.{}", sign, int, frac.trim_end_matches('0'))
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+    task: Option<IntervalTask>,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    input: String,
+    #[prop_or(Unit::Auto)]
+    unit: Unit,
+    #[prop_or(Tz::Asia__Tokyo)]
+    zone: Tz,
+    #[prop_or_default]
+    now: bool,
+}
+
+pub enum Msg {
+    Input(String),
+    Unit(Unit),
+    Zone(String),
+    Now(bool),
+    Tick,
+}
+
+impl Model {
+    fn set_now(&mut self) {
+        self.props.input = format_scaled(to_nanos(&Utc::now()), NANOS_PER_SEC);
+        self.props.unit = Unit::Auto;
+    }
+
+    fn view_zone<T: TimeZone>(&self, title: &str, dt: &DateTime<T>) -> Html
+    where
+        T::Offset: std::fmt::Display,
+    {
+        let input = |s: String| {
+            html! {
+                <input class="input" type="text" value=s readonly=true/>
+            }
+        };
+        html! {
+            <>
+            <h2 class="title is-4">{ title }</h2>
+            { horizontal_field("ISO 8601", input(dt.to_rfc3339_opts(SecondsFormat::AutoSi, true))) }
+            { horizontal_field("RFC 2822", input(format_rfc2822(dt))) }
+            { horizontal_field("Readable", input(dt.format("%Y-%m-%d (%a) %H:%M:%S%.f %Z").to_string())) }
+            </>
+        }
+    }
+
+    fn view_result(&self, dt: &DateTime<Utc>) -> Html {
+        let nanos = to_nanos(dt);
+        let input = |s: String| {
+            html! {
+                <input class="input" type="text" value=s readonly=true/>
+            }
+        };
+        html! {
+            <>
+            <h2 class="title is-4">{"Unix time"}</h2>
+            {
+                for UNITS[1..5].iter().map(|&unit| {
+                    horizontal_field(unit.name(), input(format_scaled(nanos, unit.scale())))
+                })
+            }
+            {
+                horizontal_field("FILETIME", input(format_scaled(
+                    nanos + FILETIME_EPOCH_OFFSET * NANOS_PER_SEC,
+                    Unit::FileTime.scale(),
+                )))
+            }
+            { self.view_zone("UTC", dt) }
+            { self.view_zone("Local time", &dt.with_timezone(&Local)) }
+            { self.view_zone(self.props.zone.name(), &dt.with_timezone(&self.props.zone)) }
+            </>
+        }
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self {
+            link,
+            props,
+            task: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(text) => {
+                self.props.input = text;
+                self.props.now = false;
+                self.task = None;
+            }
+            Msg::Unit(unit) => self.props.unit = unit,
+            Msg::Zone(name) => {
+                if let Ok(zone) = name.parse() {
+                    self.props.zone = zone;
+                }
+            }
+            Msg::Now(now) => {
+                self.props.now = now;
+                self.task = if now {
+                    self.set_now();
+                    Some(IntervalService::spawn(
+                        Duration::from_secs(1),
+                        self.link.callback(|_| Msg::Tick),
+                    ))
+                } else {
+                    None
+                };
+            }
+            Msg::Tick => self.set_now(),
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let result = if self.props.input.trim().is_empty() {
+            None
+        } else {
+            Some(parse_instant(
+                &self.props.input,
+                self.props.unit,
+                self.props.zone,
+            ))
+        };
+        let mut classes = vec!["input"];
+        if let Some(Err(_)) = result {
+            classes.push("is-danger");
+        }
+        let now = self.props.now;
+
+        html! {
+            <>
+            {
+                horizontal_field("Timestamp", html!{
+                    <>
+                    <input class=classes type="text" placeholder="1700000000, 2023-11-14T22:13:20Z"
+                        value=self.props.input.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))/>
+                    {
+                        match &result {
+                            Some(Err(e)) => html! { <p class="help is-danger">{ e }</p> },
+                            Some(Ok((_, Some(unit)))) if self.props.unit == Unit::Auto => html! {
+                                <p class="help">{ format!("Read as {}", unit.name().to_lowercase()) }</p>
+                            },
+                            _ => html! {},
+                        }
+                    }
+                    </>
+                })
+            }
+            {
+                horizontal_field("Unit", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Unit(UNITS[s.selected_index().max(0) as usize]),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for UNITS.iter().map(|&unit| html!{
+                                    <option selected=self.props.unit == unit>{ unit.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                horizontal_field("Time zone", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Zone(s.value()),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for TZ_VARIANTS.iter().map(|zone| html!{
+                                    <option value=zone.name() selected=*zone == self.props.zone>{ zone.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                horizontal_field("", html!{
+                    <label class="checkbox">
+                        <input type="checkbox"
+                            checked=self.props.now
+                            onchange=self.link.callback(move |_| Msg::Now(!now))/>
+                        {" Now (updates every second)"}
+                    </label>
+                })
+            }
+            {
+                if let Some(Ok((dt, _))) = &result {
+                    self.view_result(dt)
+                } else {
+                    html! {}
+                }
+            }
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str, unit: Unit) -> Result<(String, Option<&'static str>), String> {
+        parse_instant(text, unit, Tz::Asia__Tokyo).map(|(dt, unit)| {
+            (
+                dt.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+                unit.map(Unit::name),
+            )
+        })
+    }
+
+    #[test]
+    fn detects_units_from_magnitude() {
+        let cases = [
+            ("1700000000", "2023-11-14T22:13:20Z", Unit::Seconds),
+            (
+                "1700000000123",
+                "2023-11-14T22:13:20.123Z",
+                Unit::Milliseconds,
+            ),
+            (
+                "1700000000123456",
+                "2023-11-14T22:13:20.123456Z",
+                Unit::Microseconds,
+            ),
+            (
+                "1700000000123456789",
+                "2023-11-14T22:13:20.123456789Z",
+                Unit::Nanoseconds,
+            ),
+            ("-1.5", "1969-12-31T23:59:58.500Z", Unit::Seconds),
+            ("1_700_000_000", "2023-11-14T22:13:20Z", Unit::Seconds),
+        ];
+        for &(text, expected, unit) in &cases {
+            assert_eq!(
+                parse(text, Unit::Auto),
+                Ok((expected.to_string(), Some(unit.name()))),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(
+            parse("1700000000000", Unit::Seconds).map(|(_, unit)| unit),
+            Ok(Some(Unit::Seconds.name()))
+        );
+    }
+
+    #[test]
+    fn parses_filetime() {
+        assert_eq!(
+            parse("133444736000000000", Unit::FileTime),
+            Ok((
+                "2023-11-14T22:13:20Z".to_string(),
+                Some(Unit::FileTime.name())
+            ))
+        );
+        assert_eq!(
+            parse("0", Unit::FileTime).map(|(dt, _)| dt),
+            Ok("1601-01-01T00:00:00Z".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_iso_8601_and_rfc_2822() {
+        let expected = Ok(("2023-11-14T22:13:20Z".to_string(), None));
+        assert_eq!(parse("2023-11-14T22:13:20Z", Unit::Auto), expected);
+        assert_eq!(parse("2023-11-15 07:13:20+09:00", Unit::Auto), expected);
+        assert_eq!(
+            parse("Tue, 14 Nov 2023 22:13:20 +0000", Unit::Auto),
+            expected
+        );
+        // Without an offset, the selected zone is used.
+        assert_eq!(parse("2023-11-15 07:13:20", Unit::Auto), expected);
+        assert_eq!(
+            parse("2023/11/15", Unit::Auto).map(|(dt, _)| dt),
+            Ok("2023-11-14T15:00:00Z".to_string())
+        );
+        assert!(parse("yesterday", Unit::Auto).is_err());
+    }
+
+    #[test]
+    fn out_of_range_years() {
+        let (dt, _) = parse_instant("-99999999999", Unit::Auto, Tz::UTC).unwrap();
+        assert_eq!(dt.year(), -1199);
+        assert_eq!(format_rfc2822(&dt), "-");
+        let (dt, _) = parse_instant("1000000000000", Unit::Seconds, Tz::UTC).unwrap();
+        assert_eq!(dt.year(), 33658);
+        assert_eq!(format_rfc2822(&dt), "-");
+        let (dt, _) = parse_instant("0", Unit::Auto, Tz::UTC).unwrap();
+        assert_eq!(format_rfc2822(&dt), "Thu, 1 Jan 1970 00:00:00 +0000");
+        assert_eq!(
+            parse_instant("99999999999999999999999999999", Unit::Seconds, Tz::UTC).err(),
+            Some("timestamp is out of range".to_string())
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The twitter-text v3 configuration: weights are in hundredths of a
+/// character, so a tweet holds 280 weight-100 characters or 140 CJK ones.
+pub const MAX_WEIGHTED_LENGTH: usize = 280;
+const SCALE: usize = 100;
+const DEFAULT_WEIGHT: usize = 200;
+const URL_LENGTH: usize = 23;
+
+/// Code point ranges that weigh 100 instead of the default 200: Latin and
+/// most other alphabetic scripts, and common punctuation.
+const LIGHT_RANGES: &[(char, char)] = &[
+    ('\u{0000}', '\u{10FF}'),
+    ('\u{2000}', '\u{200D}'),
+    ('\u{2010}', '\u{201F}'),
+    ('\u{2032}', '\u{2037}'),
+];
+
+/// Top-level domains recognized in URLs written without a scheme. This is a
+/// common subset of the list twitter-text ships with.
+const TLDS: &[&str] = &[
+    "com", "net", "org", "edu", "gov", "info", "biz", "io", "co", "me", "dev", "app", "ai", "ly",
+    "gl", "be", "tv", "fm", "jp", "us", "uk", "de", "fr", "cn", "kr", "tw", "ru", "in", "au", "ca",
+    "es", "it", "nl", "eu", "xyz", "site", "online", "tech", "blog", "news", "shop",
+];
+
+fn char_weight(c: char) -> usize {
+    if LIGHT_RANGES.iter().any(|&(lo, hi)| lo <= c && c <= hi) {
+        SCALE
+    } else {
+        DEFAULT_WEIGHT
+    }
+}
+
+/// Whether a grapheme cluster is an emoji, which counts as 2 however many
+/// code points it is made of.
+fn is_emoji(g: &str) -> bool {
+    g.chars().any(|c| {
+        matches!(
+            c,
+            '\u{1F000}'..='\u{1FAFF}'
+                | '\u{2600}'..='\u{27BF}'
+                | '\u{2B00}'..='\u{2BFF}'
+                | '\u{FE0F}'
+                | '\u{20E3}'
+        )
+    })
+}
+
+fn is_url_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "-._~:/?#[]@!$&'()*+,;=%".contains(c)
+}
+
+/// Whether `s` looks like a URL to twitter-text: an http(s) URL, or a
+/// host name with a known TLD optionally followed by a port or path.
+fn is_url(s: &str) -> bool {
+    let lower = s.to_ascii_lowercase();
+    let (rest, scheme) = match lower
+        .strip_prefix("https://")
+        .or_else(|| lower.strip_prefix("http://"))
+    {
+        Some(rest) => (rest, true),
+        None => (&lower[..], false),
+    };
+    let host = rest.split(['/', '?', '#', ':']).next().unwrap_or("");
+    let labels: Vec<&str> = host.split('.').collect();
+    let valid = labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    valid && (scheme || TLDS.contains(labels.last().unwrap()))
+}
+
+/// Byte ranges of the URLs in `text`.
+fn find_urls(text: &str) -> Vec<(usize, usize)> {
+    let mut urls = vec![];
+    let mut start = None;
+    for (i, c) in text
+        .char_indices()
+        .chain(std::iter::once((text.len(), ' ')))
+    {
+        match (start, is_url_char(c)) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                start = None;
+                let run = &text[s..i];
+                // Closing punctuation after a URL is not part of it.
+                let run = run.trim_end_matches(['.', ',', ':', ';', '!', '?', '\'', ')', ']']);
+                let preceded_by_at = text[..s].ends_with('@');
+                if !preceded_by_at && !run.contains('@') && is_url(run) {
+                    urls.push((s, s + run.len()));
+                }
+            }
+            _ => {}
+        }
+    }
+    urls
+}
+
+pub struct TweetLength {
+    /// The text after NFC normalization, which is what gets counted.
+    pub text: String,
+    /// The weighted length in characters, rounded up.
+    pub length: usize,
+    /// The byte offset in `text` where the text exceeds the limit.
+    pub overflow: Option<usize>,
+}
+
+/// Measures `text` the way twitter-text does: after NFC normalization,
+/// each URL counts as 23 characters, each emoji as 2, CJK and most other
+/// characters outside the Latin ranges as 2, and the rest as 1.
+pub fn tweet_length(text: &str) -> TweetLength {
+    let text: String = text.nfc().collect();
+    let urls = find_urls(&text);
+    let mut weight = 0;
+    let mut overflow = None;
+    let mut url = urls.iter().peekable();
+    let mut skip_until = 0;
+
+    for (i, g) in text.grapheme_indices(true) {
+        if i < skip_until {
+            continue;
+        }
+        let w = match url.peek() {
+            Some(&&(start, end)) if start <= i => {
+                url.next();
+                skip_until = end;
+                URL_LENGTH * SCALE
+            }
+            _ if is_emoji(g) => DEFAULT_WEIGHT,
+            _ => g.chars().map(char_weight).sum(),
+        };
+        weight += w;
+        if overflow.is_none() && weight > MAX_WEIGHTED_LENGTH * SCALE {
+            overflow = Some(i);
+        }
+    }
+
+    TweetLength {
+        text,
+        length: weight.div_ceil(SCALE),
+        overflow,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn length(text: &str) -> usize {
+        tweet_length(text).length
+    }
+
+    #[test]
+    fn character_weights() {
+        assert_eq!(length("hello"), 5);
+        assert_eq!(length("あいう"), 6);
+        assert_eq!(length("“quoted”"), 8);
+        assert_eq!(length("👨\u{200D}👩\u{200D}👧"), 2);
+        assert_eq!(length("1\u{FE0F}\u{20E3}"), 2);
+    }
+
+    #[test]
+    fn counts_after_nfc() {
+        let t = tweet_length("か\u{3099}");
+        assert_eq!(t.text, "が");
+        assert_eq!(t.length, 2);
+        assert_eq!(length("e\u{301}"), 1);
+    }
+
+    #[test]
+    fn urls_count_as_23() {
+        assert_eq!(length("https://example.com/a/very/long/path?query=1"), 23);
+        assert_eq!(length("see example.com."), 28);
+        assert_eq!(length("example.invalid"), 15);
+        assert_eq!(length("user@example.com"), 16);
+    }
+
+    #[test]
+    fn overflow_offset() {
+        let t = tweet_length(&"あ".repeat(140));
+        assert_eq!((t.length, t.overflow), (280, None));
+        let t = tweet_length(&"あ".repeat(141));
+        assert_eq!((t.length, t.overflow), (282, Some(420)));
+    }
+}
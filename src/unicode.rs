@@ -0,0 +1,421 @@
+use crate::unicode_tables::EAW_AMBIGUOUS;
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_script::UnicodeScript;
+use unicode_width::UnicodeWidthChar;
+
+/// Name aliases of the C0 controls, which have no character name.
+const C0_NAMES: [&str; 32] = [
+    "NULL",
+    "START OF HEADING",
+    "START OF TEXT",
+    "END OF TEXT",
+    "END OF TRANSMISSION",
+    "ENQUIRY",
+    "ACKNOWLEDGE",
+    "ALERT",
+    "BACKSPACE",
+    "CHARACTER TABULATION",
+    "LINE FEED",
+    "LINE TABULATION",
+    "FORM FEED",
+    "CARRIAGE RETURN",
+    "SHIFT OUT",
+    "SHIFT IN",
+    "DATA LINK ESCAPE",
+    "DEVICE CONTROL ONE",
+    "DEVICE CONTROL TWO",
+    "DEVICE CONTROL THREE",
+    "DEVICE CONTROL FOUR",
+    "NEGATIVE ACKNOWLEDGE",
+    "SYNCHRONOUS IDLE",
+    "END OF TRANSMISSION BLOCK",
+    "CANCEL",
+    "END OF MEDIUM",
+    "SUBSTITUTE",
+    "ESCAPE",
+    "INFORMATION SEPARATOR FOUR",
+    "INFORMATION SEPARATOR THREE",
+    "INFORMATION SEPARATOR TWO",
+    "INFORMATION SEPARATOR ONE",
+];
+
+/// Name aliases of the C1 controls.
+const C1_NAMES: [&str; 32] = [
+    "PADDING CHARACTER",
+    "HIGH OCTET PRESET",
+    "BREAK PERMITTED HERE",
+    "NO BREAK HERE",
+    "INDEX",
+    "NEXT LINE",
+    "START OF SELECTED AREA",
+    "END OF SELECTED AREA",
+    "CHARACTER TABULATION SET",
+    "CHARACTER TABULATION WITH JUSTIFICATION",
+    "LINE TABULATION SET",
+    "PARTIAL LINE FORWARD",
+    "PARTIAL LINE BACKWARD",
+    "REVERSE LINE FEED",
+    "SINGLE SHIFT TWO",
+    "SINGLE SHIFT THREE",
+    "DEVICE CONTROL STRING",
+    "PRIVATE USE ONE",
+    "PRIVATE USE TWO",
+    "SET TRANSMIT STATE",
+    "CANCEL CHARACTER",
+    "MESSAGE WAITING",
+    "START OF GUARDED AREA",
+    "END OF GUARDED AREA",
+    "START OF STRING",
+    "SINGLE GRAPHIC CHARACTER INTRODUCER",
+    "SINGLE CHARACTER INTRODUCER",
+    "CONTROL SEQUENCE INTRODUCER",
+    "STRING TERMINATOR",
+    "OPERATING SYSTEM COMMAND",
+    "PRIVACY MESSAGE",
+    "APPLICATION PROGRAM COMMAND",
+];
+
+/// The character name, or for characters without one a label in the style
+/// of the Unicode code charts, e.g. `<control> LINE FEED` or
+/// `<private-use-E000>`.
+pub fn name(c: char) -> String {
+    if let Some(name) = unicode_names2::name(c) {
+        return name.to_string();
+    }
+    let cp = c as u32;
+    match cp {
+        0x00..=0x1F => format!("<control> {}", C0_NAMES[cp as usize]),
+        0x7F => "<control> DELETE".to_string(),
+        0x80..=0x9F => format!("<control> {}", C1_NAMES[cp as usize - 0x80]),
+        _ => match get_general_category(c) {
+            GeneralCategory::PrivateUse => format!("<private-use-{:04X}>", cp),
+            _ if (0xFDD0..=0xFDEF).contains(&cp) || cp & 0xFFFE == 0xFFFE => {
+                format!("<noncharacter-{:04X}>", cp)
+            }
+            _ => format!("<reserved-{:04X}>", cp),
+        },
+    }
+}
+
+/// The general category as its abbreviation and long name, e.g.
+/// `("Lu", "Uppercase Letter")`.
+pub fn category(c: char) -> (&'static str, String) {
+    let category = get_general_category(c);
+    let mut long = String::new();
+    for ch in format!("{:?}", category).chars() {
+        if ch.is_uppercase() && !long.is_empty() {
+            long.push(' ');
+        }
+        long.push(ch);
+    }
+    (category.abbreviation(), long)
+}
+
+pub fn script(c: char) -> &'static str {
+    c.script().full_name()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EastAsianWidth {
+    Fullwidth,
+    Halfwidth,
+    Wide,
+    Narrow,
+    Ambiguous,
+    Neutral,
+}
+
+impl EastAsianWidth {
+    pub fn name(self) -> &'static str {
+        match self {
+            EastAsianWidth::Fullwidth => "F (Fullwidth)",
+            EastAsianWidth::Halfwidth => "H (Halfwidth)",
+            EastAsianWidth::Wide => "W (Wide)",
+            EastAsianWidth::Narrow => "Na (Narrow)",
+            EastAsianWidth::Ambiguous => "A (Ambiguous)",
+            EastAsianWidth::Neutral => "N (Neutral)",
+        }
+    }
+
+    /// Columns taken in a terminal or a monospace font. Ambiguous
+    /// characters are wide in East Asian contexts.
+    pub fn columns(self, east_asian: bool) -> usize {
+        match self {
+            EastAsianWidth::Fullwidth | EastAsianWidth::Wide => 2,
+            EastAsianWidth::Ambiguous if east_asian => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// The East Asian Width property. Wide is taken from the display width
+/// `unicode-width` computes, which is 2 for Wide and Fullwidth except for
+/// the zero-width marks listed here, and for no other character but U+17A4;
+/// Ambiguous comes from a bundled table.
+pub fn east_asian_width(c: char) -> EastAsianWidth {
+    let cp = c as u32;
+    match c.width() {
+        _ if cp == 0x3000 || (0xFF01..=0xFF60).contains(&cp) || (0xFFE0..=0xFFE6).contains(&cp) => {
+            EastAsianWidth::Fullwidth
+        }
+        _ if cp == 0x20A9 || (0xFF61..=0xFFDC).contains(&cp) || (0xFFE8..=0xFFEE).contains(&cp) => {
+            EastAsianWidth::Halfwidth
+        }
+        _ if matches!(
+            cp,
+            0x302A..=0x302F | 0x3099 | 0x309A | 0x3164 | 0x16FE4 | 0x16FF0 | 0x16FF1
+        ) =>
+        {
+            EastAsianWidth::Wide
+        }
+        Some(2) if cp != 0x17A4 => EastAsianWidth::Wide,
+        _ if (0x20..=0x7E).contains(&cp)
+            || matches!(cp, 0xA2 | 0xA3 | 0xA5 | 0xA6 | 0xAC | 0xAF)
+            || (0x27E6..=0x27ED).contains(&cp)
+            || (0x2985..=0x2986).contains(&cp) =>
+        {
+            EastAsianWidth::Narrow
+        }
+        _ if EAW_AMBIGUOUS.iter().any(|&(lo, hi)| lo <= cp && cp <= hi) => {
+            EastAsianWidth::Ambiguous
+        }
+        _ => EastAsianWidth::Neutral,
+    }
+}
+
+pub fn code_points(s: &str) -> String {
+    s.chars()
+        .map(|c| format!("U+{:04X}", c as u32))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn utf8_bytes(s: &str) -> String {
+    s.bytes()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn utf16_units(s: &str) -> String {
+    s.encode_utf16()
+        .map(|u| format!("{:04X}", u))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn utf32_units(s: &str) -> String {
+    s.chars()
+        .map(|c| format!("{:08X}", c as u32))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Numeric character references, e.g. `&#x1F600;`.
+pub fn html_escape(s: &str) -> String {
+    s.chars().map(|c| format!("&#x{:X};", c as u32)).collect()
+}
+
+/// UTF-16 escapes that work in any JavaScript or JSON string, e.g.
+/// `\uD83D\uDE00`.
+pub fn js_escape(s: &str) -> String {
+    s.encode_utf16().map(|u| format!("\\u{:04X}", u)).collect()
+}
+
+pub fn rust_escape(s: &str) -> String {
+    s.chars()
+        .map(|c| format!("\\u{{{:X}}}", c as u32))
+        .collect()
+}
+
+pub fn python_escape(s: &str) -> String {
+    s.chars()
+        .map(|c| match c as u32 {
+            cp @ 0..=0xFF => format!("\\x{:02x}", cp),
+            cp @ 0x100..=0xFFFF => format!("\\u{:04x}", cp),
+            cp => format!("\\U{:08x}", cp),
+        })
+        .collect()
+}
+
+/// What a code point does inside a grapheme cluster, for the parts of
+/// emoji and combining sequences that are otherwise hard to tell apart.
+pub fn role(c: char) -> Option<&'static str> {
+    match c {
+        '\u{200D}' => Some("zero width joiner"),
+        '\u{FE0E}' => Some("text presentation selector"),
+        '\u{FE0F}' => Some("emoji presentation selector"),
+        '\u{FE00}'..='\u{FE0D}' | '\u{E0100}'..='\u{E01EF}' => Some("variation selector"),
+        '\u{1F3FB}'..='\u{1F3FF}' => Some("skin tone modifier"),
+        '\u{1F1E6}'..='\u{1F1FF}' => Some("regional indicator"),
+        '\u{20E3}' => Some("keycap"),
+        '\u{E0020}'..='\u{E007E}' => Some("tag"),
+        '\u{E007F}' => Some("cancel tag"),
+        _ => match get_general_category(c) {
+            GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+            | GeneralCategory::EnclosingMark => Some("combining mark"),
+            _ => None,
+        },
+    }
+}
+
+/// Describes a grapheme cluster made of several code points.
+pub fn sequence_kind(g: &str) -> Option<&'static str> {
+    let chars: Vec<char> = g.chars().collect();
+    if chars.len() < 2 {
+        return None;
+    }
+    let has = |f: fn(char) -> bool| chars.iter().any(|&c| f(c));
+    Some(if has(|c| c == '\u{200D}') {
+        "Emoji ZWJ sequence"
+    } else if chars
+        .iter()
+        .all(|c| ('\u{1F1E6}'..='\u{1F1FF}').contains(c))
+    {
+        "Flag (regional indicator pair)"
+    } else if has(|c| ('\u{E0020}'..='\u{E007F}').contains(&c)) {
+        "Emoji tag sequence"
+    } else if has(|c| c == '\u{20E3}') {
+        "Keycap sequence"
+    } else if has(|c| ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)) {
+        "Emoji modifier sequence"
+    } else if has(|c| matches!(c, '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}')) {
+        "Variation sequence"
+    } else if chars == ['\r', '\n'] {
+        "CRLF"
+    } else if has(|c| role(c) == Some("combining mark")) {
+        "Combining sequence"
+    } else {
+        "Grapheme cluster"
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn width(c: char) -> &'static str {
+        east_asian_width(c).name()
+    }
+
+    #[test]
+    fn east_asian_widths() {
+        let cases = [
+            ('a', "Na (Narrow)"),
+            ('~', "Na (Narrow)"),
+            ('\u{A5}', "Na (Narrow)"),
+            ('\u{27E6}', "Na (Narrow)"),
+            ('\u{3000}', "F (Fullwidth)"),
+            ('Ａ', "F (Fullwidth)"),
+            ('￥', "F (Fullwidth)"),
+            ('ｶ', "H (Halfwidth)"),
+            ('\u{20A9}', "H (Halfwidth)"),
+            ('￨', "H (Halfwidth)"),
+            ('あ', "W (Wide)"),
+            ('漢', "W (Wide)"),
+            ('가', "W (Wide)"),
+            ('😀', "W (Wide)"),
+            ('\u{20000}', "W (Wide)"),
+            ('○', "A (Ambiguous)"),
+            ('★', "A (Ambiguous)"),
+            ('é', "A (Ambiguous)"),
+            ('Ω', "A (Ambiguous)"),
+            ('\u{E000}', "A (Ambiguous)"),
+            ('\u{FE0F}', "A (Ambiguous)"),
+            ('\u{0}', "N (Neutral)"),
+            ('\u{A0}', "N (Neutral)"),
+            ('\u{200D}', "N (Neutral)"),
+            ('ă', "N (Neutral)"),
+            ('א', "N (Neutral)"),
+        ];
+        for &(c, expected) in &cases {
+            assert_eq!(width(c), expected, "U+{:04X}", c as u32);
+        }
+    }
+
+    #[test]
+    fn east_asian_width_exceptions() {
+        // Wide marks that take no columns of their own.
+        for c in "\u{302A}\u{302F}\u{3099}\u{309A}\u{3164}\u{16FE4}\u{16FF0}".chars() {
+            assert_eq!(c.width(), Some(0), "U+{:04X}", c as u32);
+            assert_eq!(width(c), "W (Wide)", "U+{:04X}", c as u32);
+        }
+        // KHMER INDEPENDENT VOWEL QAA is drawn wide but is Neutral.
+        assert_eq!('\u{17A4}'.width(), Some(2));
+        assert_eq!(width('\u{17A4}'), "N (Neutral)");
+    }
+
+    #[test]
+    fn columns() {
+        assert_eq!(EastAsianWidth::Wide.columns(false), 2);
+        assert_eq!(EastAsianWidth::Fullwidth.columns(false), 2);
+        assert_eq!(EastAsianWidth::Ambiguous.columns(false), 1);
+        assert_eq!(EastAsianWidth::Ambiguous.columns(true), 2);
+        assert_eq!(EastAsianWidth::Halfwidth.columns(true), 1);
+        assert_eq!(EastAsianWidth::Neutral.columns(true), 1);
+    }
+
+    #[test]
+    fn names_categories_and_scripts() {
+        assert_eq!(name('A'), "LATIN CAPITAL LETTER A");
+        assert_eq!(name('\n'), "<control> LINE FEED");
+        assert_eq!(name('\u{85}'), "<control> NEXT LINE");
+        assert_eq!(name('\u{E000}'), "<private-use-E000>");
+        assert_eq!(name('\u{FDD0}'), "<noncharacter-FDD0>");
+        assert_eq!(name('\u{10FFFF}'), "<noncharacter-10FFFF>");
+        assert_eq!(name('\u{0378}'), "<reserved-0378>");
+
+        assert_eq!(category('A'), ("Lu", "Uppercase Letter".to_string()));
+        assert_eq!(category('\u{301}'), ("Mn", "Nonspacing Mark".to_string()));
+        assert_eq!(category('\u{3000}'), ("Zs", "Space Separator".to_string()));
+        assert_eq!(category('\u{E000}'), ("Co", "Private Use".to_string()));
+        assert_eq!(category('\u{0378}'), ("Cn", "Unassigned".to_string()));
+
+        assert_eq!(script('A'), "Latin");
+        assert_eq!(script('あ'), "Hiragana");
+        assert_eq!(script('カ'), "Katakana");
+        assert_eq!(script('漢'), "Han");
+        assert_eq!(script('ー'), "Common");
+        assert_eq!(script('\u{3099}'), "Inherited");
+    }
+
+    #[test]
+    fn sequence_kinds() {
+        let cases = [
+            ("a", None),
+            ("👩\u{200D}💻", Some("Emoji ZWJ sequence")),
+            ("🇯🇵", Some("Flag (regional indicator pair)")),
+            (
+                "🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+                Some("Emoji tag sequence"),
+            ),
+            ("1\u{FE0F}\u{20E3}", Some("Keycap sequence")),
+            ("👍🏽", Some("Emoji modifier sequence")),
+            ("❤\u{FE0F}", Some("Variation sequence")),
+            ("葛\u{E0100}", Some("Variation sequence")),
+            ("\r\n", Some("CRLF")),
+            ("e\u{301}", Some("Combining sequence")),
+            ("か\u{3099}", Some("Combining sequence")),
+            ("각", None),
+            ("\u{1100}\u{1161}\u{11A8}", Some("Grapheme cluster")),
+        ];
+        for &(g, expected) in &cases {
+            assert_eq!(sequence_kind(g), expected, "{}", code_points(g));
+        }
+    }
+
+    #[test]
+    fn encodings_and_escapes() {
+        let s = "Aé😀";
+        assert_eq!(code_points(s), "U+0041 U+00E9 U+1F600");
+        assert_eq!(utf8_bytes(s), "41 C3 A9 F0 9F 98 80");
+        assert_eq!(utf16_units(s), "0041 00E9 D83D DE00");
+        assert_eq!(utf32_units(s), "00000041 000000E9 0001F600");
+        assert_eq!(html_escape(s), "&#x41;&#xE9;&#x1F600;");
+        assert_eq!(js_escape(s), "\\u0041\\u00E9\\uD83D\\uDE00");
+        assert_eq!(rust_escape(s), "\\u{41}\\u{E9}\\u{1F600}");
+        assert_eq!(python_escape("Aあ😀"), "\\x41\\u3042\\U0001f600");
+        assert_eq!(python_escape("\u{FF}\u{100}"), "\\xff\\u0100");
+    }
+}
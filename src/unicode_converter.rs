@@ -0,0 +1,387 @@
+use crate::escape::{self, Syntax, SYNTAXES};
+use crate::unicode::code_points;
+use crate::util::horizontal_field;
+use unicode_normalization::{is_nfc, is_nfd, is_nfkc, is_nfkd, UnicodeNormalization};
+use unicode_segmentation::UnicodeSegmentation;
+use yew::prelude::*;
+
+/// Rows of the changed character table shown at most.
+const MAX_CHANGES: usize = 100;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+const FORMS: &[Form] = &[Form::Nfc, Form::Nfd, Form::Nfkc, Form::Nfkd];
+
+impl Form {
+    fn name(self) -> &'static str {
+        match self {
+            Form::Nfc => "NFC",
+            Form::Nfd => "NFD",
+            Form::Nfkc => "NFKC",
+            Form::Nfkd => "NFKD",
+        }
+    }
+
+    fn help(self) -> &'static str {
+        match self {
+            Form::Nfc => "Canonical composition. What most systems expect, e.g. が as one code point.",
+            Form::Nfd => "Canonical decomposition, as in macOS file names, e.g. か followed by a combining dakuten.",
+            Form::Nfkc => "Compatibility composition. Also folds half-width katakana, full-width ASCII, ligatures and circled numbers.",
+            Form::Nfkd => "Compatibility decomposition.",
+        }
+    }
+
+    fn apply(self, s: &str) -> String {
+        match self {
+            Form::Nfc => s.nfc().collect(),
+            Form::Nfd => s.nfd().collect(),
+            Form::Nfkc => s.nfkc().collect(),
+            Form::Nfkd => s.nfkd().collect(),
+        }
+    }
+
+    fn is(self, s: &str) -> bool {
+        match self {
+            Form::Nfc => is_nfc(s),
+            Form::Nfd => is_nfd(s),
+            Form::Nfkc => is_nfkc(s),
+            Form::Nfkd => is_nfkd(s),
+        }
+    }
+}
+
+/// The grapheme clusters normalization changes, with what they become and
+/// how often they occur, in order of first occurrence.
+fn changes(text: &str, form: Form) -> Vec<(&str, String, usize)> {
+    let mut ret: Vec<(&str, String, usize)> = vec![];
+    for g in text.graphemes(true) {
+        if let Some(entry) = ret.iter_mut().find(|e| e.0 == g) {
+            entry.2 += 1;
+            continue;
+        }
+        let normalized = form.apply(g);
+        if normalized != g {
+            ret.push((g, normalized, 1));
+        }
+    }
+    ret
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Mode {
+    Normalize,
+    Escape,
+    Unescape,
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or(Mode::Normalize)]
+    mode: Mode,
+    #[prop_or_default]
+    input: String,
+    #[prop_or(Form::Nfc)]
+    form: Form,
+    #[prop_or(Syntax::Js)]
+    syntax: Syntax,
+    #[prop_or_default]
+    escape_ascii: bool,
+}
+
+pub enum Msg {
+    Mode(Mode),
+    Input(String),
+    Form(Form),
+    Syntax(Syntax),
+    EscapeAscii(bool),
+}
+
+impl Model {
+    fn convert(&self) -> Result<String, String> {
+        let props = &self.props;
+        match props.mode {
+            Mode::Normalize => Ok(props.form.apply(&props.input)),
+            Mode::Escape => Ok(escape::escape(
+                &props.input,
+                props.syntax,
+                props.escape_ascii,
+            )),
+            Mode::Unescape => escape::unescape(&props.input, props.syntax),
+        }
+    }
+
+    fn view_tab(&self, mode: Mode, label: &str) -> Html {
+        let class = if self.props.mode == mode {
+            "is-active"
+        } else {
+            ""
+        };
+        html! {
+            <li class=class>
+                <a onclick=self.link.callback(move |_| Msg::Mode(mode))>{ label }</a>
+            </li>
+        }
+    }
+
+    fn view_options(&self) -> Html {
+        let props = &self.props;
+        let escape_ascii = props.escape_ascii;
+
+        if props.mode == Mode::Normalize {
+            return horizontal_field(
+                "Form",
+                html! {
+                    <>
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Form(FORMS[s.selected_index().max(0) as usize]),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for FORMS.iter().map(|&form| html!{
+                                    <option selected=props.form == form>{ form.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                    <p class="help">{ props.form.help() }</p>
+                    </>
+                },
+            );
+        }
+
+        html! {
+            <>
+            {
+                horizontal_field("Syntax", html!{
+                    <div class="select">
+                        <select onchange=self.link.callback(|e: ChangeData| match e {
+                            ChangeData::Select(s) => Msg::Syntax(SYNTAXES[s.selected_index().max(0) as usize]),
+                            _ => unreachable!(),
+                        })>
+                            {
+                                for SYNTAXES.iter().map(|&syntax| html!{
+                                    <option selected=props.syntax == syntax>{ syntax.name() }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+                })
+            }
+            {
+                if props.mode == Mode::Escape {
+                    horizontal_field("", html!{
+                        <label class="checkbox">
+                            <input type="checkbox" checked=escape_ascii
+                                onchange=self.link.callback(move |_| Msg::EscapeAscii(!escape_ascii))/>
+                            {" Escape ASCII characters too"}
+                        </label>
+                    })
+                } else {
+                    html! {}
+                }
+            }
+            </>
+        }
+    }
+
+    fn view_changes(&self) -> Html {
+        let props = &self.props;
+        let changes = changes(&props.input, props.form);
+        let forms = FORMS
+            .iter()
+            .filter(|form| form.is(&props.input))
+            .map(|form| form.name())
+            .collect::<Vec<_>>();
+
+        html! {
+            <>
+            <p class="mb-3">
+            {
+                if forms.is_empty() {
+                    "The input is in no normalization form.".to_string()
+                } else {
+                    format!("The input is already in {}.", forms.join(", "))
+                }
+            }
+            </p>
+            {
+                if changes.is_empty() {
+                    html! {}
+                } else {
+                    html! {
+                        <table class="table is-narrow is-fullwidth">
+                            <thead>
+                                <tr>
+                                    <th>{"Before"}</th>
+                                    <th>{"Code points"}</th>
+                                    <th>{"After"}</th>
+                                    <th>{"Code points"}</th>
+                                    <th>{"Count"}</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                            {
+                                for changes.iter().take(MAX_CHANGES).map(|(before, after, count)| html! {
+                                    <tr>
+                                        <td>{ before }</td>
+                                        <td><code>{ code_points(before) }</code></td>
+                                        <td>{ after }</td>
+                                        <td><code>{ code_points(after) }</code></td>
+                                        <td>{ count }</td>
+                                    </tr>
+                                })
+                            }
+                            </tbody>
+                        </table>
+                    }
+                }
+            }
+            </>
+        }
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Mode(mode) => {
+                // Switching between escaping and unescaping carries the
+                // output over, so that the result can be checked by
+                // converting it back.
+                let escaping = [Mode::Escape, Mode::Unescape];
+                if self.props.mode != mode
+                    && escaping.contains(&self.props.mode)
+                    && escaping.contains(&mode)
+                {
+                    if let Ok(output) = self.convert() {
+                        self.props.input = output;
+                    }
+                }
+                self.props.mode = mode;
+            }
+            Msg::Input(txt) => self.props.input = txt,
+            Msg::Form(form) => self.props.form = form,
+            Msg::Syntax(syntax) => self.props.syntax = syntax,
+            Msg::EscapeAscii(b) => self.props.escape_ascii = b,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let props = &self.props;
+        let (output, error) = match self.convert() {
+            Ok(output) => (output, None),
+            Err(err) => (String::new(), Some(err)),
+        };
+        let placeholder = match props.mode {
+            Mode::Normalize => "ガギグゲゴ",
+            Mode::Escape => "あ😀",
+            Mode::Unescape => props.syntax.placeholder(),
+        };
+
+        html! {
+            <>
+            <div class="tabs is-boxed">
+                <ul>
+                    { self.view_tab(Mode::Normalize, "Normalize") }
+                    { self.view_tab(Mode::Escape, "Escape") }
+                    { self.view_tab(Mode::Unescape, "Unescape") }
+                </ul>
+            </div>
+
+            { self.view_options() }
+
+            <div class="field">
+                <label class="label">{"Input"}</label>
+                <div class="control">
+                    <textarea class="textarea" placeholder=placeholder value=&props.input
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))/>
+                </div>
+            </div>
+            <div class="field">
+                <label class="label">{"Output"}</label>
+                <div class="control">
+                    <textarea class=if error.is_some() { "textarea is-danger" } else { "textarea" }
+                        value=output readonly=true/>
+                </div>
+                {
+                    match error {
+                        Some(err) => html! { <p class="help is-danger">{ err }</p> },
+                        None => html! {},
+                    }
+                }
+            </div>
+
+            {
+                if props.mode == Mode::Normalize && !props.input.is_empty() {
+                    self.view_changes()
+                } else {
+                    html! {}
+                }
+            }
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owned(changes: Vec<(&str, String, usize)>) -> Vec<(String, String, usize)> {
+        changes
+            .into_iter()
+            .map(|(g, normalized, count)| (g.to_string(), normalized, count))
+            .collect()
+    }
+
+    fn change(g: &str, normalized: &str, count: usize) -> (String, String, usize) {
+        (g.to_string(), normalized.to_string(), count)
+    }
+
+    #[test]
+    fn reports_changed_graphemes() {
+        assert_eq!(
+            owned(changes("か\u{3099}", Form::Nfc)),
+            [change("か\u{3099}", "が", 1)]
+        );
+        assert_eq!(
+            owned(changes("か\u{3099}aが か\u{3099}e\u{301}", Form::Nfc)),
+            [change("か\u{3099}", "が", 2), change("e\u{301}", "é", 1)]
+        );
+        assert_eq!(
+            owned(changes("がが", Form::Nfd)),
+            [change("が", "か\u{3099}", 2)]
+        );
+        assert_eq!(
+            owned(changes("ｶﾞ①", Form::Nfkc)),
+            [change("ｶﾞ", "ガ", 1), change("①", "1", 1)]
+        );
+        assert!(changes("が", Form::Nfc).is_empty());
+        assert!(changes("ｶﾞ", Form::Nfc).is_empty());
+        assert!(changes("", Form::Nfkd).is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+use crate::unicode::{self, code_points};
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+use yew::prelude::*;
+
+/// Grapheme clusters inspected at most; the page gets slow beyond that.
+const MAX_GRAPHEMES: usize = 100;
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    input: String,
+}
+
+pub enum Msg {
+    Input(String),
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(txt) => self.props.input = txt,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let graphemes: Vec<&str> = self.props.input.graphemes(true).collect();
+
+        html! {
+            <>
+            <div class="field">
+                <label class="label">{"Input"}</label>
+                <div class="control">
+                    <textarea class="textarea" rows=2 placeholder="é👨‍👩‍👧1️⃣🇯🇵"
+                        value=&self.props.input
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))/>
+                </div>
+                {
+                    if graphemes.len() > MAX_GRAPHEMES {
+                        html! { <p class="help is-danger">{ format!("Showing the first {} of {} characters", MAX_GRAPHEMES, graphemes.len()) }</p> }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+
+            { for graphemes.iter().take(MAX_GRAPHEMES).map(|g| view_grapheme(g)) }
+            </>
+        }
+    }
+}
+
+/// The heading of a grapheme cluster: its kind if it is a sequence, or the
+/// name of its only code point.
+fn title(g: &str) -> String {
+    match unicode::sequence_kind(g) {
+        Some(kind) => format!("{} ({} code points)", kind, g.chars().count()),
+        None => unicode::name(g.chars().next().unwrap()),
+    }
+}
+
+/// The escapes and normalization forms shown below the code points.
+fn rows(g: &str) -> Vec<(&'static str, String)> {
+    let nfc: String = g.nfc().collect();
+    let nfd: String = g.nfd().collect();
+    let normalized = |form: &str| {
+        if form == g {
+            format!("{} (unchanged)", code_points(form))
+        } else {
+            code_points(form)
+        }
+    };
+    vec![
+        ("HTML", unicode::html_escape(g)),
+        ("JavaScript", unicode::js_escape(g)),
+        ("Rust", unicode::rust_escape(g)),
+        ("Python", unicode::python_escape(g)),
+        ("NFC", normalized(&nfc)),
+        ("NFD", normalized(&nfd)),
+    ]
+}
+
+fn view_grapheme(g: &str) -> Html {
+    let title = title(g);
+    let rows = rows(g);
+
+    html! {
+        <div class="box">
+            <div class="media">
+                <div class="media-left">
+                    <p class="title is-1" style="min-width: 1.5em; text-align: center;">{ g }</p>
+                </div>
+                <div class="media-content">
+                    <p class="subtitle is-6">{ title }</p>
+                    <table class="table is-narrow is-fullwidth">
+                        <thead>
+                            <tr>
+                                <th>{"Code point"}</th>
+                                <th>{"Name"}</th>
+                                <th>{"Category"}</th>
+                                <th>{"Script"}</th>
+                                <th>{"East Asian Width"}</th>
+                                <th>{"UTF-8"}</th>
+                                <th>{"UTF-16"}</th>
+                                <th>{"UTF-32"}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            { for g.chars().map(view_char) }
+                        </tbody>
+                    </table>
+                    <table class="table is-narrow">
+                        <tbody>
+                        {
+                            for rows.into_iter().map(|(label, value)| html! {
+                                <tr>
+                                    <th>{ label }</th>
+                                    <td><code>{ value }</code></td>
+                                </tr>
+                            })
+                        }
+                        </tbody>
+                    </table>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+fn view_char(c: char) -> Html {
+    let s = c.to_string();
+    let (abbreviation, category) = unicode::category(c);
+
+    html! {
+        <tr>
+            <td><code>{ format!("U+{:04X}", c as u32) }</code></td>
+            <td>
+                { unicode::name(c) }
+                {
+                    match unicode::role(c) {
+                        Some(role) => html! { <span class="tag is-info is-light ml-2">{ role }</span> },
+                        None => html! {},
+                    }
+                }
+            </td>
+            <td>{ format!("{} ({})", abbreviation, category) }</td>
+            <td>{ unicode::script(c) }</td>
+            <td>{ unicode::east_asian_width(c).name() }</td>
+            <td><code>{ unicode::utf8_bytes(&s) }</code></td>
+            <td><code>{ unicode::utf16_units(&s) }</code></td>
+            <td><code>{ unicode::utf32_units(&s) }</code></td>
+        </tr>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn titles() {
+        assert_eq!(title("A"), "LATIN CAPITAL LETTER A");
+        assert_eq!(title("\u{7F}"), "<control> DELETE");
+        assert_eq!(
+            title("🇯🇵"),
+            "Flag (regional indicator pair) (2 code points)"
+        );
+        assert_eq!(
+            title("👨\u{200D}👩\u{200D}👧"),
+            "Emoji ZWJ sequence (5 code points)"
+        );
+    }
+
+    #[test]
+    fn escapes_and_normalization_forms() {
+        assert_eq!(
+            rows("が"),
+            [
+                ("HTML", "&#x304C;".to_string()),
+                ("JavaScript", "\\u304C".to_string()),
+                ("Rust", "\\u{304C}".to_string()),
+                ("Python", "\\u304c".to_string()),
+                ("NFC", "U+304C (unchanged)".to_string()),
+                ("NFD", "U+304B U+3099".to_string()),
+            ]
+        );
+        assert_eq!(
+            rows("😀"),
+            [
+                ("HTML", "&#x1F600;".to_string()),
+                ("JavaScript", "\\uD83D\\uDE00".to_string()),
+                ("Rust", "\\u{1F600}".to_string()),
+                ("Python", "\\U0001f600".to_string()),
+                ("NFC", "U+1F600 (unchanged)".to_string()),
+                ("NFD", "U+1F600 (unchanged)".to_string()),
+            ]
+        );
+        let rows = rows("e\u{301}");
+        assert_eq!(rows[4].1, "U+00E9");
+        assert_eq!(rows[5].1, "U+0065 U+0301 (unchanged)");
+    }
+}
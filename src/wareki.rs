@@ -0,0 +1,409 @@
+use crate::number_words::from_kanji;
+use crate::util::horizontal_field;
+use chrono::{Datelike, NaiveDate};
+use num_traits::ToPrimitive;
+use std::convert::TryFrom;
+use yew::prelude::*;
+
+struct Era {
+    name: &'static str,
+    letter: char,
+    /// The single-character form, such as ㍻.
+    ligature: char,
+    start: (i32, u32, u32),
+}
+
+/// Eras since the switch to one era per reign. Meiji is counted from
+/// 1868-01-25 so that 明治元年 is 1868, as is customary for conversions.
+const ERAS: &[Era] = &[
+    Era {
+        name: "明治",
+        letter: 'M',
+        ligature: '㍾',
+        start: (1868, 1, 25),
+    },
+    Era {
+        name: "大正",
+        letter: 'T',
+        ligature: '㍽',
+        start: (1912, 7, 30),
+    },
+    Era {
+        name: "昭和",
+        letter: 'S',
+        ligature: '㍼',
+        start: (1926, 12, 25),
+    },
+    Era {
+        name: "平成",
+        letter: 'H',
+        ligature: '㍻',
+        start: (1989, 1, 8),
+    },
+    Era {
+        name: "令和",
+        letter: 'R',
+        ligature: '㋿',
+        start: (2019, 5, 1),
+    },
+];
+
+/// Japan adopted the Gregorian calendar on 明治6年1月1日. Earlier dates
+/// were written in the lunisolar calendar.
+const GREGORIAN_ADOPTION: (i32, u32, u32) = (1873, 1, 1);
+
+const WEEKDAYS: &[&str] = &["月", "火", "水", "木", "金", "土", "日"];
+
+fn ymd((y, m, d): (i32, u32, u32)) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+impl Era {
+    fn start(&self) -> NaiveDate {
+        ymd(self.start)
+    }
+
+    /// The first day of the next era, if any.
+    fn end(&self) -> Option<NaiveDate> {
+        let i = ERAS.iter().position(|e| e.name == self.name).unwrap();
+        ERAS.get(i + 1).map(Era::start)
+    }
+
+    fn contains(&self, date: NaiveDate) -> bool {
+        self.start() <= date && self.end().is_none_or(|end| date < end)
+    }
+}
+
+fn format_year(era: &Era, year: i32) -> String {
+    if year == 1 {
+        format!("{}元年", era.name)
+    } else {
+        format!("{}{}年", era.name, year)
+    }
+}
+
+fn to_wareki(date: NaiveDate) -> Option<(&'static Era, i32)> {
+    let era = ERAS.iter().find(|era| era.contains(date))?;
+    Some((era, date.year() - era.start.0 + 1))
+}
+
+/// Splits `6.10.18`, `6年10月18日` or `元年五月一日` into numbers.
+fn parse_numbers(s: &str) -> Option<Vec<u32>> {
+    s.split(|c: char| c.is_whitespace() || "年月日./-・".contains(c))
+        .filter(|t| !t.is_empty())
+        .map(|t| {
+            if t == "元" {
+                Some(1)
+            } else {
+                from_kanji(t)?.to_u32()
+            }
+        })
+        .collect()
+}
+
+enum Parsed {
+    Date(NaiveDate),
+    Year(i32),
+}
+
+/// Parses a Gregorian or Japanese date such as `2024-10-18`,
+/// `令和6年10月18日`, `R6.10.18` or `㍻31年4月30日`. A year alone is also
+/// accepted.
+fn parse(text: &str) -> Result<Parsed, String> {
+    let text = text.trim();
+    let era = ERAS.iter().find_map(|era| {
+        text.strip_prefix(era.name)
+            .or_else(|| text.strip_prefix(era.ligature))
+            .or_else(|| text.strip_prefix(era.letter))
+            .or_else(|| text.strip_prefix(era.letter.to_ascii_lowercase()))
+            .map(|rest| (era, rest))
+    });
+    let (era, rest) = match era {
+        Some((era, rest)) => (Some(era), rest),
+        None => (None, text),
+    };
+
+    let numbers = parse_numbers(rest).ok_or_else(|| "日付を解釈できません".to_string())?;
+    let year = match numbers.first() {
+        Some(0) | None => return Err("日付を解釈できません".to_string()),
+        Some(&y) => i32::try_from(y).ok(),
+    };
+    let year = match (era, year) {
+        (Some(era), Some(y)) => y.checked_add(era.start.0 - 1),
+        (None, y) => y,
+        (_, None) => None,
+    }
+    .filter(|&year| year <= 9999)
+    .ok_or_else(|| "年が大きすぎます".to_string())?;
+
+    let date = match numbers[1..] {
+        [] => {
+            if let Some(era) = era {
+                let last = era
+                    .end()
+                    .map_or(i32::MAX, |end| end.pred_opt().unwrap().year());
+                if year > last {
+                    return Err(format!(
+                        "{}は{}までです",
+                        era.name,
+                        format_year(era, last - era.start.0 + 1)
+                    ));
+                }
+            }
+            return Ok(Parsed::Year(year));
+        }
+        [month, day] => NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or_else(|| "存在しない日付です".to_string())?,
+        _ => return Err("日付を解釈できません".to_string()),
+    };
+
+    if let Some(era) = era {
+        if !era.contains(date) {
+            let md = format!("{}月{}日", date.month(), date.day());
+            let written = format!("{}{}", format_year(era, date.year() - era.start.0 + 1), md);
+            return Err(match to_wareki(date) {
+                Some((actual, y)) => {
+                    format!(
+                        "{}は存在しません（{}{}）",
+                        written,
+                        format_year(actual, y),
+                        md
+                    )
+                }
+                None => format!("{}は存在しません", written),
+            });
+        }
+    }
+    Ok(Parsed::Date(date))
+}
+
+pub struct Model {
+    link: ComponentLink<Self>,
+    props: Props,
+}
+
+#[derive(Properties, Clone)]
+pub struct Props {
+    #[prop_or_default]
+    input: String,
+}
+
+pub enum Msg {
+    Input(String),
+}
+
+impl Model {
+    fn view_date(&self, date: NaiveDate) -> Html {
+        let input = |s: String| {
+            html! {
+                <input class="input" type="text" value=s readonly=true/>
+            }
+        };
+        let weekday = WEEKDAYS[date.weekday().num_days_from_monday() as usize];
+        let md = format!("{}月{}日", date.month(), date.day());
+        let (wareki, abbreviation) = match to_wareki(date) {
+            Some((era, y)) => (
+                format!("{}{}（{}）", format_year(era, y), md, weekday),
+                format!("{}{}.{}.{}", era.letter, y, date.month(), date.day()),
+            ),
+            None => ("".to_string(), "".to_string()),
+        };
+
+        html! {
+            <>
+            { horizontal_field("西暦", input(format!("{}年{}（{}）", date.year(), md, weekday))) }
+            { horizontal_field("ISO 8601", input(date.format("%Y-%m-%d").to_string())) }
+            {
+                horizontal_field("和暦", html!{
+                    <>
+                    { input(wareki) }
+                    {
+                        if date < ymd(GREGORIAN_ADOPTION) {
+                            html! { <p class="help">{"明治5年以前は旧暦が使われていたため、月日は当時の暦と一致しません"}</p> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    </>
+                })
+            }
+            { horizontal_field("略記", input(abbreviation)) }
+            </>
+        }
+    }
+
+    fn view_year(&self, year: i32) -> Html {
+        let first = ymd((year, 1, 1));
+        let last = ymd((year, 12, 31));
+        let eras: Vec<String> = ERAS
+            .iter()
+            .filter(|era| era.start() <= last && era.end().is_none_or(|end| first < end))
+            .map(|era| format_year(era, year - era.start.0 + 1))
+            .collect();
+
+        html! {
+            <>
+            { horizontal_field("西暦", html! { <input class="input" type="text" value=format!("{}年", year) readonly=true/> }) }
+            { horizontal_field("和暦", html! { <input class="input" type="text" value=eras.join(" / ") readonly=true/> }) }
+            </>
+        }
+    }
+}
+
+impl Component for Model {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        Self { link, props }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(text) => self.props.input = text,
+        }
+        true
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let result = if self.props.input.trim().is_empty() {
+            None
+        } else {
+            Some(parse(&self.props.input))
+        };
+        let mut classes = vec!["input"];
+        if let Some(Err(_)) = result {
+            classes.push("is-danger");
+        }
+
+        html! {
+            <>
+            <h1 class="title">{"和暦変換"}</h1>
+
+            {
+                horizontal_field("日付", html!{
+                    <>
+                    <input class=classes type="text" placeholder="令和6年10月18日, R6.10.18, 2024-10-18"
+                        value=self.props.input.clone()
+                        oninput=self.link.callback(|e: InputData| Msg::Input(e.value))/>
+                    {
+                        if let Some(Err(e)) = &result {
+                            html! { <p class="help is-danger">{ e }</p> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    </>
+                })
+            }
+            {
+                match result {
+                    Some(Ok(Parsed::Date(date))) => self.view_date(date),
+                    Some(Ok(Parsed::Year(year))) => self.view_year(year),
+                    _ => html! {},
+                }
+            }
+
+            <h2 class="title is-4">{"元号一覧"}</h2>
+            <table class="table is-narrow">
+                <thead>
+                    <tr><th>{"元号"}</th><th>{"略記"}</th><th>{"開始"}</th><th>{"終了"}</th></tr>
+                </thead>
+                <tbody>
+                {
+                    for ERAS.iter().map(|era| html! {
+                        <tr>
+                            <td>{ era.name }</td>
+                            <td>{ era.letter }</td>
+                            <td>{ era.start().format("%Y-%m-%d").to_string() }</td>
+                            <td>{ era.end().map_or("".to_string(), |end| end.pred_opt().unwrap().format("%Y-%m-%d").to_string()) }</td>
+                        </tr>
+                    })
+                }
+                </tbody>
+            </table>
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wareki(y: i32, m: u32, d: u32) -> Option<(&'static str, i32)> {
+        to_wareki(ymd((y, m, d))).map(|(era, year)| (era.name, year))
+    }
+
+    fn parse_date(text: &str) -> Result<NaiveDate, String> {
+        match parse(text)? {
+            Parsed::Date(date) => Ok(date),
+            Parsed::Year(year) => Err(format!("year {}", year)),
+        }
+    }
+
+    fn parse_year(text: &str) -> Result<i32, String> {
+        match parse(text)? {
+            Parsed::Year(year) => Ok(year),
+            Parsed::Date(date) => Err(format!("date {}", date)),
+        }
+    }
+
+    #[test]
+    fn era_boundaries() {
+        assert_eq!(wareki(1868, 1, 24), None);
+        assert_eq!(wareki(1868, 1, 25), Some(("明治", 1)));
+        assert_eq!(wareki(1912, 7, 29), Some(("明治", 45)));
+        assert_eq!(wareki(1912, 7, 30), Some(("大正", 1)));
+        assert_eq!(wareki(1926, 12, 25), Some(("昭和", 1)));
+        assert_eq!(wareki(1989, 1, 7), Some(("昭和", 64)));
+        assert_eq!(wareki(1989, 1, 8), Some(("平成", 1)));
+        assert_eq!(wareki(2019, 4, 30), Some(("平成", 31)));
+        assert_eq!(wareki(2019, 5, 1), Some(("令和", 1)));
+    }
+
+    #[test]
+    fn parses_japanese_dates() {
+        let date = ymd((2024, 10, 18));
+        assert_eq!(parse_date("令和6年10月18日"), Ok(date));
+        assert_eq!(parse_date("R6.10.18"), Ok(date));
+        assert_eq!(parse_date("r6/10/18"), Ok(date));
+        assert_eq!(parse_date("2024-10-18"), Ok(date));
+        assert_eq!(parse_date("令和元年五月一日"), Ok(ymd((2019, 5, 1))));
+        assert_eq!(parse_date("㍻31年4月30日"), Ok(ymd((2019, 4, 30))));
+        assert_eq!(parse_year("㍻元年"), Ok(1989));
+    }
+
+    #[test]
+    fn rejects_dates_outside_their_era() {
+        assert_eq!(
+            parse_date("平成31年5月1日"),
+            Err("平成31年5月1日は存在しません（令和元年5月1日）".to_string())
+        );
+        assert_eq!(
+            parse_year("昭和65年"),
+            Err("昭和は昭和64年までです".to_string())
+        );
+        assert_eq!(
+            parse_date("令和元年2月30日"),
+            Err("存在しない日付です".to_string())
+        );
+        assert!(parse("令和0年").is_err());
+    }
+
+    #[test]
+    fn rejects_huge_years() {
+        let too_large = Err("年が大きすぎます".to_string());
+        assert_eq!(parse_year("9999"), Ok(9999));
+        assert_eq!(parse_year("10000"), too_large);
+        assert_eq!(parse_year("令和7981年"), Ok(9999));
+        assert_eq!(parse_year("令和7982年"), too_large);
+        assert_eq!(parse_year("令和2147483647年"), too_large);
+        assert_eq!(parse_year("令和4294967295年"), too_large);
+        assert_eq!(parse_date("4294967295-1-1").map(|_| 0), too_large);
+    }
+}
@@ -0,0 +1,518 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+pub const BOM: char = '\u{FEFF}';
+
+/// Splits `text` into lines, each with the line ending that terminates it
+/// (`"\r\n"`, `"\r"`, `"\n"` or `""` for an unterminated last line).
+/// Unlike `str::lines`, a lone CR is a line break.
+pub fn split_lines(text: &str) -> Vec<(&str, &str)> {
+    let mut lines = vec![];
+    let mut start = 0;
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let ending = match bytes[i] {
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => 2,
+            b'\r' | b'\n' => 1,
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        lines.push((&text[start..i], &text[i..i + ending]));
+        i += ending;
+        start = i;
+    }
+    if start < text.len() || lines.is_empty() {
+        lines.push((&text[start..], ""));
+    }
+    lines
+}
+
+/// Whitespace other than the ASCII space, tab and line breaks, such as
+/// NBSP, the ideographic space and the typographic spaces.
+pub fn is_unusual_space(c: char) -> bool {
+    c.is_whitespace() && !matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+pub fn unusual_space_name(c: char) -> &'static str {
+    match c {
+        '\u{0B}' => "LINE TABULATION",
+        '\u{0C}' => "FORM FEED",
+        '\u{85}' => "NEXT LINE",
+        '\u{A0}' => "NO-BREAK SPACE",
+        '\u{1680}' => "OGHAM SPACE MARK",
+        '\u{2000}' => "EN QUAD",
+        '\u{2001}' => "EM QUAD",
+        '\u{2002}' => "EN SPACE",
+        '\u{2003}' => "EM SPACE",
+        '\u{2004}' => "THREE-PER-EM SPACE",
+        '\u{2005}' => "FOUR-PER-EM SPACE",
+        '\u{2006}' => "SIX-PER-EM SPACE",
+        '\u{2007}' => "FIGURE SPACE",
+        '\u{2008}' => "PUNCTUATION SPACE",
+        '\u{2009}' => "THIN SPACE",
+        '\u{200A}' => "HAIR SPACE",
+        '\u{2028}' => "LINE SEPARATOR",
+        '\u{2029}' => "PARAGRAPH SEPARATOR",
+        '\u{202F}' => "NARROW NO-BREAK SPACE",
+        '\u{205F}' => "MEDIUM MATHEMATICAL SPACE",
+        '\u{3000}' => "IDEOGRAPHIC SPACE",
+        _ => "SPACE",
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum LineEnding {
+    None,
+    Lf,
+    Crlf,
+    Cr,
+    Mixed,
+}
+
+impl LineEnding {
+    pub fn name(self) -> &'static str {
+        match self {
+            LineEnding::None => "None",
+            LineEnding::Lf => "LF",
+            LineEnding::Crlf => "CRLF",
+            LineEnding::Cr => "CR",
+            LineEnding::Mixed => "Mixed",
+        }
+    }
+}
+
+/// Line numbers below are 1-based.
+#[derive(Clone, Default)]
+pub struct Diagnostics {
+    pub lf: usize,
+    pub crlf: usize,
+    pub cr: usize,
+    /// Lines whose ending differs from the most common one.
+    pub odd_endings: Vec<usize>,
+    pub bom: bool,
+    pub final_newline: bool,
+    pub lines: usize,
+    pub trailing: Vec<usize>,
+    pub tab_indented: Vec<usize>,
+    pub space_indented: Vec<usize>,
+    /// Lines whose indentation mixes tabs and spaces.
+    pub mixed_indent: Vec<usize>,
+    /// The longest line and its length in graphemes.
+    pub longest: Option<(usize, usize)>,
+    /// Empty or whitespace-only lines.
+    pub blank: Vec<usize>,
+    pub unusual_spaces: Vec<(usize, char)>,
+}
+
+impl Diagnostics {
+    pub fn new(text: &str) -> Self {
+        let mut diagnostics = Self {
+            bom: text.starts_with(BOM),
+            ..Self::default()
+        };
+        let text = text.strip_prefix(BOM).unwrap_or(text);
+        if text.is_empty() {
+            return diagnostics;
+        }
+
+        let lines = split_lines(text);
+        diagnostics.final_newline = lines.last().is_some_and(|(_, ending)| !ending.is_empty());
+        diagnostics.lines = lines.len();
+
+        for &(_, ending) in &lines {
+            match ending {
+                "\n" => diagnostics.lf += 1,
+                "\r\n" => diagnostics.crlf += 1,
+                "\r" => diagnostics.cr += 1,
+                _ => {}
+            }
+        }
+        let common = match diagnostics.ending() {
+            LineEnding::Mixed => {
+                let counts = [
+                    (diagnostics.lf, "\n"),
+                    (diagnostics.crlf, "\r\n"),
+                    (diagnostics.cr, "\r"),
+                ];
+                counts.iter().max_by_key(|&&(n, _)| n).unwrap().1
+            }
+            _ => "",
+        };
+
+        for (i, &(line, ending)) in lines.iter().enumerate() {
+            let number = i + 1;
+            if !common.is_empty() && !ending.is_empty() && ending != common {
+                diagnostics.odd_endings.push(number);
+            }
+            if line.trim().is_empty() {
+                diagnostics.blank.push(number);
+            }
+            if line.ends_with(char::is_whitespace) {
+                diagnostics.trailing.push(number);
+            }
+
+            let indent: &str = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+            match (indent.contains('\t'), indent.contains(' ')) {
+                (true, true) => diagnostics.mixed_indent.push(number),
+                (true, false) => diagnostics.tab_indented.push(number),
+                (false, true) => diagnostics.space_indented.push(number),
+                (false, false) => {}
+            }
+
+            let length = line.graphemes(true).count();
+            if diagnostics
+                .longest
+                .is_none_or(|(_, longest)| length > longest)
+            {
+                diagnostics.longest = Some((number, length));
+            }
+
+            for c in line.chars().filter(|&c| is_unusual_space(c)) {
+                diagnostics.unusual_spaces.push((number, c));
+            }
+        }
+        diagnostics
+    }
+
+    pub fn ending(&self) -> LineEnding {
+        match (self.lf > 0, self.crlf > 0, self.cr > 0) {
+            (false, false, false) => LineEnding::None,
+            (true, false, false) => LineEnding::Lf,
+            (false, true, false) => LineEnding::Crlf,
+            (false, false, true) => LineEnding::Cr,
+            _ => LineEnding::Mixed,
+        }
+    }
+}
+
+/// Tab widths outside `1..=MAX_TAB_WIDTH` are clamped.
+pub const MAX_TAB_WIDTH: usize = 16;
+
+/// Characters replaced by an ASCII space when normalizing spaces.
+const WIDE_SPACES: &[char] = &['\u{A0}', '\u{202F}', '\u{3000}'];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum NewLine {
+    Keep,
+    Lf,
+    Crlf,
+    Cr,
+}
+
+pub const NEW_LINES: &[NewLine] = &[NewLine::Keep, NewLine::Lf, NewLine::Crlf, NewLine::Cr];
+
+impl NewLine {
+    pub fn name(self) -> &'static str {
+        match self {
+            NewLine::Keep => "Keep",
+            NewLine::Lf => "LF (Unix)",
+            NewLine::Crlf => "CRLF (Windows)",
+            NewLine::Cr => "CR (classic Mac)",
+        }
+    }
+
+    fn as_str(self) -> Option<&'static str> {
+        match self {
+            NewLine::Keep => None,
+            NewLine::Lf => Some("\n"),
+            NewLine::Crlf => Some("\r\n"),
+            NewLine::Cr => Some("\r"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Bom {
+    Keep,
+    Add,
+    Strip,
+}
+
+pub const BOMS: &[Bom] = &[Bom::Keep, Bom::Add, Bom::Strip];
+
+impl Bom {
+    pub fn name(self) -> &'static str {
+        match self {
+            Bom::Keep => "Keep",
+            Bom::Add => "Add",
+            Bom::Strip => "Strip",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Indent {
+    Keep,
+    Spaces,
+    Tabs,
+}
+
+pub const INDENTS: &[Indent] = &[Indent::Keep, Indent::Spaces, Indent::Tabs];
+
+impl Indent {
+    pub fn name(self) -> &'static str {
+        match self {
+            Indent::Keep => "Keep",
+            Indent::Spaces => "Tabs to spaces",
+            Indent::Tabs => "Leading spaces to tabs",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Normalize {
+    pub new_line: NewLine,
+    pub bom: Bom,
+    pub indent: Indent,
+    pub tab_width: usize,
+    pub trim_trailing: bool,
+    pub collapse_blank: bool,
+    /// Replace NBSPs and ideographic spaces (U+3000) with ASCII spaces.
+    pub replace_spaces: bool,
+}
+
+/// Replaces every tab with spaces up to the next tab stop, like `expand`.
+fn expand_tabs(line: &str, width: usize) -> String {
+    let mut ret = String::new();
+    let mut column = 0;
+    for c in line.chars() {
+        if c == '\t' {
+            let n = width - column % width;
+            ret.extend(std::iter::repeat_n(' ', n));
+            column += n;
+        } else {
+            ret.push(c);
+            column += 1;
+        }
+    }
+    ret
+}
+
+/// Rewrites the indentation with as many tabs as fit, like `unexpand`.
+/// Spaces after the first other character are left alone.
+fn unexpand_tabs(line: &str, width: usize) -> String {
+    let rest = line.trim_start_matches([' ', '\t']);
+    let indent = &line[..line.len() - rest.len()];
+    let column = indent.chars().fold(0, |column, c| {
+        if c == '\t' {
+            column + width - column % width
+        } else {
+            column + 1
+        }
+    });
+    let mut ret = "\t".repeat(column / width);
+    ret += &" ".repeat(column % width);
+    ret + rest
+}
+
+impl Normalize {
+    pub fn apply(&self, text: &str) -> String {
+        let had_bom = text.starts_with(BOM);
+        let text = text.strip_prefix(BOM).unwrap_or(text);
+        let width = self.tab_width.clamp(1, MAX_TAB_WIDTH);
+
+        let mut ret = String::new();
+        if self.bom == Bom::Add || self.bom == Bom::Keep && had_bom {
+            ret.push(BOM);
+        }
+        let mut prev_blank = false;
+        for (line, ending) in split_lines(text) {
+            let mut line = if self.replace_spaces {
+                line.replace(WIDE_SPACES, " ")
+            } else {
+                line.to_string()
+            };
+            line = match self.indent {
+                Indent::Keep => line,
+                Indent::Spaces => expand_tabs(&line, width),
+                Indent::Tabs => unexpand_tabs(&line, width),
+            };
+            if self.trim_trailing {
+                line.truncate(line.trim_end().len());
+            }
+
+            let blank = line.trim().is_empty();
+            if self.collapse_blank && blank && prev_blank {
+                continue;
+            }
+            prev_blank = blank;
+
+            ret += &line;
+            ret += match (ending, self.new_line.as_str()) {
+                ("", _) => "",
+                (ending, None) => ending,
+                (_, Some(new_line)) => new_line,
+            };
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_every_line_ending() {
+        assert_eq!(
+            split_lines("a\r\nb\rc\nd"),
+            [("a", "\r\n"), ("b", "\r"), ("c", "\n"), ("d", "")]
+        );
+        assert_eq!(split_lines("\r\r\n"), [("", "\r"), ("", "\r\n")]);
+        assert_eq!(split_lines("a\n"), [("a", "\n")]);
+        assert_eq!(split_lines(""), [("", "")]);
+    }
+
+    #[test]
+    fn reports_mixed_line_endings() {
+        let d = Diagnostics::new("a\r\nb\nc\nd\re");
+        assert_eq!((d.lf, d.crlf, d.cr), (2, 1, 1));
+        assert!(d.ending() == LineEnding::Mixed);
+        assert_eq!(d.odd_endings, [1, 4]);
+        assert_eq!(d.lines, 5);
+        assert!(!d.final_newline);
+
+        let d = Diagnostics::new("a\r\nb\r\n");
+        assert!(d.ending() == LineEnding::Crlf);
+        assert!(d.odd_endings.is_empty());
+        assert_eq!(d.lines, 2);
+        assert!(d.final_newline);
+
+        assert!(Diagnostics::new("a\rb").ending() == LineEnding::Cr);
+        assert!(Diagnostics::new("a").ending() == LineEnding::None);
+    }
+
+    #[test]
+    fn ignores_the_bom() {
+        let d = Diagnostics::new("\u{FEFF} a\n");
+        assert!(d.bom);
+        assert_eq!(d.lines, 1);
+        assert_eq!(d.space_indented, [1]);
+        assert_eq!(d.longest, Some((1, 2)));
+        assert!(d.unusual_spaces.is_empty());
+
+        let d = Diagnostics::new("\u{FEFF}");
+        assert!(d.bom);
+        assert_eq!(d.lines, 0);
+        assert!(!Diagnostics::new("a\u{FEFF}").bom);
+    }
+
+    #[test]
+    fn finds_trailing_and_blank_lines() {
+        let d = Diagnostics::new("a \nb\t\r\nc\u{3000}\n\n  \nd");
+        assert_eq!(d.trailing, [1, 2, 3, 5]);
+        assert_eq!(d.blank, [4, 5]);
+        assert_eq!(d.unusual_spaces, [(3, '\u{3000}')]);
+        assert_eq!(d.longest, Some((1, 2)));
+    }
+
+    #[test]
+    fn classifies_indentation() {
+        let d = Diagnostics::new("\tx\n  y\n\t z\n \tw\nv\n\u{3000}u");
+        assert_eq!(d.tab_indented, [1]);
+        assert_eq!(d.space_indented, [2]);
+        assert_eq!(d.mixed_indent, [3, 4]);
+    }
+
+    #[test]
+    fn line_numbers_match_the_textarea() {
+        // Jump-to-line counts LF-separated lines of the textarea, where the
+        // browser has turned CRLF and CR into LF.
+        let text = "\u{FEFF}a \r\nb\rc\n\td\r\n\r \n";
+        let textarea = text.replace("\r\n", "\n").replace('\r', "\n");
+        let lines: Vec<&str> = textarea.split('\n').collect();
+        let d = Diagnostics::new(text);
+        assert_eq!(d.trailing, [1, 6]);
+        assert_eq!(d.tab_indented, [4]);
+        assert_eq!(d.blank, [5, 6]);
+        assert_eq!(lines[0], "\u{FEFF}a ");
+        assert_eq!(lines[3], "\td");
+        assert_eq!(lines[5], " ");
+        assert_eq!(d.lines, lines.len() - 1);
+    }
+
+    fn normalize(text: &str, configure: impl FnOnce(&mut Normalize)) -> String {
+        let mut normalize = Normalize {
+            new_line: NewLine::Keep,
+            bom: Bom::Keep,
+            indent: Indent::Keep,
+            tab_width: 4,
+            trim_trailing: false,
+            collapse_blank: false,
+            replace_spaces: false,
+        };
+        configure(&mut normalize);
+        normalize.apply(text)
+    }
+
+    #[test]
+    fn converts_line_endings() {
+        let text = "a\r\nb\rc\nd";
+        assert_eq!(normalize(text, |_| {}), text);
+        assert_eq!(normalize(text, |n| n.new_line = NewLine::Lf), "a\nb\nc\nd");
+        assert_eq!(
+            normalize(text, |n| n.new_line = NewLine::Crlf),
+            "a\r\nb\r\nc\r\nd"
+        );
+        assert_eq!(normalize(text, |n| n.new_line = NewLine::Cr), "a\rb\rc\rd");
+        assert_eq!(normalize("a\r\n", |n| n.new_line = NewLine::Lf), "a\n");
+    }
+
+    #[test]
+    fn adds_and_strips_the_bom() {
+        let bom = |text, b| normalize(text, |n| n.bom = b);
+        assert_eq!(bom("\u{FEFF}a", Bom::Keep), "\u{FEFF}a");
+        assert_eq!(bom("a", Bom::Keep), "a");
+        assert_eq!(bom("a", Bom::Add), "\u{FEFF}a");
+        assert_eq!(bom("\u{FEFF}a", Bom::Add), "\u{FEFF}a");
+        assert_eq!(bom("\u{FEFF}a", Bom::Strip), "a");
+        assert_eq!(bom("a\u{FEFF}", Bom::Strip), "a\u{FEFF}");
+        assert_eq!(bom("", Bom::Add), "\u{FEFF}");
+    }
+
+    #[test]
+    fn trims_and_collapses() {
+        let text = "a \t\r\n\u{3000}\n\n \nb\u{A0}";
+        assert_eq!(normalize(text, |n| n.trim_trailing = true), "a\r\n\n\n\nb");
+        assert_eq!(
+            normalize(text, |n| {
+                n.trim_trailing = true;
+                n.collapse_blank = true;
+            }),
+            "a\r\n\nb"
+        );
+        assert_eq!(
+            normalize("a\u{A0}b\u{3000}c\u{202F}", |n| n.replace_spaces = true),
+            "a b c "
+        );
+    }
+
+    #[test]
+    fn expands_and_unexpands_tabs() {
+        let spaces = |text, width| {
+            normalize(text, |n| {
+                n.indent = Indent::Spaces;
+                n.tab_width = width;
+            })
+        };
+        let tabs = |text, width| {
+            normalize(text, |n| {
+                n.indent = Indent::Tabs;
+                n.tab_width = width;
+            })
+        };
+        assert_eq!(spaces("\tab\tc\n  \td", 4), "    ab  c\n    d");
+        assert_eq!(spaces("\ta", 2), "  a");
+        assert_eq!(
+            tabs("        a  b\n   \tc\n     d", 4),
+            "\t\ta  b\n\tc\n\t d"
+        );
+        assert_eq!(tabs("\t  a", 8), "\t  a");
+
+        assert_eq!(spaces("\ta", 0), " a");
+        assert_eq!(spaces("\ta", 1_000_000), " ".repeat(MAX_TAB_WIDTH) + "a");
+        assert_eq!(tabs(&" ".repeat(40), usize::MAX), "\t\t        ");
+    }
+}